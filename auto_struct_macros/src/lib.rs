@@ -34,6 +34,7 @@ enum AutoStructFieldKind
     IteratorDerivable(Expr, Expr),
     Expected(Expr, Expr),
     Literal(Expr),
+    TryLiteral(Expr),
     Simple(Expr),
 }
 
@@ -65,6 +66,11 @@ impl AutoStructField
                 err(raw.ident.span(), "`literal` auto_struct field cant have an initializer")?;
             }
             AutoStructFieldKind::Literal(syn::parse2(raw.tts)?)
+        } else if raw.ident == "try_literal" {
+            if !init_was_none {
+                err(raw.ident.span(), "`try_literal` auto_struct field cant have an initializer")?;
+            }
+            AutoStructFieldKind::TryLiteral(syn::parse2(raw.tts)?)
         } else if raw.ident == "derive_from_iter" {
             AutoStructFieldKind::IteratorDerivable(syn::parse2(raw.tts)?, init_expr)
         } else if raw.ident == "pad_align" {
@@ -97,6 +103,7 @@ impl AutoStructField
             AutoStructFieldKind::IteratorDerivable(_, _) => false,
             AutoStructFieldKind::Expected(_, _) => false,
             AutoStructFieldKind::Literal(_) => true,
+            AutoStructFieldKind::TryLiteral(_) => true,
             AutoStructFieldKind::Simple(_) => true,
         }
     }
@@ -109,47 +116,63 @@ impl AutoStructField
             AutoStructFieldKind::IteratorDerivable(_, _) => false,
             AutoStructFieldKind::Expected(_, _) => false,
             AutoStructFieldKind::Literal(_) => false,
+            AutoStructFieldKind::TryLiteral(_) => false,
             AutoStructFieldKind::Simple(_) => false,
         }
     }
 
     fn read_expr(&self, struct_name: &Ident) -> proc_macro2::TokenStream
     {
+        let ident = &self.ident;
+        let in_field = quote! {
+            |__e__: reader_writer::ReadError| __e__.in_field(
+                stringify!(#struct_name),
+                stringify!(#ident),
+            )
+        };
         match &self.kind {
             AutoStructFieldKind::PadAlign(aligned) => quote! {
                 {
                     let __curr_len__ = __reader__.len();
-                    __reader__.read(reader_writer::pad_bytes_count(
+                    __reader__.try_read(reader_writer::pad_bytes_count(
                         #aligned,
                         __start_len__ - __curr_len__
-                    ))
+                    )).map_err(#in_field)?
                 }
             },
-            AutoStructFieldKind::Derivable(_, init) => quote!(__reader__.read(#init)),
-            AutoStructFieldKind::IteratorDerivable(_, init) => quote!(__reader__.read(#init)),
+            AutoStructFieldKind::Derivable(_, init) |
+            AutoStructFieldKind::IteratorDerivable(_, init) |
+            AutoStructFieldKind::Simple(init) => quote! {
+                __reader__.try_read(#init).map_err(#in_field)?
+            },
             AutoStructFieldKind::Expected(expected, init) => {
-                let ident = &self.ident;
                 let ty = &self.ty;
-                let file_line = quote_spanned! { self.ident.span() =>
-                    file!(), line!()
-                };
                 quote! {
                     {
-                        let __tmp__ = __reader__.read(#init);
+                        let __pos__ = __reader__.position();
+                        let __tmp__: #ty = __reader__.try_read(#init).map_err(#in_field)?;
                         let expected: #ty = #expected;
-
-                        // reader_writer::expect!(
-                        assert_eq!(
-                            expected, __tmp__,
-                            "While deserializing {}, {}:{}",
-                            stringify!(#struct_name::#ident), #file_line
-                        );
+                        if expected != __tmp__ {
+                            let __kind__ = reader_writer::ReadErrorKind::UnexpectedValue {
+                                expected: format!("{:?}", expected),
+                                found: format!("{:?}", __tmp__),
+                            };
+                            return Err((#in_field)(reader_writer::ReadError::new(__kind__, __pos__)));
+                        }
                         __tmp__
                     }
                 }
             },
             AutoStructFieldKind::Literal(expr) => quote!(#expr),
-            AutoStructFieldKind::Simple(init) => quote!(__reader__.read(#init)),
+            AutoStructFieldKind::TryLiteral(expr) => quote! {
+                match #expr {
+                    Ok(__v__) => __v__,
+                    Err(__msg__) => {
+                        let __kind__ = reader_writer::ReadErrorKind::Invalid(__msg__);
+                        return Err((#in_field)(__reader__.error(__kind__)));
+                    },
+                }
+            },
         }
     }
 
@@ -168,6 +191,7 @@ impl AutoStructField
             }),
             AutoStructFieldKind::Expected(expected, _) => Some(expected.into_token_stream()),
             AutoStructFieldKind::Literal(_) => None,
+            AutoStructFieldKind::TryLiteral(_) => None,
             AutoStructFieldKind::Simple(_) => {
                 let ident = &self.ident;
                 Some(quote!(self.#ident))
@@ -180,6 +204,7 @@ impl AutoStructField
         let ty = &self.ty;
         match &self.kind {
             AutoStructFieldKind::Literal(_) => None,
+            AutoStructFieldKind::TryLiteral(_) => None,
             AutoStructFieldKind::IteratorDerivable(_, _) => Some(quote! {
                 reader_writer::Dap<_, <#ty as reader_writer::DerivableFromIterator>::Item>
             }),
@@ -442,14 +467,25 @@ impl AutoStructDecl
                 type Args = #args_ty;
                 fn read_from(
                     __reader__: &mut reader_writer::Reader<#reader_lifetime>,
-                    #args_pat: Self::Args
+                    __args__: Self::Args
                 ) -> Self
+                {
+                    match Self::try_read_from(__reader__, __args__) {
+                        Ok(__res__) => __res__,
+                        Err(__e__) => panic!("{}", __e__),
+                    }
+                }
+
+                fn try_read_from(
+                    __reader__: &mut reader_writer::Reader<#reader_lifetime>,
+                    #args_pat: Self::Args
+                ) -> ::std::result::Result<Self, reader_writer::ReadError>
                 {
                     #offset_let
                     #(let #idents: #tys = #read_exprs;)*
-                    #name {
+                    Ok(#name {
                         #(#storage_idents,)*
-                    }
+                    })
                 }
 
                 #size_fn
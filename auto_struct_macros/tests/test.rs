@@ -83,3 +83,25 @@ fn test_padding()
     let padding_test: PaddingTest = reader.read(());
     assert_eq!(padding_test.size(), 36);
 }
+
+#[test]
+fn test_try_read_errors()
+{
+    use reader_writer::ReadErrorKind;
+    // One SizeTest holding 2 bytes, whose x is 0x00FF instead of 0xFFFF
+    let data = [0, 0, 0, 1, 0, 0, 0, 2, 0, 0xFF, 0, 0];
+    let err = reader_writer::Reader::new(&data[..]).try_read::<DeriveFromIteratorTest>(())
+        .err().unwrap();
+    assert_eq!(err.type_name(), Some("SizeTest"));
+    assert_eq!(err.field(), Some("x"));
+    assert_eq!(err.fields[1], ("DeriveFromIteratorTest", "array"));
+    assert_eq!(err.offset, 8);
+    assert_eq!(err.kind, ReadErrorKind::UnexpectedValue {
+        expected: "65535".to_string(),
+        found: "255".to_string(),
+    });
+
+    let err = reader_writer::Reader::new(&data[..2]).try_read::<FixedSizeTest>(()).err().unwrap();
+    assert_eq!(err.field(), Some("i"));
+    assert_eq!(err.kind, ReadErrorKind::UnexpectedEof { needed: 4, remaining: 2 });
+}
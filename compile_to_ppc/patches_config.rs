@@ -1,11 +1,34 @@
 mod _rel_config {
     use serde::{Serialize, Deserialize};
+
+    // Bump whenever a field is added. Fields are only ever appended to the end of RelConfig
+    // (`version` itself came after the quickplay fields), so the REL can read a config file
+    // written for an older version by filling in the fields the file is missing from
+    // `RelConfig::DEFAULT`.
+    pub(crate) const REL_CONFIG_VERSION: u32 = 1;
+
+    pub(crate) const SEED_HASH_LEN: usize = 32;
+
     #[derive(Serialize, Deserialize)]
     #[repr(C)]
     pub(crate) struct RelConfig
     {
         pub quickplay_mlvl: u32,
         pub quickplay_mrea: u32,
+
+        pub version: u32,
+        // ASCII, NUL padded
+        pub seed_hash: [u8; SEED_HASH_LEN],
+    }
+
+    impl RelConfig
+    {
+        pub(crate) const DEFAULT: RelConfig = RelConfig {
+            quickplay_mlvl: 0xFFFFFFFF,
+            quickplay_mrea: 0xFFFFFFFF,
+            version: REL_CONFIG_VERSION,
+            seed_hash: [0; SEED_HASH_LEN],
+        };
     }
 }
-pub(crate) use self::_rel_config::RelConfig;
+pub(crate) use self::_rel_config::{RelConfig, SEED_HASH_LEN};
@@ -14,13 +14,12 @@ use primeapi::mp1::{
 };
 use primeapi::rstl::WString;
 
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
 
 include!("../../patches_config.rs");
-static mut REL_CONFIG: RelConfig = RelConfig {
-    quickplay_mlvl: 0xFFFFFFFF,
-    quickplay_mrea: 0xFFFFFFFF,
-};
+// Unmangled so the host can find it in the link map for the memory map it exports
+#[no_mangle]
+static mut REL_CONFIG: RelConfig = RelConfig::DEFAULT;
 
 #[prolog_fn]
 unsafe extern "C" fn setup_global_state()
@@ -38,8 +37,14 @@ unsafe extern "C" fn setup_global_state()
         {
             let _ = fi.read_async(recv_buf, 0, 0);
         }
-        REL_CONFIG = ssmarshal::deserialize(&recv_buf[..config_size].assume_init())
-            .unwrap().0;
+
+        // A config written for an older REL is shorter than ours; the fields it's missing keep
+        // their default values
+        let mut config_buf = [0u8; mem::size_of::<RelConfig>()];
+        let _ = ssmarshal::serialize(&mut config_buf, &RelConfig::DEFAULT);
+        let len = config_size.min(config_buf.len());
+        config_buf[..len].copy_from_slice(&recv_buf[..len].assume_init());
+        REL_CONFIG = ssmarshal::deserialize(&config_buf).unwrap().0;
     }
 
 }
@@ -74,8 +79,15 @@ unsafe extern "C" fn update_main_menu_text(frame: *mut CGuiFrame, widget_name: *
     } else {
         110
     };
-    let raw_string = CStringTable::get_string(CStringTable::main_string_table(), str_idx);
-    let s = WString::from_ucs2_str(raw_string);
+    let seed_hash_len = REL_CONFIG.seed_hash.iter()
+        .position(|b| *b == 0)
+        .unwrap_or(REL_CONFIG.seed_hash.len());
+    let s = if seed_hash_len > 0 {
+        WString::from_ascii(&REL_CONFIG.seed_hash[..seed_hash_len])
+    } else {
+        let raw_string = CStringTable::get_string(CStringTable::main_string_table(), str_idx);
+        WString::from_ucs2_str(raw_string)
+    };
 
     for name in &[b"textpane_identifier\0".as_ptr(), b"textpane_identifierb\0".as_ptr()] {
         let widget = CGuiFrame::find_widget(frame, *name);
@@ -0,0 +1,461 @@
+//! Builds small synthetic discs for tests, so the patcher can be exercised without a copy of the
+//! game. Each `WorldFixture` becomes a PAK holding an MLVL, a SAVW, a STRG with the world's name,
+//! any extra resources it was given and an MREA (plus a name STRG) for every room. Nothing else the game needs (models, textures,
+//! the DOL, ...) is there, so the discs only work with code that doesn't go looking for it.
+
+use std::{borrow::Cow, ffi::CString};
+
+use reader_writer::{CStr, Readable, Reader, RoArray, Writable};
+use structs::{
+    Area, AreaDependencies, AreaLayerFlags, Connection, ConnectionMsg, ConnectionState, Dependency,
+    GcDisc, MemoryRelayConn, Mlvl, Mrea, MreaSection, Pak, ResId, Resource, ResourceKind, Savw, Scly,
+    SclyLayer, SclyObject, SclyProperty, Strg,
+};
+
+const IDENTITY_TRANSFORM: [f32; 12] = [
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+];
+
+pub struct LayerFixture
+{
+    pub name: String,
+    pub active: bool,
+    pub objects: Vec<SclyObject<'static>>,
+    pub dependencies: Vec<Dependency>,
+}
+
+pub struct RoomFixture
+{
+    pub mrea: u32,
+    pub internal_id: u32,
+    pub name_strg: u32,
+    pub name: String,
+    pub layers: Vec<LayerFixture>,
+}
+
+impl RoomFixture
+{
+    /// A room with a single, empty, "Default" layer
+    pub fn new(mrea: u32, name_strg: u32, name: &str) -> RoomFixture
+    {
+        RoomFixture {
+            mrea,
+            internal_id: mrea,
+            name_strg,
+            name: name.to_string(),
+            layers: vec![],
+        }.layer("Default", true)
+    }
+
+    pub fn layer(mut self, name: &str, active: bool) -> RoomFixture
+    {
+        self.layers.push(LayerFixture {
+            name: name.to_string(),
+            active,
+            objects: vec![],
+            dependencies: vec![],
+        });
+        self
+    }
+
+    /// Adds to the layer's dependency list in the MLVL. The resource itself has to be added to
+    /// the world with `WorldFixture::resource`.
+    pub fn dependency(mut self, layer: usize, asset_id: u32, asset_type: &[u8; 4]) -> RoomFixture
+    {
+        self.layers[layer].dependencies.push(Dependency {
+            asset_id,
+            asset_type: asset_type.into(),
+        });
+        self
+    }
+
+    pub fn object(mut self, layer: usize, object: SclyObject<'static>) -> RoomFixture
+    {
+        self.layers[layer].objects.push(object);
+        self
+    }
+
+    fn mrea(&self) -> Mrea<'static>
+    {
+        let layers: Vec<_> = self.layers.iter()
+            .map(|layer| SclyLayer {
+                unknown: 0,
+                objects: layer.objects.clone().into(),
+            })
+            .collect();
+        let scly = Scly {
+            unknown: 1,
+            layers: layers.into(),
+        };
+
+        // Only the SCLY section exists, the other indices point past the end
+        Mrea {
+            area_transform: IDENTITY_TRANSFORM.into(),
+            world_model_count: 0,
+            world_geometry_section_idx: u32::MAX,
+            scly_section_idx: 0,
+            collision_section_idx: u32::MAX,
+            unknown_section_idx: u32::MAX,
+            lights_section_idx: u32::MAX,
+            visibility_tree_section_idx: u32::MAX,
+            path_section_idx: u32::MAX,
+            area_octree_section_idx: u32::MAX,
+            sections: vec![MreaSection::Scly(scly)].into(),
+        }
+    }
+
+    fn area(&self) -> Area<'static>
+    {
+        // One dependency list per layer plus one for the whole area
+        let deps: Vec<_> = self.layers.iter()
+            .map(|layer| layer.dependencies.clone().into())
+            .chain(Some(vec![].into()))
+            .collect();
+        Area {
+            area_name_strg: ResId::new(self.name_strg),
+            area_transform: IDENTITY_TRANSFORM.into(),
+            area_bounding_box: [-10.0, -10.0, -10.0, 10.0, 10.0, 10.0].into(),
+            mrea: ResId::new(self.mrea),
+            internal_id: self.internal_id,
+            attached_area_count: 0,
+            attached_areas: vec![].into(),
+            dependencies: AreaDependencies { deps: deps.into() },
+            docks: vec![].into(),
+        }
+    }
+
+    fn layer_flags(&self) -> AreaLayerFlags
+    {
+        let flags = self.layers.iter()
+            .enumerate()
+            .filter(|(_, layer)| layer.active)
+            .fold(0, |flags, (i, _)| flags | 1 << i);
+        AreaLayerFlags {
+            layer_count: self.layers.len() as u32,
+            flags,
+        }
+    }
+}
+
+/// The world's SAVW and name STRG use the two ids after its MLVL's
+pub struct WorldFixture
+{
+    pub pak_name: String,
+    pub mlvl: u32,
+    pub name: String,
+    pub rooms: Vec<RoomFixture>,
+    pub memory_relay_conns: Vec<MemoryRelayConn>,
+    pub resources: Vec<Resource<'static>>,
+}
+
+impl WorldFixture
+{
+    pub fn new(pak_name: &str, mlvl: u32, name: &str) -> WorldFixture
+    {
+        WorldFixture {
+            pak_name: pak_name.to_string(),
+            mlvl,
+            name: name.to_string(),
+            rooms: vec![],
+            memory_relay_conns: vec![],
+            resources: vec![],
+        }
+    }
+
+    pub fn room(mut self, room: RoomFixture) -> WorldFixture
+    {
+        self.rooms.push(room);
+        self
+    }
+
+    /// A resource stored uncompressed in the PAK, before the rooms
+    pub fn resource(mut self, file_id: u32, kind: ResourceKind<'static>) -> WorldFixture
+    {
+        self.resources.push(resource(file_id, kind));
+        self
+    }
+
+    pub fn savw_id(&self) -> u32
+    {
+        self.mlvl + 1
+    }
+
+    pub fn name_strg_id(&self) -> u32
+    {
+        self.mlvl + 2
+    }
+
+    fn mlvl(&self) -> Mlvl<'static>
+    {
+        let layer_names: Vec<Vec<CStr>> = self.rooms.iter()
+            .map(|room| room.layers.iter().map(|layer| cstr(&layer.name)).collect())
+            .collect();
+        Mlvl {
+            world_name_strg: ResId::new(self.name_strg_id()),
+            world_savw: ResId::new(self.savw_id()),
+            default_skybox_cmdl: ResId::invalid(),
+            memory_relay_conns: self.memory_relay_conns.clone().into(),
+            areas: self.rooms.iter().map(RoomFixture::area).collect::<Vec<_>>().into(),
+            world_map_mapw: u32::MAX,
+            audio_groups: empty_array(),
+            area_layer_flags: self.rooms.iter().map(RoomFixture::layer_flags).collect::<Vec<_>>().into(),
+            area_layer_names: layer_names.into(),
+        }
+    }
+
+    fn savw(&self) -> Savw<'static>
+    {
+        Savw {
+            area_count: self.rooms.len() as u32,
+            cinematic_skip_array: vec![].into(),
+            memory_relay_array: empty_array(),
+            layer_toggle_array: empty_array(),
+            door_array: empty_array(),
+            scan_array: vec![].into(),
+        }
+    }
+
+    pub fn pak(&self) -> Vec<u8>
+    {
+        let mut resources = vec![
+            resource(self.name_strg_id(), ResourceKind::Strg(strg(&self.name))),
+            resource(self.savw_id(), ResourceKind::Savw(self.savw())),
+        ];
+        resources.extend(self.resources.iter().cloned());
+        for room in self.rooms.iter() {
+            resources.push(resource(room.name_strg, ResourceKind::Strg(strg(&room.name))));
+            resources.push(resource(room.mrea, ResourceKind::Mrea(room.mrea())));
+        }
+        // PrimePatcher writes its edited copy of the MLVL back when it reaches it, so it has to
+        // come after every MREA (as it does in the game's PAKs)
+        resources.push(resource(self.mlvl, ResourceKind::Mlvl(self.mlvl())));
+
+        let pak = Pak {
+            start: Reader::new(&[]),
+            unused: 0,
+            named_resources: empty_array(),
+            resources: resources.into_iter().collect(),
+        };
+        to_bytes(&pak)
+    }
+}
+
+/// A GameCube disc image containing only the given files, all in the root directory
+pub struct DiscFixture
+{
+    pub game_id: [u8; 6],
+    pub version: u8,
+    pub files: Vec<(String, Vec<u8>)>,
+}
+
+impl DiscFixture
+{
+    /// An NTSC-U 0-00 disc
+    pub fn new() -> DiscFixture
+    {
+        DiscFixture {
+            game_id: *b"GM8E01",
+            version: 0,
+            files: vec![],
+        }
+    }
+
+    pub fn world(self, world: &WorldFixture) -> DiscFixture
+    {
+        let pak = world.pak();
+        self.file(&world.pak_name, pak)
+    }
+
+    pub fn file(mut self, name: &str, contents: Vec<u8>) -> DiscFixture
+    {
+        self.files.push((name.to_string(), contents));
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8>
+    {
+        // Disc header, header info and an apploader without any code
+        const FST_OFFSET: usize = 0x440 + 0x2000 + 0x20;
+        const FST_ENTRY_SIZE: usize = 12;
+
+        // The root directory's name comes first in the string table
+        let mut string_table = vec![0];
+        let mut name_offsets = vec![];
+        for (name, _) in self.files.iter() {
+            name_offsets.push(string_table.len());
+            string_table.extend_from_slice(name.as_bytes());
+            string_table.push(0);
+        }
+        let fst_len = FST_ENTRY_SIZE * (self.files.len() + 1) + string_table.len();
+
+        let mut file_offsets = vec![];
+        let mut disc_len = align32(FST_OFFSET + fst_len);
+        for (_, contents) in self.files.iter() {
+            file_offsets.push(disc_len);
+            disc_len = align32(disc_len + contents.len());
+        }
+
+        let mut disc = vec![0; disc_len];
+        disc[..6].copy_from_slice(&self.game_id);
+        disc[7] = self.version;
+        put_u32(&mut disc, 0x1C, 0xC2339F3D);
+        let dol_offset = self.files.iter()
+            .position(|(name, _)| name == "default.dol")
+            .map(|i| file_offsets[i])
+            .unwrap_or(0);
+        put_u32(&mut disc, 0x420, dol_offset as u32);
+        put_u32(&mut disc, 0x424, FST_OFFSET as u32);
+        put_u32(&mut disc, 0x428, fst_len as u32);
+        put_u32(&mut disc, 0x42C, fst_len as u32);
+
+        disc[FST_OFFSET] = 1;
+        put_u32(&mut disc, FST_OFFSET + 8, self.files.len() as u32 + 1);
+        for (i, (_, contents)) in self.files.iter().enumerate() {
+            let entry = FST_OFFSET + FST_ENTRY_SIZE * (i + 1);
+            put_u32(&mut disc, entry, name_offsets[i] as u32);
+            put_u32(&mut disc, entry + 4, file_offsets[i] as u32);
+            put_u32(&mut disc, entry + 8, contents.len() as u32);
+            disc[file_offsets[i]..file_offsets[i] + contents.len()].copy_from_slice(contents);
+        }
+        let string_table_offset = FST_OFFSET + FST_ENTRY_SIZE * (self.files.len() + 1);
+        disc[string_table_offset..string_table_offset + string_table.len()]
+            .copy_from_slice(&string_table);
+
+        disc
+    }
+}
+
+impl Default for DiscFixture
+{
+    fn default() -> DiscFixture
+    {
+        DiscFixture::new()
+    }
+}
+
+/*** Script objects ***/
+
+pub fn object<'r>(instance_id: u32, property_data: impl Into<SclyProperty<'r>>) -> SclyObject<'r>
+{
+    SclyObject {
+        instance_id,
+        connections: vec![].into(),
+        property_data: property_data.into(),
+    }
+}
+
+pub fn connection(state: ConnectionState, message: ConnectionMsg, target_object_id: u32) -> Connection
+{
+    Connection {
+        state,
+        message,
+        target_object_id,
+    }
+}
+
+/// Instance ids carry their layer and area index
+pub fn instance_id(layer: u32, area: u32, index: u32) -> u32
+{
+    layer << 26 | area << 16 | index
+}
+
+pub fn cstr(s: &str) -> CStr<'static>
+{
+    Cow::Owned(CString::new(s).unwrap())
+}
+
+/*** Inspecting discs ***/
+
+pub fn pak_mut<'a, 'r>(disc: &'a mut GcDisc<'r>, name: &str) -> &'a mut Pak<'r>
+{
+    let entry = disc.file_system_root.dir_files_iter_mut()
+        .find(|(path, _)| path == name.as_bytes())
+        .map(|(_, entry)| entry)
+        .unwrap_or_else(|| panic!("{} isn't on the disc", name));
+    entry.guess_kind();
+    match entry.file_mut() {
+        Some(structs::FstEntryFile::Pak(pak)) => pak,
+        _ => unreachable!(),
+    }
+}
+
+pub fn find_resource<'r>(pak: &Pak<'r>, fourcc: &[u8; 4], id: u32) -> Resource<'r>
+{
+    pak.resources.iter()
+        .find(|res| res.fourcc() == fourcc.into() && res.file_id == id)
+        .map(|res| res.into_owned())
+        .unwrap_or_else(|| panic!("0x{:08X}.{} isn't in the PAK", id, String::from_utf8_lossy(fourcc)))
+}
+
+pub fn find_mlvl<'r>(pak: &Pak<'r>) -> Mlvl<'r>
+{
+    pak.resources.iter()
+        .find(|res| res.fourcc() == b"MLVL".into())
+        .expect("The PAK doesn't have an MLVL")
+        .kind.as_mlvl().unwrap().into_owned()
+}
+
+pub fn find_scly<'r>(pak: &Pak<'r>, mrea: u32) -> Scly<'r>
+{
+    let mrea = find_resource(pak, b"MREA", mrea).kind.as_mrea().unwrap().into_owned();
+    mrea.scly_section().into_owned()
+}
+
+pub fn layer_objects<'r>(scly: &Scly<'r>, layer: usize) -> Vec<SclyObject<'r>>
+{
+    let layer = scly.layers.iter().nth(layer).expect("No such layer");
+    layer.objects.iter().map(|obj| obj.into_owned()).collect()
+}
+
+pub fn to_bytes<W: Writable>(w: &W) -> Vec<u8>
+{
+    let mut bytes = vec![];
+    w.write_to(&mut bytes).unwrap();
+    bytes
+}
+
+/*** Helpers ***/
+
+fn strg(s: &str) -> Strg<'static>
+{
+    Strg::from_strings(vec![format!("{}\0", s)])
+}
+
+fn empty_array<'r, T>() -> RoArray<'r, T>
+    where T: Readable<'r, Args = ()>
+{
+    Reader::new(&[]).read((0, ()))
+}
+
+#[cfg(not(debug_assertions))]
+fn resource<'r>(file_id: u32, kind: ResourceKind<'r>) -> Resource<'r>
+{
+    Resource {
+        compressed: false,
+        file_id,
+        kind,
+    }
+}
+
+#[cfg(debug_assertions)]
+fn resource<'r>(file_id: u32, kind: ResourceKind<'r>) -> Resource<'r>
+{
+    Resource {
+        compressed: false,
+        file_id,
+        kind,
+        original_offset: 0,
+    }
+}
+
+fn align32(n: usize) -> usize
+{
+    (n + 31) & !31
+}
+
+fn put_u32(buf: &mut [u8], offset: usize, value: u32)
+{
+    buf[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+}
@@ -1,5 +1,7 @@
 use clap::{Arg, App, SubCommand};
 
+use std::path::Path;
+
 use dol_linker::{read_symbol_table, link_obj_files_to_bin, link_obj_files_to_rel};
 
 fn main()
@@ -26,6 +28,11 @@ fn main()
                 .short("o")
                 .takes_value(true)
                 .default_value("output.rel"))
+            .arg(Arg::with_name("map")
+                .long("map")
+                .short("m")
+                .takes_value(true)
+                .help("Write a map of section/symbol locations and imports to this file"))
             .arg(Arg::with_name("obj-files")
                 .required(true)
                 .multiple(true)
@@ -46,6 +53,11 @@ fn main()
                 .short("o")
                 .takes_value(true)
                 .default_value("output.bin"))
+            .arg(Arg::with_name("map")
+                .long("map")
+                .short("m")
+                .takes_value(true)
+                .help("Write a map of section/symbol locations and imports to this file"))
             .arg(Arg::with_name("obj-files")
                 .required(true)
                 .multiple(true)
@@ -75,6 +87,7 @@ fn main()
             matches.values_of("obj-files").unwrap(),
             &extern_sym_table,
             matches.value_of("output").unwrap(),
+            matches.value_of("map").map(Path::new),
         ).unwrap();
     } else if let Some(matches) = matches.subcommand_matches("bin") {
 
@@ -88,6 +101,7 @@ fn main()
             load_addr,
             &extern_sym_table,
             matches.value_of("output").unwrap(),
+            matches.value_of("map").map(Path::new),
         ).unwrap();
 
         println!("Exported symbol addresses:");
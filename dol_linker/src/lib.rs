@@ -3,14 +3,13 @@ use enum_map::{Enum, EnumMap};
 use goblin::elf::{self, Elf};
 use goblin::Object;
 
-use memmap::{Mmap, MmapOptions};
-
 use scroll::{ctx, IOwrite, Cwrite, SizeWith};
 use snafu::{ensure, OptionExt, ResultExt, Snafu};
 
 
 use std::collections::HashMap;
-use std::fs::File;
+use std::fs::{self, File};
+use std::fmt::Write as _;
 use std::io::{BufRead, BufReader, Write, Seek, SeekFrom};
 use std::iter;
 use std::path::{Path, PathBuf};
@@ -616,7 +615,8 @@ struct Section<'a>
     alignment: u8,
     is_executable: bool,
     is_bss: bool,
-    exported_symbols: Vec<(&'a str, u32, SymbolVis)>,
+    // (name, offset, size, visibility)
+    exported_symbols: Vec<(&'a str, u32, u32, SymbolVis)>,
     relocations: Vec<Relocation<'a>>,
 }
 
@@ -646,6 +646,7 @@ impl<'a> Section<'a>
                 (
                     elf.strtab.get(sym.st_name).unwrap().unwrap(),
                     sym.st_value as u32,
+                    sym.st_size as u32,
                     SymbolVis::from_st_other(sym.st_other),
                 )
             })
@@ -687,6 +688,7 @@ impl<'a> Section<'a>
                     vec![(
                         elf.strtab.get(sym.st_name).unwrap().unwrap(),
                         0,
+                        sym.st_size as u32,
                         SymbolVis::from_st_other(sym.st_other),
                     )]
                 } else {
@@ -804,40 +806,36 @@ struct SectionInfo<'a>
     rel_section_index: Option<u8>,
 }
 
-fn mmap_obj_files(obj_file_names: impl Iterator<Item = impl AsRef<Path>>)
-    -> Result<Vec<(PathBuf, Mmap)>>
+// Read rather than memory mapped so the crate also builds for targets without mmap (wasm)
+fn read_obj_files(obj_file_names: impl Iterator<Item = impl AsRef<Path>>)
+    -> Result<Vec<(PathBuf, Vec<u8>)>>
 {
-    let mut mmaps = vec![];
+    let mut bufs = vec![];
     for fname in obj_file_names {
         let fname = fname.as_ref();
-        let f = File::open(fname)
+        let buf = fs::read(fname)
             .with_context(|| OpenFile { filename: fname.to_path_buf() })?;
-        let mmap = unsafe {
-            MmapOptions::new()
-                .map(&f)
-                .with_context(|| OpenFile { filename: fname.to_path_buf() })?
-        };
-        mmaps.push((fname.to_path_buf(), mmap));
+        bufs.push((fname.to_path_buf(), buf));
     }
 
-    Ok(mmaps)
+    Ok(bufs)
 }
 
-fn object_files_from_mmaps(mmaps: &[(PathBuf, Mmap)]) -> Result<Vec<ObjectFile>>
+fn object_files_from_bufs(bufs: &[(PathBuf, Vec<u8>)]) -> Result<Vec<ObjectFile>>
 {
     let mut object_files = vec![];
-    for (filename, mmap) in mmaps.iter() {
-        match Object::parse(mmap).with_context(|| ObjectParsing { filename })? {
+    for (filename, buf) in bufs.iter() {
+        match Object::parse(buf).with_context(|| ObjectParsing { filename })? {
             Object::Elf(elf) => {
-                object_files.push(ObjectFile::from_elf(mmap, elf));
+                object_files.push(ObjectFile::from_elf(buf, elf));
             },
             Object::Archive(ar) => {
                 for member_name in ar.members() {
-                    let buf = ar.extract(member_name, mmap)
+                    let member = ar.extract(member_name, buf)
                         .with_context(|| ObjectParsing { filename })?;
-                    let elf = Elf::parse(buf)
+                    let elf = Elf::parse(member)
                         .with_context(|| ObjectParsing { filename })?;
-                    object_files.push(ObjectFile::from_elf(buf, elf));
+                    object_files.push(ObjectFile::from_elf(member, elf));
                 }
             },
             _ => {
@@ -861,7 +859,7 @@ fn filter_unused_sections<'a>(object_files: &'a [ObjectFile<'a>])
     let mut sections_in_question = vec![];
     for (of_i, of) in object_files.iter().enumerate() {
         for (sec_i, sec) in of.sections.iter().enumerate() {
-            if sec.exported_symbols.iter().any(|(_, _, vis)| *vis == SymbolVis::Default) {
+            if sec.exported_symbols.iter().any(|(_, _, _, vis)| *vis == SymbolVis::Default) {
                 sections_to_keep.push((of_i, sec_i, sec));
             } else {
                 sections_in_question.push((of_i, sec_i, sec));
@@ -895,7 +893,7 @@ fn filter_unused_sections<'a>(object_files: &'a [ObjectFile<'a>])
                             // Check if any of sec_k's External relocs match sec_q's symbols
                             sec_q.exported_symbols
                                 .iter()
-                                .any(|(sym_name_q, _, _)| sym_name_k == *sym_name_q)
+                                .any(|(sym_name_q, _, _, _)| sym_name_k == *sym_name_q)
                         },
                         _ => false,
                     }
@@ -1037,7 +1035,7 @@ fn build_local_symbol_table<'a, 'b: 'a>(
     // Populate the local symbol table
     for (sec_type, rs) in rel_sections.iter() {
         for loc_sec in rs.sections.iter() {
-            for (sym_name, sym_offset, _) in loc_sec.exported_symbols.iter() {
+            for (sym_name, sym_offset, _, _) in loc_sec.exported_symbols.iter() {
                 let o = local_sym_table.insert(*sym_name, (sec_type, loc_sec.offset + sym_offset));
                 // TODO: If we have a STV_SINGLETON symbol, we would actually want to not error
                 // out, but instead keep only one of the two symbols
@@ -1128,15 +1126,94 @@ fn write_relocated_section_data(
 }
 
 
+/// Writes a human readable map of where every section and symbol was placed, followed by the
+/// external symbols the output imports. `rel_section_locations` holds the absolute address of
+/// each section for bin images; for RELs it's empty and locations are written as
+/// `section index:offset` instead, since the sections are only placed when the REL is loaded.
+fn write_link_map(
+    map_file_name: &Path,
+    rel_sections: &EnumMap<RelSectionType, SectionInfo>,
+    rel_section_locations: &EnumMap<RelSectionType, Option<u32>>,
+    local_sym_table: &HashMap<&str, (RelSectionType, u32)>,
+    extern_sym_table: &HashMap<String, u32>,
+) -> Result<()>
+{
+    let location = |sec_type: RelSectionType, offset: u32| {
+        match (rel_section_locations[sec_type], rel_sections[sec_type].rel_section_index) {
+            (Some(addr), _) => format!("0x{:08x}", addr + offset),
+            (None, Some(index)) => format!("{}:0x{:08x}", index, offset),
+            (None, None) => format!("?:0x{:08x}", offset),
+        }
+    };
+
+    let mut map = String::new();
+    writeln!(map, "Sections").unwrap();
+    writeln!(map, "  Type   Location       Size       Align").unwrap();
+    for (sec_type, rs) in rel_sections.iter() {
+        if rs.size == 0 {
+            continue
+        }
+        writeln!(map, "  {:<6} {:<14} 0x{:08x} {}",
+                 format!("{:?}", sec_type), location(sec_type, 0), rs.size, rs.alignment).unwrap();
+    }
+
+    writeln!(map).unwrap();
+    writeln!(map, "Layout").unwrap();
+    writeln!(map, "  Location       Size       Name").unwrap();
+    for (sec_type, rs) in rel_sections.iter() {
+        for loc_sec in rs.sections.iter() {
+            writeln!(map, "  {:<14} 0x{:08x} {}",
+                     location(sec_type, loc_sec.offset), loc_sec.size(), loc_sec.name).unwrap();
+
+            let mut symbols = loc_sec.exported_symbols.clone();
+            symbols.sort_by_key(|(_, offset, _, _)| *offset);
+            for (sym_name, sym_offset, sym_size, vis) in symbols {
+                writeln!(map, "    {:<12} 0x{:08x} {}{}",
+                         location(sec_type, loc_sec.offset + sym_offset),
+                         sym_size,
+                         sym_name,
+                         if vis == SymbolVis::Hidden { " (hidden)" } else { "" }).unwrap();
+            }
+        }
+    }
+
+    // Every symbol referenced by a relocation but not defined by the linked objects
+    let mut imports = rel_sections.values()
+        .flat_map(|rs| rs.sections.iter())
+        .flat_map(|loc_sec| loc_sec.relocations.iter())
+        .filter_map(|reloc| match reloc.kind {
+            RelocationKind::ExternalSymbol(sym_name) if !local_sym_table.contains_key(sym_name) =>
+                Some(sym_name),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    imports.sort();
+    imports.dedup();
+
+    writeln!(map).unwrap();
+    writeln!(map, "Imports").unwrap();
+    for sym_name in imports {
+        match extern_sym_table.get(sym_name) {
+            Some(addr) => writeln!(map, "  0x{:08x}     {}", addr, sym_name).unwrap(),
+            None => writeln!(map, "  UNRESOLVED     {}", sym_name).unwrap(),
+        }
+    }
+
+    std::fs::write(map_file_name, map)
+        .with_context(|| WriteFile { filename: map_file_name })?;
+    Ok(())
+}
+
 pub fn link_obj_files_to_rel<'a>(
     obj_file_names: impl Iterator<Item = impl AsRef<Path>>,
     extern_sym_table: &HashMap<String, u32>,
     output_file_name: impl AsRef<Path>,
+    map_file_name: Option<&Path>,
 ) -> Result<()>
 {
 
-    let mmaps = mmap_obj_files(obj_file_names)?;
-    let object_files = object_files_from_mmaps(&mmaps)?;
+    let bufs = read_obj_files(obj_file_names)?;
+    let object_files = object_files_from_bufs(&bufs)?;
 
     let sections_to_keep = filter_unused_sections(&object_files);
     // TODO: Print the names of the sections we're keeping, for the sake of debugging the resulting
@@ -1148,6 +1225,17 @@ pub fn link_obj_files_to_rel<'a>(
     let mut section_boundary_symbol_names = HashMap::new();
     let local_sym_table = build_local_symbol_table(&rel_sections, &mut section_boundary_symbol_names)?;
 
+    // Written before any symbol is resolved so the map is available even if linking fails
+    if let Some(map_file_name) = map_file_name {
+        write_link_map(
+            map_file_name,
+            &rel_sections,
+            &EnumMap::default(),
+            &local_sym_table,
+            extern_sym_table,
+        )?;
+    }
+
     // Build the lists of relocations that will be included in the REL
     let mut dol_relocations = EnumMap::<_, Vec<_>>::default();
     let mut dol_curr_offsets = EnumMap::default();
@@ -1364,11 +1452,12 @@ pub fn link_obj_files_to_bin<'a>(
     load_addr: u32,
     extern_sym_table: &HashMap<String, u32>,
     output_file_name: impl AsRef<Path>,
+    map_file_name: Option<&Path>,
 ) -> Result<Vec<(String, u32)>>
 {
 
-    let mmaps = mmap_obj_files(obj_file_names)?;
-    let object_files = object_files_from_mmaps(&mmaps)?;
+    let bufs = read_obj_files(obj_file_names)?;
+    let object_files = object_files_from_bufs(&bufs)?;
 
     let sections_to_keep = filter_unused_sections(&object_files);
 
@@ -1378,6 +1467,24 @@ pub fn link_obj_files_to_bin<'a>(
     let mut section_boundary_symbol_names = HashMap::new();
     let local_sym_table = build_local_symbol_table(&rel_sections, &mut section_boundary_symbol_names)?;
 
+    let mut curr_addr = load_addr;
+    let mut rel_section_locations = EnumMap::default();
+    for (sec_type, rs) in rel_sections.iter() {
+        curr_addr = align_to(curr_addr, rs.alignment);
+        rel_section_locations[sec_type] = Some(curr_addr);
+        curr_addr += rs.size;
+    }
+
+    if let Some(map_file_name) = map_file_name {
+        write_link_map(
+            map_file_name,
+            &rel_sections,
+            &rel_section_locations,
+            &local_sym_table,
+            extern_sym_table,
+        )?;
+    }
+
     for rs in rel_sections.values() {
         for loc_sec in rs.sections.iter() {
             for reloc in loc_sec.relocations.iter() {
@@ -1396,14 +1503,6 @@ pub fn link_obj_files_to_bin<'a>(
         }
     }
 
-    let mut curr_addr = load_addr;
-    let mut rel_section_locations = EnumMap::default();
-    for (sec_type, rs) in rel_sections.iter() {
-        curr_addr = align_to(curr_addr, rs.alignment);
-        rel_section_locations[sec_type] = Some(curr_addr);
-        curr_addr += rs.size;
-    }
-
     let output_file_name = output_file_name.as_ref();
     let mut output_file = File::create(output_file_name)
         .with_context(|| WriteFile { filename: output_file_name })?;
@@ -1502,8 +1601,8 @@ fn test_external_symbol_table()
 #[test]
 fn test_read_objects()
 {
-    let mmaps = mmap_obj_files(["test_data/func_a.o", "test_data/func_b.o"].iter()).unwrap();
-    let object_files = object_files_from_mmaps(&mmaps).unwrap();
+    let bufs = read_obj_files(["test_data/func_a.o", "test_data/func_b.o"].iter()).unwrap();
+    let object_files = object_files_from_bufs(&bufs).unwrap();
 
     assert_eq!(object_files.len(), 2);
 
@@ -1515,7 +1614,42 @@ fn test_read_objects()
 #[test]
 fn test_filter_sections()
 {
-    let mmaps = mmap_obj_files(["test_data/func_a.o", "test_data/func_b.o"].iter()).unwrap();
-    let object_files = object_files_from_mmaps(&mmaps).unwrap();
+    let bufs = read_obj_files(["test_data/func_a.o", "test_data/func_b.o"].iter()).unwrap();
+    let object_files = object_files_from_bufs(&bufs).unwrap();
     let _sections_to_keep = filter_unused_sections(&object_files);
 }
+
+#[test]
+fn test_link_map()
+{
+    let extern_sym_table = read_symbol_table("test_data/symbols.map").unwrap();
+    let out_dir = std::env::temp_dir();
+    let bin_path = out_dir.join("dol_linker_test_link_map.bin");
+    let map_path = out_dir.join("dol_linker_test_link_map.map");
+    let symbols = link_obj_files_to_bin(
+        ["test_data/func_a.o", "test_data/func_b.o"].iter(),
+        0x80001000,
+        &extern_sym_table,
+        &bin_path,
+        Some(&map_path),
+    ).unwrap();
+
+    let map = std::fs::read_to_string(&map_path).unwrap();
+    for (sym_name, addr) in symbols {
+        assert!(map.contains(&format!("0x{:08x} ", addr)), "{} missing from map", sym_name);
+        assert!(map.contains(&sym_name), "{} missing from map", sym_name);
+    }
+    assert!(map.contains("Imports"));
+
+    // REL sections aren't placed until load time, so locations are section relative
+    let rel_path = out_dir.join("dol_linker_test_link_map.rel");
+    link_obj_files_to_rel(
+        ["test_data/func_a.o", "test_data/func_b.o"].iter(),
+        &extern_sym_table,
+        &rel_path,
+        Some(&map_path),
+    ).unwrap();
+    let map = std::fs::read_to_string(&map_path).unwrap();
+    assert!(map.contains("1:0x00000000"));
+    assert!(map.contains("0x80001230     printf"));
+}
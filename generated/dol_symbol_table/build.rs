@@ -0,0 +1,57 @@
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+// Address-ordered copies of the symbol tables, for looking symbols up by address at runtime
+fn main()
+{
+    let output_path = Path::new(&env::var("OUT_DIR").unwrap()).join("symbols_by_addr.rs");
+    let mut output_file = BufWriter::new(File::create(&output_path).unwrap());
+
+    const GAME_VERSIONS: &[(&str, &str)] = &[
+        ("1.00", "MP1_100_SYMBOLS"),
+        ("1.01", "MP1_101_SYMBOLS"),
+        ("1.02", "MP1_102_SYMBOLS"),
+        ("pal", "MP1_PAL_SYMBOLS"),
+        ("kor", "MP1_KOR_SYMBOLS"),
+        ("jpn", "MP1_JPN_SYMBOLS"),
+        ("trilogy_ntsc_j", "MP1_TRILOGY_NTSC_J_SYMBOLS"),
+        ("trilogy_ntsc_u", "MP1_TRILOGY_NTSC_U_SYMBOLS"),
+        ("trilogy_pal", "MP1_TRILOGY_PAL_SYMBOLS"),
+    ];
+
+    for (version_name, table_name) in GAME_VERSIONS {
+        let file_name = format!("{}.txt", version_name);
+        println!("cargo:rerun-if-changed={}", file_name);
+        let symbol_file = BufReader::new(File::open(&file_name).unwrap());
+
+        let mut symbols = symbol_file.lines()
+            .filter_map(|line| {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    None
+                } else {
+                    let addr = u32::from_str_radix(&line[2..10], 16).unwrap();
+                    Some((addr, line[11..].to_string()))
+                }
+            })
+            .collect::<Vec<_>>();
+        symbols.sort();
+
+        writeln!(&mut output_file, "static {}: &[(u32, &str)] = &[", table_name).unwrap();
+        for (addr, sym_name) in &symbols {
+            writeln!(&mut output_file, "    (0x{:08X}, {:?}),", addr, sym_name).unwrap();
+        }
+        writeln!(&mut output_file, "];").unwrap();
+    }
+
+    writeln!(&mut output_file, "fn symbols_by_addr(version_name: &str) -> Option<&'static [(u32, &'static str)]> {{").unwrap();
+    writeln!(&mut output_file, "    match version_name {{").unwrap();
+    for (version_name, table_name) in GAME_VERSIONS {
+        writeln!(&mut output_file, "        {:?} => Some({}),", version_name, table_name).unwrap();
+    }
+    writeln!(&mut output_file, "        _ => None,").unwrap();
+    writeln!(&mut output_file, "    }}").unwrap();
+    writeln!(&mut output_file, "}}").unwrap();
+}
@@ -15,9 +15,9 @@ fn main()
         ("pal.txt", "MP1_PAL_SYMBOL_TABLE"),
         ("kor.txt", "MP1_KOR_SYMBOL_TABLE"),
         ("jpn.txt", "MP1_JPN_SYMBOL_TABLE"),
-        // ("trilogy_ntsc_j.txt", "MP1_TRILOGY_NTSC_J_SYMBOL_TABLE"),
-        // ("trilogy_ntsc_u.txt", "MP1_TRILOGY_NTSC_U_SYMBOL_TABLE"),
-        // ("trilogy_pal.txt", "MP1_TRILOGY_PAL_SYMBOL_TABLE"),
+        ("trilogy_ntsc_j.txt", "MP1_TRILOGY_NTSC_J_SYMBOL_TABLE"),
+        ("trilogy_ntsc_u.txt", "MP1_TRILOGY_NTSC_U_SYMBOL_TABLE"),
+        ("trilogy_pal.txt", "MP1_TRILOGY_PAL_SYMBOL_TABLE"),
     ];
 
     for (file_name, table_name) in GAME_VERSIONS {
@@ -33,7 +33,7 @@ build_lookup_macros! {
     (mp1_pal_symbol, MP1_PAL_SYMBOL_TABLE, "PAL"),
     (mp1_kor_symbol, MP1_KOR_SYMBOL_TABLE, "KOR"),
     (mp1_jpn_symbol, MP1_JPN_SYMBOL_TABLE, "JPN"),
-    // (mp1_trilogy_ntsc_j_symbol, MP1_TRILOGY_NTSC_J_SYMBOL_TABLE, "Trilogy NTSC-J"),
-    // (mp1_trilogy_ntsc_u_symbol, MP1_TRILOGY_NTSC_U_SYMBOL_TABLE, "Trilogy NTSC-U"),
-    // (mp1_trilogy_pal_symbol, MP1_TRILOGY_PAL_SYMBOL_TABLE, "Trilogy PAL"),
+    (mp1_trilogy_ntsc_j_symbol, MP1_TRILOGY_NTSC_J_SYMBOL_TABLE, "Trilogy NTSC-J"),
+    (mp1_trilogy_ntsc_u_symbol, MP1_TRILOGY_NTSC_U_SYMBOL_TABLE, "Trilogy NTSC-U"),
+    (mp1_trilogy_pal_symbol, MP1_TRILOGY_PAL_SYMBOL_TABLE, "Trilogy PAL"),
 }
@@ -1,6 +1,6 @@
 pub use dol_symbol_table_macro::{
     mp1_100_symbol, mp1_101_symbol, mp1_102_symbol, mp1_pal_symbol, mp1_kor_symbol, mp1_jpn_symbol,
-    // mp1_trilogy_ntsc_j_symbol, mp1_trilogy_ntsc_u_symbol, mp1_trilogy_pal_symbol,
+    mp1_trilogy_ntsc_j_symbol, mp1_trilogy_ntsc_u_symbol, mp1_trilogy_pal_symbol,
 };
 
 pub struct Mp1Symbol
@@ -11,9 +11,9 @@ pub struct Mp1Symbol
     pub addr_pal: Option<u32>,
     pub addr_kor: Option<u32>,
     pub addr_jpn: Option<u32>,
-    // pub addr_trilogy_ntsc_u: Option<u32>,
-    // pub addr_trilogy_ntsc_j: Option<u32>,
-    // pub addr_trilogy_pal: Option<u32>,
+    pub addr_trilogy_ntsc_u: Option<u32>,
+    pub addr_trilogy_ntsc_j: Option<u32>,
+    pub addr_trilogy_pal: Option<u32>,
 }
 
 #[macro_export]
@@ -26,9 +26,32 @@ macro_rules! mp1_symbol {
             addr_pal: $crate::mp1_pal_symbol!($syn_name),
             addr_kor: $crate::mp1_kor_symbol!($syn_name),
             addr_jpn: $crate::mp1_jpn_symbol!($syn_name),
-            // addr_trilogy_ntsc_u: $crate::mp1_trilogy_ntsc_u_symbol!($syn_name),
-            // addr_trilogy_ntsc_j: $crate::mp1_trilogy_ntsc_j_symbol!($syn_name),
-            // addr_trilogy_pal: $crate::mp1_trilogy_pal_symbol!($syn_name),
+            addr_trilogy_ntsc_u: $crate::mp1_trilogy_ntsc_u_symbol!($syn_name),
+            addr_trilogy_ntsc_j: $crate::mp1_trilogy_ntsc_j_symbol!($syn_name),
+            addr_trilogy_pal: $crate::mp1_trilogy_pal_symbol!($syn_name),
         }
     }
 }
+
+include!(concat!(env!("OUT_DIR"), "/symbols_by_addr.rs"));
+
+// Addresses further than this past the nearest preceding symbol aren't attributed to it
+const MAX_SYMBOL_OFFSET: u32 = 0x1000;
+
+/// Names `addr` for the given version's symbol table ("1.00", "pal", ... as in the table file
+/// names). Addresses inside a symbol are shown as "symbol+0xoffset".
+pub fn symbol_name(version_name: &str, addr: u32) -> Option<String>
+{
+    let symbols = symbols_by_addr(version_name)?;
+    let idx = match symbols.binary_search_by_key(&addr, |(sym_addr, _)| *sym_addr) {
+        Ok(idx) => return Some(symbols[idx].1.to_string()),
+        Err(0) => return None,
+        Err(idx) => idx - 1,
+    };
+    let (sym_addr, sym_name) = symbols[idx];
+    if addr - sym_addr < MAX_SYMBOL_OFFSET {
+        Some(format!("{}+0x{:x}", sym_name, addr - sym_addr))
+    } else {
+        None
+    }
+}
@@ -73,6 +73,7 @@ fn main()
             *os_arena_hi,
             &symbol_table,
             &bin_path,
+            None,
         ).unwrap();
         let map_path = bin_path.with_extension("bin.map");
         {
@@ -88,10 +89,12 @@ fn main()
         }
 
         let rel_path = out_dir.join(format!("patches_{}.rel", version));
+        let rel_map_path = rel_path.with_extension("rel.linkmap");
         link_obj_files_to_rel(
             [target_dir.join("librel_patches.a")].iter(),
             &symbol_table,
             &rel_path,
+            Some(rel_map_path.as_path()),
         ).unwrap();
     }
 
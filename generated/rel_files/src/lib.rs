@@ -34,3 +34,27 @@ pub const PATCHES_102_REL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/pat
 pub const PATCHES_PAL_REL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/patches_pal.rel"));
 pub const PATCHES_KOR_REL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/patches_kor.rel"));
 pub const PATCHES_JPN_REL: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/patches_jpn.rel"));
+pub const PATCHES_100_REL_LINKMAP: &str = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/patches_1.00.rel.linkmap"
+    ));
+pub const PATCHES_101_REL_LINKMAP: &str = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/patches_1.01.rel.linkmap"
+    ));
+pub const PATCHES_102_REL_LINKMAP: &str = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/patches_1.02.rel.linkmap"
+    ));
+pub const PATCHES_PAL_REL_LINKMAP: &str = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/patches_pal.rel.linkmap"
+    ));
+pub const PATCHES_KOR_REL_LINKMAP: &str = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/patches_kor.rel.linkmap"
+    ));
+pub const PATCHES_JPN_REL_LINKMAP: &str = include_str!(concat!(
+        env!("OUT_DIR"),
+        "/patches_jpn.rel.linkmap"
+    ));
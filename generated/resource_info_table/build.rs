@@ -0,0 +1,40 @@
+use std::env;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+#[path = "resource_info_parser.rs"]
+mod resource_info_parser;
+
+fn main()
+{
+    let output_path = Path::new(&env::var("OUT_DIR").unwrap()).join("resource_table.rs");
+    let mut output_file = BufWriter::new(File::create(&output_path).unwrap());
+
+    let resources_path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("resource_info.txt");
+    println!("cargo:rerun-if-changed={}", resources_path.display());
+    println!("cargo:rerun-if-changed=resource_info_parser.rs");
+
+    let mut resources: Vec<(u32, String)> = vec![];
+    for res in resource_info_parser::parse_resource_info(&resources_path) {
+        let pak_names_formatted = res.paks.iter()
+            .map(|name| format!("b{:?}", name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let res_id_value = u32::from_str_radix(&res.res_id[2..], 16).unwrap();
+        resources.push((res_id_value, format!(
+            "ResourceInfo {{ long_name: {:?}, short_name: {:?}, res_id: {}, \
+             fourcc: FourCC::from_bytes(b\"{}\"), paks: &[{}] }},",
+            res.long_name, res.short_name, res.res_id, res.fourcc, pak_names_formatted
+        )));
+    }
+    resources.sort_by_key(|(res_id, _)| *res_id);
+
+    writeln!(&mut output_file, "static RESOURCE_TABLE: &[ResourceInfo] = &[").unwrap();
+    for (_, resource_data) in &resources {
+        writeln!(&mut output_file, "    {}", resource_data).unwrap();
+    }
+    writeln!(&mut output_file, "];").unwrap();
+}
@@ -0,0 +1,52 @@
+// Parser for resource_info.txt, shared by the build scripts of resource_info_table and
+// resource_info_table_macro
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub struct ResourceInfoLine
+{
+    pub long_name: String,
+    pub short_name: Option<String>,
+    pub res_id: String,
+    pub fourcc: String,
+    pub paks: Vec<String>,
+}
+
+pub fn parse_resource_info(path: &Path) -> Vec<ResourceInfoLine>
+{
+    let resources_file = BufReader::new(File::open(path).unwrap());
+
+    let mut resources = vec![];
+    for line in resources_file.lines() {
+        let line = line.unwrap();
+        if line.is_empty() {
+            continue;
+        }
+        let mut parts = line.split('"');
+        assert_eq!(parts.next(), Some(""));
+        let long_name = parts.next().unwrap();
+        let res_id = &parts.next().unwrap()[2..12];
+        let res_type = parts.next().unwrap();
+        let mut pak_names = parts
+            .filter(|s| !(s.contains(',') || s.contains('[') || s.contains(']') || s.is_empty()))
+            .collect::<Vec<_>>();
+
+        let last_pak = pak_names.last().unwrap();
+        assert!(last_pak.len() > 3, "{:?} {:?}", long_name, pak_names);
+        let short_name = if last_pak[last_pak.len() - 4..].to_lowercase() != ".pak" {
+            pak_names.pop()
+        } else {
+            None
+        };
+
+        resources.push(ResourceInfoLine {
+            long_name: long_name.to_string(),
+            short_name: short_name.map(|s| s.to_string()),
+            res_id: res_id.to_string(),
+            fourcc: res_type.to_string(),
+            paks: pak_names.iter().map(|s| s.to_string()).collect(),
+        });
+    }
+    resources
+}
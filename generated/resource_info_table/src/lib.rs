@@ -36,3 +36,15 @@ impl<'a> Into<(&'a [u8], u32)> for ResourceInfo
         (self.paks[0], self.res_id)
     }
 }
+
+include!(concat!(env!("OUT_DIR"), "/resource_table.rs"));
+
+/// Runtime counterpart to `resource_info!` for resources that are only known by id (e.g. when
+/// walking the contents of a PAK).
+pub fn lookup_resource_info(res_id: u32, fourcc: FourCC) -> Option<&'static ResourceInfo>
+{
+    RESOURCE_TABLE.binary_search_by_key(&res_id, |info| info.res_id)
+        .ok()
+        .map(|i| &RESOURCE_TABLE[i])
+        .filter(|info| info.fourcc == fourcc)
+}
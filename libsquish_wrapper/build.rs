@@ -1,6 +1,7 @@
 
 fn main()
 {
+    #[cfg(feature = "native")]
     cc::Build::new()
         .include("libSquish")
         .file("src/wrapper.cpp")
@@ -0,0 +1,464 @@
+// Pure-Rust encoder/decoder for the GameCube flavour of DXT1 used by CMPR textures. Compared to
+// PC DXT1 the endpoints are stored big-endian and each row's indices are packed with the
+// leftmost pixel in the high bits. Decoding matches libsquish's, including the 3 color +
+// transparent mode used whenever color0 <= color1.
+//
+// The encoder follows libsquish's iterative cluster fit: the distinct colors are ordered along
+// their principal axis, every way of splitting that ordering into the palette's clusters is
+// solved for the least-squares endpoints, and the axis is re-derived from the best endpoints
+// until the ordering stops changing.
+
+// Perceptual channel weights, the same ones libsquish uses by default
+const METRIC: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+const MAX_ITERATIONS: usize = 8;
+
+type Vec3 = [f32; 3];
+
+fn dot(a: Vec3, b: Vec3) -> f32
+{
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn unpack_565(c: u16) -> [u8; 3]
+{
+    let r = ((c >> 11) & 0x1f) as u8;
+    let g = ((c >> 5) & 0x3f) as u8;
+    let b = (c & 0x1f) as u8;
+    [(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2)]
+}
+
+fn pack_565(v: Vec3) -> u16
+{
+    let r = (v[0] * 31.0 + 0.5).clamp(0.0, 31.0) as u16;
+    let g = (v[1] * 63.0 + 0.5).clamp(0.0, 63.0) as u16;
+    let b = (v[2] * 31.0 + 0.5).clamp(0.0, 31.0) as u16;
+    (r << 11) | (g << 5) | b
+}
+
+// Snaps a color to the nearest value the endpoints can hold
+fn quantize(v: Vec3) -> Vec3
+{
+    let c = unpack_565(pack_565(v));
+    [c[0] as f32 / 255.0, c[1] as f32 / 255.0, c[2] as f32 / 255.0]
+}
+
+fn palette(c0: u16, c1: u16) -> [[u8; 4]; 4]
+{
+    let a = unpack_565(c0);
+    let b = unpack_565(c1);
+    let mut palette = [[0u8; 4]; 4];
+    for i in 0..3 {
+        palette[0][i] = a[i];
+        palette[1][i] = b[i];
+        if c0 > c1 {
+            palette[2][i] = ((2 * a[i] as u16 + b[i] as u16) / 3) as u8;
+            palette[3][i] = ((a[i] as u16 + 2 * b[i] as u16) / 3) as u8;
+        } else {
+            palette[2][i] = ((a[i] as u16 + b[i] as u16) / 2) as u8;
+        }
+    }
+    palette[0][3] = 0xff;
+    palette[1][3] = 0xff;
+    palette[2][3] = 0xff;
+    palette[3][3] = if c0 > c1 { 0xff } else { 0 };
+    palette
+}
+
+fn is_transparent(pixel: &[u8; 4]) -> bool
+{
+    pixel[3] < 128
+}
+
+// The distinct opaque colors of a block and how many pixels use each of them
+struct ColorSet
+{
+    points: Vec<Vec3>,
+    weights: Vec<f32>,
+    transparent: bool,
+}
+
+impl ColorSet
+{
+    fn new(rgba: &[[u8; 4]; 16]) -> ColorSet
+    {
+        let mut colors: Vec<[u8; 3]> = Vec::with_capacity(16);
+        let mut weights = Vec::with_capacity(16);
+        let mut transparent = false;
+        for pixel in rgba.iter() {
+            if is_transparent(pixel) {
+                transparent = true;
+                continue;
+            }
+            let color = [pixel[0], pixel[1], pixel[2]];
+            match colors.iter().position(|c| *c == color) {
+                Some(i) => weights[i] += 1.0,
+                None => {
+                    colors.push(color);
+                    weights.push(1.0);
+                },
+            }
+        }
+
+        let points = colors.iter()
+            .map(|c| [c[0] as f32 / 255.0, c[1] as f32 / 255.0, c[2] as f32 / 255.0])
+            .collect();
+        ColorSet { points, weights, transparent }
+    }
+
+    fn principal_axis(&self) -> Vec3
+    {
+        let total: f32 = self.weights.iter().sum();
+        let mut centroid = [0.0; 3];
+        for (p, w) in self.points.iter().zip(self.weights.iter()) {
+            for i in 0..3 {
+                centroid[i] += p[i] * w / total;
+            }
+        }
+
+        let mut covariance = [[0.0f32; 3]; 3];
+        for (p, w) in self.points.iter().zip(self.weights.iter()) {
+            let d = [p[0] - centroid[0], p[1] - centroid[1], p[2] - centroid[2]];
+            for i in 0..3 {
+                for j in 0..3 {
+                    covariance[i][j] += d[i] * d[j] * w;
+                }
+            }
+        }
+
+        // Power iteration
+        let mut axis = [1.0f32, 1.0, 1.0];
+        for _ in 0..8 {
+            let next = [
+                dot(covariance[0], axis),
+                dot(covariance[1], axis),
+                dot(covariance[2], axis),
+            ];
+            let len = dot(next, next).sqrt();
+            if len < f32::EPSILON {
+                break;
+            }
+            axis = [next[0] / len, next[1] / len, next[2] / len];
+        }
+        axis
+    }
+}
+
+// Sums over the points assigned to one cluster
+#[derive(Copy, Clone, Default)]
+struct ClusterSum
+{
+    weight: f32,
+    x: Vec3,
+}
+
+impl ClusterSum
+{
+    fn between(prefix: &[ClusterSum], start: usize, end: usize) -> ClusterSum
+    {
+        let (s, e) = (prefix[start], prefix[end]);
+        ClusterSum {
+            weight: e.weight - s.weight,
+            x: [e.x[0] - s.x[0], e.x[1] - s.x[1], e.x[2] - s.x[2]],
+        }
+    }
+}
+
+// Finds the least-squares endpoints for points whose position between endpoint `a` (alpha = 1)
+// and `b` (alpha = 0) is given by their cluster, then returns them quantized along with their
+// error (minus a term that's the same for every split of the same points)
+fn solve_clusters(clusters: &[(ClusterSum, f32)]) -> Option<(Vec3, Vec3, f32)>
+{
+    let mut alpha2 = 0.0;
+    let mut beta2 = 0.0;
+    let mut alphabeta = 0.0;
+    let mut alphax = [0.0; 3];
+    let mut betax = [0.0; 3];
+    for (sum, alpha) in clusters.iter() {
+        let beta = 1.0 - alpha;
+        alpha2 += alpha * alpha * sum.weight;
+        beta2 += beta * beta * sum.weight;
+        alphabeta += alpha * beta * sum.weight;
+        for i in 0..3 {
+            alphax[i] += alpha * sum.x[i];
+            betax[i] += beta * sum.x[i];
+        }
+    }
+
+    let det = alpha2 * beta2 - alphabeta * alphabeta;
+    if det.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let mut a = [0.0; 3];
+    let mut b = [0.0; 3];
+    for i in 0..3 {
+        a[i] = ((alphax[i] * beta2 - betax[i] * alphabeta) / det).clamp(0.0, 1.0);
+        b[i] = ((betax[i] * alpha2 - alphax[i] * alphabeta) / det).clamp(0.0, 1.0);
+    }
+    let (a, b) = (quantize(a), quantize(b));
+
+    let mut error = 0.0;
+    for i in 0..3 {
+        let e = a[i] * a[i] * alpha2 + b[i] * b[i] * beta2 + 2.0 * a[i] * b[i] * alphabeta
+            - 2.0 * a[i] * alphax[i] - 2.0 * b[i] * betax[i];
+        error += e * METRIC[i] * METRIC[i];
+    }
+    Some((a, b, error))
+}
+
+// Returns the endpoints of the best fit using 4 colors, or 3 colors when `four_colors` is false
+fn cluster_fit(set: &ColorSet, four_colors: bool) -> Option<(u16, u16)>
+{
+    let n = set.points.len();
+    let mut axis = set.principal_axis();
+    let mut order: Vec<usize> = vec![];
+    let mut best: Option<(Vec3, Vec3, f32)> = None;
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut new_order: Vec<usize> = (0..n).collect();
+        new_order.sort_by(|&i, &j| {
+            dot(set.points[i], axis).partial_cmp(&dot(set.points[j], axis)).unwrap()
+        });
+        if new_order == order {
+            break;
+        }
+        order = new_order;
+
+        let mut prefix = vec![ClusterSum::default(); n + 1];
+        for (k, &i) in order.iter().enumerate() {
+            let w = set.weights[i];
+            let p = set.points[i];
+            prefix[k + 1] = ClusterSum {
+                weight: prefix[k].weight + w,
+                x: [prefix[k].x[0] + p[0] * w, prefix[k].x[1] + p[1] * w, prefix[k].x[2] + p[2] * w],
+            };
+        }
+
+        let mut improved = false;
+        let mut try_split = |clusters: &[(ClusterSum, f32)]| {
+            if let Some((a, b, error)) = solve_clusters(clusters) {
+                if best.map(|best| error < best.2).unwrap_or(true) {
+                    best = Some((a, b, error));
+                    improved = true;
+                }
+            }
+        };
+        for i in 0..=n {
+            for j in i..=n {
+                if four_colors {
+                    for k in j..=n {
+                        try_split(&[
+                            (ClusterSum::between(&prefix, 0, i), 1.0),
+                            (ClusterSum::between(&prefix, i, j), 2.0 / 3.0),
+                            (ClusterSum::between(&prefix, j, k), 1.0 / 3.0),
+                            (ClusterSum::between(&prefix, k, n), 0.0),
+                        ]);
+                    }
+                } else {
+                    try_split(&[
+                        (ClusterSum::between(&prefix, 0, i), 1.0),
+                        (ClusterSum::between(&prefix, i, j), 0.5),
+                        (ClusterSum::between(&prefix, j, n), 0.0),
+                    ]);
+                }
+            }
+        }
+
+        let (a, b, _) = match best {
+            Some(best) if improved => best,
+            _ => break,
+        };
+        axis = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    }
+
+    best.map(|(a, b, _)| (pack_565(a), pack_565(b)))
+}
+
+// Endpoints whose interpolated color best matches a block of a single color, found per channel
+fn single_color_fit(color: [u8; 3], four_colors: bool) -> (u16, u16)
+{
+    const BITS: [u32; 3] = [5, 6, 5];
+    let mut ends = [(0u16, 0u16); 3];
+    for i in 0..3 {
+        let max = (1u16 << BITS[i]) - 1;
+        let expand = |v: u16| -> i32 {
+            let v = v << (8 - BITS[i]);
+            (v | (v >> BITS[i])) as i32
+        };
+        let mut best_error = i32::MAX;
+        for e0 in 0..=max {
+            for e1 in 0..=max {
+                let (x, y) = (expand(e0), expand(e1));
+                let value = if four_colors { (2 * x + y) / 3 } else { (x + y) / 2 };
+                let error = (value - color[i] as i32).abs();
+                if error < best_error {
+                    best_error = error;
+                    ends[i] = (e0, e1);
+                }
+            }
+        }
+    }
+    let pack = |e: [u16; 3]| (e[0] << 11) | (e[1] << 5) | e[2];
+    (pack([ends[0].0, ends[1].0, ends[2].0]), pack([ends[0].1, ends[1].1, ends[2].1]))
+}
+
+// Orders the endpoints for the requested mode, picks the closest palette entry for each pixel
+// and returns the block along with its error, or None if the mode can't represent the block
+fn build_block(c0: u16, c1: u16, four_colors: bool, rgba: &[[u8; 4]; 16]) -> Option<([u8; 8], f32)>
+{
+    let (c0, c1) = if (c0 < c1) == four_colors { (c1, c0) } else { (c0, c1) };
+    let palette = palette(c0, c1);
+
+    let mut block = [0u8; 8];
+    block[0..2].copy_from_slice(&c0.to_be_bytes());
+    block[2..4].copy_from_slice(&c1.to_be_bytes());
+
+    let mut total_error = 0.0;
+    for (i, pixel) in rgba.iter().enumerate() {
+        let transparent = is_transparent(pixel);
+        let mut best: Option<(usize, f32)> = None;
+        for (index, entry) in palette.iter().enumerate() {
+            if (entry[3] == 0) != transparent {
+                continue;
+            }
+            let mut error = 0.0;
+            if !transparent {
+                for ch in 0..3 {
+                    let d = (entry[ch] as f32 - pixel[ch] as f32) * METRIC[ch];
+                    error += d * d;
+                }
+            }
+            if best.map(|b| error < b.1).unwrap_or(true) {
+                best = Some((index, error));
+            }
+        }
+        let (index, error) = best?;
+        total_error += error;
+        block[4 + i / 4] |= (index as u8) << (6 - 2 * (i % 4));
+    }
+    Some((block, total_error))
+}
+
+pub fn compress_dxt1gcn_block(rgba: &[[u8; 4]; 16], block: &mut [u8; 8])
+{
+    let set = ColorSet::new(rgba);
+    if set.points.is_empty() {
+        *block = [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+        return;
+    }
+
+    let mut candidates = vec![];
+    for &four_colors in [false, true].iter() {
+        // Blocks with transparent pixels can only be encoded in 3 color mode
+        if four_colors && set.transparent {
+            continue;
+        }
+        if set.points.len() == 1 {
+            let p = set.points[0];
+            let color = [
+                (p[0] * 255.0).round() as u8,
+                (p[1] * 255.0).round() as u8,
+                (p[2] * 255.0).round() as u8,
+            ];
+            let (c0, c1) = single_color_fit(color, four_colors);
+            candidates.push((c0, c1, four_colors));
+            let c = pack_565(p);
+            candidates.push((c, c, four_colors));
+        } else if let Some((c0, c1)) = cluster_fit(&set, four_colors) {
+            candidates.push((c0, c1, four_colors));
+        }
+    }
+
+    let best = candidates.into_iter()
+        .filter_map(|(c0, c1, four_colors)| build_block(c0, c1, four_colors, rgba))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    *block = match best {
+        Some((best, _)) => best,
+        // Can't happen, 3 color mode with equal endpoints always works
+        None => build_block(0, 0, false, rgba).unwrap().0,
+    };
+}
+
+pub fn decompress_dxt1gcn_block(rgba: &mut [[u8; 4]; 16], block: &[u8; 8])
+{
+    let c0 = u16::from_be_bytes([block[0], block[1]]);
+    let c1 = u16::from_be_bytes([block[2], block[3]]);
+    let palette = palette(c0, c1);
+    for (i, pixel) in rgba.iter_mut().enumerate() {
+        let index = (block[4 + i / 4] >> (6 - 2 * (i % 4))) & 3;
+        *pixel = palette[index as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn round_trip(rgba: &[[u8; 4]; 16]) -> [[u8; 4]; 16]
+    {
+        let mut block = [0u8; 8];
+        compress_dxt1gcn_block(rgba, &mut block);
+        let mut decoded = [[0u8; 4]; 16];
+        decompress_dxt1gcn_block(&mut decoded, &block);
+        decoded
+    }
+
+    #[test]
+    fn test_decode_layout()
+    {
+        // Red and blue endpoints, first row uses all four indices left to right
+        let block = [0xf8, 0x00, 0x00, 0x1f, 0b00_01_10_11, 0, 0, 0xff];
+        let mut rgba = [[0u8; 4]; 16];
+        decompress_dxt1gcn_block(&mut rgba, &block);
+        assert_eq!(&rgba[0..4], &[[255, 0, 0, 255], [0, 0, 255, 255], [170, 0, 85, 255], [85, 0, 170, 255]]);
+        assert_eq!(rgba[4], [255, 0, 0, 255]);
+        assert_eq!(rgba[15], [85, 0, 170, 255]);
+    }
+
+    #[test]
+    fn test_round_trip()
+    {
+        // Colors the endpoints can hold exactly come back unchanged
+        let mut rgba = [[0u8; 4]; 16];
+        for (i, pixel) in rgba.iter_mut().enumerate() {
+            *pixel = if i % 3 == 0 { [0xff, 0x82, 0x00, 0xff] } else { [0x00, 0x41, 0xff, 0xff] };
+        }
+        assert_eq!(round_trip(&rgba), rgba);
+
+        // A gradient spread over the 4 palette entries stays within about half a step of them
+        for (i, pixel) in rgba.iter_mut().enumerate() {
+            let v = (i * 17) as u8;
+            *pixel = [v, v / 2, 255 - v, 0xff];
+        }
+        for (decoded, pixel) in round_trip(&rgba).iter().zip(rgba.iter()) {
+            for ch in 0..3 {
+                assert!((decoded[ch] as i32 - pixel[ch] as i32).abs() <= 28, "{:?} {:?}", decoded, pixel);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transparency()
+    {
+        let mut rgba = [[0x30, 0x60, 0x90, 0xff]; 16];
+        rgba[5] = [0, 0, 0, 0];
+        rgba[10] = [0xff, 0xff, 0xff, 0x10];
+        let decoded = round_trip(&rgba);
+        for (i, (decoded, pixel)) in decoded.iter().zip(rgba.iter()).enumerate() {
+            if i == 5 || i == 10 {
+                assert_eq!(decoded[3], 0);
+            } else {
+                assert_eq!(decoded[3], 0xff);
+                for ch in 0..3 {
+                    assert!((decoded[ch] as i32 - pixel[ch] as i32).abs() <= 2);
+                }
+            }
+        }
+
+        let mut block = [0u8; 8];
+        compress_dxt1gcn_block(&[[0, 0, 0, 0]; 16], &mut block);
+        assert_eq!(block, [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]);
+    }
+}
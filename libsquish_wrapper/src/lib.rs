@@ -1,4 +1,12 @@
+// The CMPR codec is pure Rust unless the `native` feature builds libsquish. Its tests still run
+// with `native` enabled.
+#[cfg(any(not(feature = "native"), test))]
+mod cmpr;
 
+#[cfg(not(feature = "native"))]
+pub use cmpr::{compress_dxt1gcn_block, decompress_dxt1gcn_block};
+
+#[cfg(feature = "native")]
 extern "C" {
 
     // fn libsquish_CompressMasked(rgba: *const u8, mask: i32, block: *const u8, flags: i32, metric: *mut f32);
@@ -10,6 +18,7 @@ extern "C" {
     // fn libsquish_DecompressImage(rgba: *mut u8, width: i32, height: i32, blocks: *const u8, flags: i32);
 }
 
+#[cfg(feature = "native")]
 pub fn compress_dxt1gcn_block(rgba: &[[u8; 4]; 16], block: &mut [u8; 8])
 {
     unsafe {
@@ -22,6 +31,7 @@ pub fn compress_dxt1gcn_block(rgba: &[[u8; 4]; 16], block: &mut [u8; 8])
     }
 }
 
+#[cfg(feature = "native")]
 pub fn decompress_dxt1gcn_block(rgba: &mut [[u8; 4]; 16], block: &[u8; 8])
 {
     assert!(block.len() >= 8);
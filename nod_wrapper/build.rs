@@ -1,5 +1,12 @@
 
 fn main()
+{
+    #[cfg(feature = "native")]
+    build_nod();
+}
+
+#[cfg(feature = "native")]
+fn build_nod()
 {
     // This is based Nod's cmake files. If those change, this probably needs to too.
     let mut build = cpp_build::Config::new();
@@ -0,0 +1,473 @@
+use std::{
+    ffi::CStr,
+    fmt,
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use aes::{
+    cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit},
+    Aes128,
+};
+
+/*
+    Wii discs hold their game in an encrypted data partition. The partition's title key is
+    decrypted with the console's common key, then the partition is read in 0x8000 byte clusters:
+    0x400 bytes of hashes (the H0-H2 tree, whose top level is checked against the H3 table in the
+    partition header) followed by 0x7C00 bytes of data. The data is AES-128-CBC encrypted with the
+    title key, using bytes 0x3D0-0x3E0 of the encrypted hashes as its IV. The hashes aren't
+    verified, only skipped.
+
+    GameCube discs are read as-is, so both kinds of disc can be opened.
+*/
+
+const WII_MAGIC: u32 = 0x5D1C9EA3;
+const GC_MAGIC: u32 = 0xC2339F3D;
+
+const PARTITION_INFO_OFFSET: u64 = 0x40000;
+const DATA_PARTITION_TYPE: u32 = 0;
+
+const TICKET_TITLE_KEY_OFFSET: u64 = 0x1BF;
+const TICKET_TITLE_ID_OFFSET: u64 = 0x1DC;
+const TICKET_COMMON_KEY_INDEX_OFFSET: u64 = 0x1F1;
+const PARTITION_DATA_OFFSET_OFFSET: u64 = 0x2B8;
+
+const CLUSTER_SIZE: u64 = 0x8000;
+const CLUSTER_HASH_SIZE: u64 = 0x400;
+const CLUSTER_DATA_SIZE: u64 = CLUSTER_SIZE - CLUSTER_HASH_SIZE;
+const CLUSTER_IV_OFFSET: usize = 0x3D0;
+
+const FST_OFFSET_OFFSET: u64 = 0x424;
+
+const COMMON_KEYS: [[u8; 16]; 2] = [
+    // Retail
+    [0xeb, 0xe4, 0x2a, 0x22, 0x5e, 0x85, 0x93, 0xe4, 0x48, 0xd9, 0xc5, 0x45, 0x73, 0x81, 0xaa, 0xf7],
+    // Korean
+    [0x63, 0xb8, 0x2b, 0xb4, 0xf4, 0x61, 0x4e, 0x2e, 0x13, 0xf2, 0xfe, 0xfb, 0xba, 0x4c, 0x9b, 0x7e],
+];
+
+type Aes128CbcDec = cbc::Decryptor<Aes128>;
+
+fn decrypt(key: &[u8; 16], iv: &[u8; 16], data: &mut [u8]) -> Result<(), String>
+{
+    Aes128CbcDec::new(key.into(), iv.into())
+        .decrypt_padded_mut::<NoPadding>(data)
+        .map(|_| ())
+        .map_err(|_| "Encrypted data isn't a whole number of AES blocks".to_owned())
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32
+{
+    u32::from_be_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+// Where the disc image's bytes come from: a plain ISO/GCM or a WBFS container
+enum Image
+{
+    Iso(File),
+    Wbfs
+    {
+        file: File,
+        sector_shift: u32,
+        // Physical WBFS sector of every disc sector, 0 for sectors that weren't stored
+        sector_table: Vec<u16>,
+    },
+}
+
+impl Image
+{
+    fn open(mut file: File) -> Result<Image, String>
+    {
+        let mut magic = [0u8; 4];
+        file.read_exact(&mut magic)
+            .map_err(|e| format!("Failed to read disc: {}", e))?;
+        if &magic != b"WBFS" {
+            return Ok(Image::Iso(file));
+        }
+
+        // The header is followed by the first disc's info: a copy of its header and its table
+        // of sectors
+        let mut header = [0u8; 6];
+        file.read_exact(&mut header)
+            .map_err(|e| format!("Failed to read WBFS header: {}", e))?;
+        let hd_sector_size = 1u64 << header[4];
+        let sector_shift = header[5] as u32;
+        let disc_sector_count = (143432 * 2 * CLUSTER_SIZE) >> sector_shift;
+
+        let mut table = vec![0u8; disc_sector_count as usize * 2];
+        file.seek(SeekFrom::Start(hd_sector_size + 0x100))
+            .and_then(|_| file.read_exact(&mut table))
+            .map_err(|e| format!("Failed to read WBFS sector table: {}", e))?;
+        let sector_table = table.chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect();
+
+        Ok(Image::Wbfs { file, sector_shift, sector_table })
+    }
+
+    fn read_at(&mut self, mut offset: u64, mut buf: &mut [u8]) -> io::Result<()>
+    {
+        match self {
+            Image::Iso(file) => {
+                file.seek(SeekFrom::Start(offset))?;
+                file.read_exact(buf)
+            },
+            Image::Wbfs { file, sector_shift, sector_table } => {
+                let sector_size = 1u64 << *sector_shift;
+                while !buf.is_empty() {
+                    let sector = (offset >> *sector_shift) as usize;
+                    let in_sector = offset & (sector_size - 1);
+                    let len = std::cmp::min(buf.len() as u64, sector_size - in_sector) as usize;
+                    let (chunk, rest) = buf.split_at_mut(len);
+                    match sector_table.get(sector) {
+                        Some(0) => chunk.iter_mut().for_each(|b| *b = 0),
+                        Some(&physical) => {
+                            file.seek(SeekFrom::Start(((physical as u64) << *sector_shift) + in_sector))?;
+                            file.read_exact(chunk)?;
+                        },
+                        None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Read past the end of the WBFS disc"))?,
+                    }
+                    offset += len as u64;
+                    buf = rest;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+enum Partition
+{
+    GameCube,
+    Wii
+    {
+        data_offset: u64,
+        title_key: [u8; 16],
+    },
+}
+
+struct DiscReader
+{
+    image: Image,
+    partition: Partition,
+    // The last cluster decrypted, as files are mostly read sequentially
+    cluster: Option<(u64, Vec<u8>)>,
+}
+
+impl DiscReader
+{
+    fn read_raw(&mut self, offset: u64, len: usize) -> Result<Vec<u8>, String>
+    {
+        let mut buf = vec![0u8; len];
+        self.image.read_at(offset, &mut buf)
+            .map_err(|e| format!("Failed to read disc at 0x{:x}: {}", offset, e))?;
+        Ok(buf)
+    }
+
+    fn find_data_partition(&mut self) -> Result<Partition, String>
+    {
+        let info = self.read_raw(PARTITION_INFO_OFFSET, 0x20)?;
+        for group in 0..4 {
+            let count = read_u32(&info, group * 8) as u64;
+            let table_offset = (read_u32(&info, group * 8 + 4) as u64) << 2;
+            let table = self.read_raw(table_offset, count as usize * 8)?;
+            for entry in table.chunks_exact(8) {
+                if read_u32(entry, 4) != DATA_PARTITION_TYPE {
+                    continue;
+                }
+                let partition_offset = (read_u32(entry, 0) as u64) << 2;
+                return self.open_wii_partition(partition_offset);
+            }
+        }
+        Err("Failed to find data partition".to_owned())
+    }
+
+    fn open_wii_partition(&mut self, partition_offset: u64) -> Result<Partition, String>
+    {
+        let ticket = self.read_raw(partition_offset, PARTITION_DATA_OFFSET_OFFSET as usize + 4)?;
+        let key_index = ticket[TICKET_COMMON_KEY_INDEX_OFFSET as usize] as usize;
+        let common_key = COMMON_KEYS.get(key_index)
+            .ok_or_else(|| format!("Unknown common key index {}", key_index))?;
+
+        let title_key_offset = TICKET_TITLE_KEY_OFFSET as usize;
+        let title_id_offset = TICKET_TITLE_ID_OFFSET as usize;
+        let mut title_key: [u8; 16] = ticket[title_key_offset..title_key_offset + 16].try_into().unwrap();
+        let mut iv = [0u8; 16];
+        iv[..8].copy_from_slice(&ticket[title_id_offset..title_id_offset + 8]);
+        decrypt(common_key, &iv, &mut title_key)?;
+
+        let data_offset = (read_u32(&ticket, PARTITION_DATA_OFFSET_OFFSET as usize) as u64) << 2;
+        Ok(Partition::Wii {
+            data_offset: partition_offset + data_offset,
+            title_key,
+        })
+    }
+
+    // Reads from the data partition's decrypted address space
+    fn read(&mut self, mut offset: u64, mut buf: &mut [u8]) -> Result<(), String>
+    {
+        let (data_offset, title_key) = match self.partition {
+            Partition::GameCube => {
+                return self.image.read_at(offset, buf)
+                    .map_err(|e| format!("Failed to read disc at 0x{:x}: {}", offset, e));
+            },
+            Partition::Wii { data_offset, title_key } => (data_offset, title_key),
+        };
+
+        while !buf.is_empty() {
+            let cluster_index = offset / CLUSTER_DATA_SIZE;
+            let in_cluster = (offset % CLUSTER_DATA_SIZE) as usize;
+
+            if self.cluster.as_ref().map(|c| c.0) != Some(cluster_index) {
+                let mut cluster = self.read_raw(data_offset + cluster_index * CLUSTER_SIZE, CLUSTER_SIZE as usize)?;
+                let iv: [u8; 16] = cluster[CLUSTER_IV_OFFSET..CLUSTER_IV_OFFSET + 16].try_into().unwrap();
+                let mut data = cluster.split_off(CLUSTER_HASH_SIZE as usize);
+                decrypt(&title_key, &iv, &mut data)?;
+                self.cluster = Some((cluster_index, data));
+            }
+            let data = &self.cluster.as_ref().unwrap().1;
+
+            let len = std::cmp::min(buf.len(), data.len() - in_cluster);
+            let (chunk, rest) = buf.split_at_mut(len);
+            chunk.copy_from_slice(&data[in_cluster..in_cluster + len]);
+            offset += len as u64;
+            buf = rest;
+        }
+        Ok(())
+    }
+
+    // Wii discs store offsets in the partition divided by 4
+    fn offset_shift(&self) -> u32
+    {
+        match self.partition {
+            Partition::GameCube => 0,
+            Partition::Wii { .. } => 2,
+        }
+    }
+}
+
+struct FstFile
+{
+    name: Vec<u8>,
+    offset: u64,
+    size: u64,
+}
+
+fn parse_fst(fst: &[u8], offset_shift: u32) -> Result<Vec<FstFile>, String>
+{
+    let entry_count = fst.get(8..12)
+        .map(|_| read_u32(fst, 8) as usize)
+        .ok_or("FST is truncated")?;
+    let string_table = fst.get(entry_count * 12..).ok_or("FST is truncated")?;
+
+    let mut files = vec![];
+    for entry in fst[..entry_count * 12].chunks_exact(12).skip(1) {
+        // Directories only group files, names are matched without their path
+        let is_dir = entry[0] != 0;
+        if is_dir {
+            continue;
+        }
+        let name_offset = (read_u32(entry, 0) & 0xffffff) as usize;
+        let name = string_table.get(name_offset..)
+            .and_then(|s| s.split(|b| *b == 0).next())
+            .ok_or("FST name is out of bounds")?;
+        files.push(FstFile {
+            name: name.to_owned(),
+            offset: (read_u32(entry, 4) as u64) << offset_shift,
+            size: read_u32(entry, 8) as u64,
+        });
+    }
+    Ok(files)
+}
+
+pub struct DiscWrapper
+{
+    reader: Arc<Mutex<DiscReader>>,
+    files: Vec<FstFile>,
+}
+
+impl DiscWrapper
+{
+    pub fn new<P>(disc_path: P) -> Result<DiscWrapper, String>
+        where P: AsRef<Path>
+    {
+        let file = File::open(disc_path.as_ref())
+            .map_err(|e| format!("Failed to open disc: {}", e))?;
+        let mut reader = DiscReader {
+            image: Image::open(file)?,
+            partition: Partition::GameCube,
+            cluster: None,
+        };
+
+        let header = reader.read_raw(0, 0x20)?;
+        if read_u32(&header, 0x18) == WII_MAGIC {
+            reader.partition = reader.find_data_partition()?;
+        } else if read_u32(&header, 0x1C) != GC_MAGIC {
+            Err("Failed to open disc: not a GameCube or Wii disc image".to_owned())?
+        }
+
+        let offset_shift = reader.offset_shift();
+        let mut fst_info = [0u8; 8];
+        reader.read(FST_OFFSET_OFFSET, &mut fst_info)?;
+        let fst_offset = (read_u32(&fst_info, 0) as u64) << offset_shift;
+        let fst_size = (read_u32(&fst_info, 4) as u64) << offset_shift;
+        let mut fst = vec![0u8; fst_size as usize];
+        reader.read(fst_offset, &mut fst)?;
+        let files = parse_fst(&fst, offset_shift)?;
+
+        Ok(DiscWrapper {
+            reader: Arc::new(Mutex::new(reader)),
+            files,
+        })
+    }
+
+    pub fn open_file(&self, file_name: &CStr) -> Result<FileWrapper, String>
+    {
+        let file = self.files.iter()
+            .find(|file| file.name == file_name.to_bytes())
+            .ok_or_else(|| format!("Failed to find file {}", &file_name.to_string_lossy()[..]))?;
+        Ok(FileWrapper {
+            reader: self.reader.clone(),
+            offset: file.offset,
+            size: file.size,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct FileWrapper
+{
+    reader: Arc<Mutex<DiscReader>>,
+    offset: u64,
+    size: u64,
+}
+
+impl FileWrapper
+{
+    // Returns the number of bytes read, which is 0 on errors
+    pub fn read_bytes(&self, offset: u64, buf: &mut [u8]) -> u64
+    {
+        let len = std::cmp::min(buf.len() as u64, self.size.saturating_sub(offset));
+        let mut reader = self.reader.lock().unwrap();
+        match reader.read(self.offset + offset, &mut buf[..len as usize]) {
+            Ok(()) => len,
+            Err(_) => 0,
+        }
+    }
+
+    pub fn len(&self) -> u64
+    {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.size == 0
+    }
+}
+
+impl fmt::Debug for FileWrapper
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("FileWrapper")
+            .field("offset", &self.offset)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+
+    type Aes128CbcEnc = cbc::Encryptor<Aes128>;
+
+    fn encrypt(key: &[u8; 16], iv: &[u8; 16], data: &mut [u8])
+    {
+        let len = data.len();
+        Aes128CbcEnc::new(key.into(), iv.into())
+            .encrypt_padded_mut::<NoPadding>(data, len)
+            .unwrap();
+    }
+
+    fn write_u32(bytes: &mut [u8], offset: usize, value: u32)
+    {
+        bytes[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+    }
+
+    // A Wii disc whose data partition holds one file that straddles a cluster boundary
+    fn build_wii_disc(file_data: &[u8]) -> Vec<u8>
+    {
+        const PARTITION_OFFSET: usize = 0x50000;
+        const DATA_OFFSET: usize = 0x20000;
+        const FST_OFFSET: usize = 0x2000;
+        const FILE_OFFSET: usize = 0x7800;
+
+        let mut plain = vec![0u8; CLUSTER_DATA_SIZE as usize * 2];
+        write_u32(&mut plain, FST_OFFSET_OFFSET as usize, (FST_OFFSET >> 2) as u32);
+        write_u32(&mut plain, FST_OFFSET_OFFSET as usize + 4, 0x40 >> 2);
+        let fst = &mut plain[FST_OFFSET..];
+        fst[0] = 1;
+        write_u32(fst, 8, 3);
+        fst[12] = 1;
+        write_u32(fst, 20, 3);
+        write_u32(fst, 24, 6);
+        write_u32(fst, 28, (FILE_OFFSET >> 2) as u32);
+        write_u32(fst, 32, file_data.len() as u32);
+        fst[36..50].copy_from_slice(b"audio\0test.dsp");
+        plain[FILE_OFFSET..FILE_OFFSET + file_data.len()].copy_from_slice(file_data);
+
+        let mut disc = vec![0u8; PARTITION_OFFSET + DATA_OFFSET + CLUSTER_SIZE as usize * 2];
+        write_u32(&mut disc, 0x18, WII_MAGIC);
+        write_u32(&mut disc, PARTITION_INFO_OFFSET as usize, 1);
+        write_u32(&mut disc, PARTITION_INFO_OFFSET as usize + 4, (PARTITION_INFO_OFFSET as u32 + 0x20) >> 2);
+        write_u32(&mut disc, PARTITION_INFO_OFFSET as usize + 0x20, (PARTITION_OFFSET >> 2) as u32);
+
+        let title_key = [0x42; 16];
+        let title_id = [0x00, 0x01, 0x00, 0x00, b'R', b'3', b'M', b'E'];
+        let ticket = &mut disc[PARTITION_OFFSET..];
+        let mut encrypted_key = title_key;
+        let mut iv = [0u8; 16];
+        iv[..8].copy_from_slice(&title_id);
+        encrypt(&COMMON_KEYS[0], &iv, &mut encrypted_key);
+        ticket[TICKET_TITLE_KEY_OFFSET as usize..][..16].copy_from_slice(&encrypted_key);
+        ticket[TICKET_TITLE_ID_OFFSET as usize..][..8].copy_from_slice(&title_id);
+        write_u32(ticket, PARTITION_DATA_OFFSET_OFFSET as usize, (DATA_OFFSET >> 2) as u32);
+
+        for (i, data) in plain.chunks_exact(CLUSTER_DATA_SIZE as usize).enumerate() {
+            let cluster = &mut disc[PARTITION_OFFSET + DATA_OFFSET + i * CLUSTER_SIZE as usize..][..CLUSTER_SIZE as usize];
+            let iv = [i as u8 + 1; 16];
+            cluster[CLUSTER_IV_OFFSET..CLUSTER_IV_OFFSET + 16].copy_from_slice(&iv);
+            cluster[CLUSTER_HASH_SIZE as usize..].copy_from_slice(data);
+            encrypt(&title_key, &iv, &mut cluster[CLUSTER_HASH_SIZE as usize..]);
+        }
+        disc
+    }
+
+    #[test]
+    fn test_read_wii_file()
+    {
+        let file_data: Vec<u8> = (0..0x1000u32).map(|i| (i * 7) as u8).collect();
+        let path = std::env::temp_dir().join(format!("nod_wrapper_test_{}.iso", std::process::id()));
+        std::fs::write(&path, build_wii_disc(&file_data)).unwrap();
+
+        let disc = DiscWrapper::new(&path);
+        std::fs::remove_file(&path).unwrap();
+        let disc = disc.unwrap();
+
+        let file = disc.open_file(c"test.dsp").unwrap();
+        assert_eq!(file.len(), file_data.len() as u64);
+        let mut buf = vec![0u8; 0x1000];
+        assert_eq!(file.read_bytes(0, &mut buf), 0x1000);
+        assert_eq!(buf, file_data);
+        assert_eq!(file.read_bytes(0xff0, &mut buf), 0x10);
+        assert_eq!(&buf[..0x10], &file_data[0xff0..]);
+
+        assert!(disc.open_file(c"audio").is_err());
+    }
+}
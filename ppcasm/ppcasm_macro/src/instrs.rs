@@ -0,0 +1,278 @@
+// The instruction table shared by the assembler (this crate) and the disassembler in ppcasm.
+// Each entry is `opname[flags], operands => bit fields`, with fields listed from the most
+// significant bit down.
+decl_instrs! {
+    add[o][.],  (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;266) | (?.);
+    addc[o][.], (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;10) | (?.);
+    adde[o][.], (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;138) | (?.);
+    addi,       (r:d), (r:a), (i:imm)   => (6;14) | d | a | (16;imm);
+    addic[.],   (r:d), (r:a), (i:imm)   => (5;6) | (?.) | d | a | (16;imm);
+    addis,      (r:d), (r:a), (i:imm)   => (6;15) | d | a | (16;imm);
+    addme[o][.],(r:d), (r:a)            => (6;31) | d | a | (5;0) | (?o) | (9;234) | (?.);
+    addze[o][.],(r:d), (r:a)            => (6;31) | d | a | (5;0) | (?o) | (9;202) | (?.);
+    and[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;28) | (?.);
+    andc[.],    (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;60) | (?.);
+    andi,       (r:d), (r:a), (i:imm)   => (6;28) | d | a | (16;imm);
+    andis,       (r:d), (r:a), (i:imm)  => (6;29) | d | a | (16;imm);
+    b[l][a],    (l:li)                  => (6;18) | (24;li) | (?a) | (?l);
+    bc[l][a],   (i:bo), (i:bi), (l:li)  => (6;16) | (5;bo) | (5;bi) | (14;li) | (?a) | (?l);
+    bctr[l]                             => (6;19) | (5;20) | (5;0) | (5;0) | (10;528) | (?l);
+    bdnz[l][a], (l:li)                  => (6;16) | (5;16) | (5;0) | (14;li) | (?a) | (?l);
+    bdz[l][a],  (l:li)                  => (6;16) | (5;18) | (5;0) | (14;li) | (?a) | (?l);
+    blr[l]                              => (6;19) | (5;20) | (5;0) | (5;0) | (10;16) | (?l);
+    blt[l][a],  (l:li)                  => (6;16) | (5;12) | (5;0) | (14;li) | (?a) | (?l);
+    bge[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;0) | (14;li) | (?a) | (?l);
+    bgt[l][a],  (l:li)                  => (6;16) | (5;12) | (5;1) | (14;li) | (?a) | (?l);
+    ble[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;1) | (14;li) | (?a) | (?l);
+    beq[l][a],  (l:li)                  => (6;16) | (5;12) | (5;2) | (14;li) | (?a) | (?l);
+    bne[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;2) | (14;li) | (?a) | (?l);
+    bso[l][a],  (l:li)                  => (6;16) | (5;12) | (5;3) | (14;li) | (?a) | (?l);
+    bns[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;3) | (14;li) | (?a) | (?l);
+    bltlr                               => (6;19) | (5;12) | (5;0) | (5;0) | (10;16) | (1;0);
+    bgelr                               => (6;19) | (5;4)  | (5;0) | (5;0) | (10;16) | (1;0);
+    bgtlr                               => (6;19) | (5;12) | (5;1) | (5;0) | (10;16) | (1;0);
+    blelr                               => (6;19) | (5;4)  | (5;1) | (5;0) | (10;16) | (1;0);
+    beqlr                               => (6;19) | (5;12) | (5;2) | (5;0) | (10;16) | (1;0);
+    bnelr                               => (6;19) | (5;4)  | (5;2) | (5;0) | (10;16) | (1;0);
+    cmp,        (cr:d), (i:l), (r:a), (r:b) => (6;31) | d | (1;0) | (1;l) | a | b | (10;0) | (1;0);
+    cmpi,       (cr:d), (i:l), (r:a), (i:imm) => (6;11) | d | (1;0) | (1;l) | a | (16;imm);
+    cmpl,       (cr:d), (i:l), (r:a), (r:b) => (6;31) | d | (1;0) | (1;l) | a | b | (10;32) | (1;0);
+    cmpli,      (cr:d), (i:l), (r:a), (i:imm) => (6;10) | d | (1;0) | (1;l) | a | (16;imm);
+    cmplw,      (r:a), (r:s)            => (6;31) | (5;0) | a | s | (11;64);
+    cmplwi,     (r:a), (i:imm)          => (6;10) | (3;0) | (1;0) | (1;0) | a | (16;imm);
+    cmpw,       (r:a), (r:s)            => (6;31) | (5;0) | a | s | (11;0);
+    cmpwi,      (r:a), (i:imm)          => (6;11) | (3;0) | (1;0) | (1;0) | a | (16;imm);
+    cntlzw[.],  (r:a), (r:s)            => (6;31) | s | a | (5;0) | (10;26) | (?.);
+    crand,      (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;257) | (1;0);
+    crandc,     (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;129) | (1;0);
+    crclr,      (i:d)                   => (6;19) | (5;d) | (5;d) | (5;d) | (10;193) | (1;0);
+    creqv,      (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;289) | (1;0);
+    crnand,     (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;225) | (1;0);
+    crnor,      (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;33) | (1;0);
+    crnot,      (i:d), (i:a)            => (6;19) | (5;d) | (5;a) | (5;a) | (10;33) | (1;0);
+    cror,       (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;449) | (1;0);
+    crorc,      (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;417) | (1;0);
+    crset,      (i:d)                   => (6;19) | (5;d) | (5;d) | (5;d) | (10;289) | (1;0);
+    crxor,      (i:d), (i:a), (i:b)     => (6;19) | (5;d) | (5;a) | (5;b) | (10;193) | (1;0);
+    clrlwi[.],  (r:a), (r:s), (i:n)     => (6;21) | s | a | (5;0) | (5;n) | (5;31) | (?.);
+    dcbf,       (r:a), (r:b)            => (6;31) | (5;0) | a | b | (10;86) | (1;0);
+    dcbi,       (r:a), (r:b)            => (6;31) | (5;0) | a | b | (10;470) | (1;0);
+    dcbst,      (r:a), (r:b)            => (6;31) | (5;0) | a | b | (10;54) | (1;0);
+    dcbt,       (r:a), (r:b)            => (6;31) | (5;0) | a | b | (10;278) | (1;0);
+    dcbz,       (r:a), (r:b)            => (6;31) | (5;0) | a | b | (10;1014) | (1;0);
+    dcbz_l,     (r:a), (r:b)            => (6;4) | (5;0) | a | b | (10;1014) | (1;0);
+    divw[o][.], (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;491) | (?.);
+    divwu[o][.],(r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;459) | (?.);
+    eieio                               => (32;0x7c0006ac);
+    eqv[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;284) | (?.);
+    extsb[.],   (r:a), (r:s)            => (6;31) | s | a | (5;0) | (10;954) | (?.);
+    extsh[.],   (r:a), (r:s)            => (6;31) | s | a | (5;0) | (10;922) | (?.);
+    fabs[.],    (f:d), (f:b)            => (6;63) | d | (5;0) | b | (10;264) | (?.);
+    fadd[.],    (f:d), (f:a), (f:b)     => (6;63) | d | a | b | (5;0) | (5;21) | (?.);
+    fadds[.],   (f:d), (f:a), (f:b)     => (6;59) | d | a | b | (10;21) | (?.);
+    fcmpo,      (cr:a), (f:d), (f:s)    => (6;63) | a | (2;0) | d | s | (11;64);
+    fcmpu,      (cr:a), (f:d), (f:s)    => (6;63) | a | (2;0) | d | s | (11;0);
+    fctiw[.],   (f:d), (f:b)            => (6;63) | d | (5;0) | b | (10;14) | (?.);
+    fctiwz[.],  (f:d), (f:b)            => (6;63) | d | (5;0) | b | (10;15) | (?.);
+    fdiv[.],    (f:d), (f:a), (f:b)     => (6;63) | d | a | b | (5;0) | (5;18) | (?.);
+    fdivs[.],   (f:d), (f:a), (f:b)     => (6;59) | d | a | b | (10;18) | (?.);
+    fmadd[.],   (f:d), (f:a), (f:c), (f:b) => (6;63) | d | a | b | c | (5;29) | (?.);
+    fmadds[.],  (f:d), (f:a), (f:c), (f:b) => (6;59) | d | a | b | c | (5;29) | (?.);
+    fmsub[.],   (f:d), (f:a), (f:c), (f:b) => (6;63) | d | a | b | c | (5;28) | (?.);
+    fmsubs[.],  (f:d), (f:a), (f:c), (f:b) => (6;59) | d | a | b | c | (5;28) | (?.);
+    fmul[.],    (f:d), (f:a), (f:c)     => (6;63) | d | a | (5;0) | c | (5;25) | (?.);
+    fmuls[.],   (f:d), (f:a), (f:c)     => (6;59) | d | a | (5;0) | c | (5;25) | (?.);
+    fnabs[.],   (f:d), (f:b)            => (6;63) | d | (5;0) | b | (10;136) | (?.);
+    fneg[.],    (f:d), (f:b)            => (6;63) | d | (5;0) | b | (10;40) | (?.);
+    fnmadd[.],  (f:d), (f:a), (f:c), (f:b) => (6;63) | d | a | b | c | (5;31) | (?.);
+    fnmadds[.], (f:d), (f:a), (f:c), (f:b) => (6;59) | d | a | b | c | (5;31) | (?.);
+    fnmsub[.],  (f:d), (f:a), (f:c), (f:b) => (6;63) | d | a | b | c | (5;30) | (?.);
+    fnmsubs[.], (f:d), (f:a), (f:c), (f:b) => (6;59) | d | a | b | c | (5;30) | (?.);
+    fres[.],    (f:d), (f:b)            => (6;59) | d | (5;0) | b | (5;0) | (5;24) | (?.);
+    frsp[.],    (f:d), (f:b)            => (6;63) | d | (5;0) | b | (10;12) | (?.);
+    frsqrte[.], (f:d), (f:b)            => (6;63) | d | (5;0) | b | (5;0) | (5;26) | (?.);
+    fsel[.],    (f:d), (f:a), (f:c), (f:b) => (6;63) | d | a | b | c | (5;23) | (?.);
+    fsub[.],    (f:d), (f:a), (f:b)     => (6;63) | d | a | b | (5;0) | (5;20) | (?.);
+    fsubs[.],   (f:d), (f:a), (f:b)     => (6;59) | d | a | b | (10;20) | (?.);
+    fmr,        (f:a), (f:s)            => (6;63) | a | (5;0) | s | (11;144);
+    icbi,       (r:a), (r:b)            => (6;31) | (5;0) | a | b | (10;982) | (1;0);
+    isync                               => (32;0x4c00012c);
+    lbz,        (r:d), (r:a:dis)        => (6;34) | d | a | (16;dis);
+    lbzu,       (r:d), (r:a:dis)        => (6;35) | d | a | (16;dis);
+    lbzux,      (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;119) | (1;0);
+    lbzx,       (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;87) | (1;0);
+    lfd,        (f:d), (r:a:dis)        => (6;50) | d | a | (16;dis);
+    lfdu,       (f:d), (r:a:dis)        => (6;51) | d | a | (16;dis);
+    lfdux,      (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;631) | (1;0);
+    lfdx,       (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;599) | (1;0);
+    lfs,        (f:d), (r:a:dis)        => (6;48) | d | a | (16;dis);
+    lfsu,       (f:d), (r:a:dis)        => (6;49) | d | a | (16;dis);
+    lfsux,      (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;567) | (1;0);
+    lfsx,       (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;535) | (1;0);
+    li,         (r:d), (i:imm)          => (6;14) | d | (5;0) | (16;imm);
+    lis,        (r:d), (i:imm)          => (6;15) | d | (5;0) | (16;imm);
+    lha,        (r:d), (r:a:dis)        => (6;42) | d | a | (16;dis);
+    lhau,       (r:d), (r:a:dis)        => (6;43) | d | a | (16;dis);
+    lhax,       (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;343) | (1;0);
+    lhbrx,      (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;790) | (1;0);
+    lhz,        (r:d), (r:a:dis)        => (6;40) | d | a | (16;dis);
+    lhzu,       (r:d), (r:a:dis)        => (6;41) | d | a | (16;dis);
+    lhzux,      (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;311) | (1;0);
+    lhzx,       (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;279) | (1;0);
+    lmw,        (r:d), (r:a:dis)        => (6;46) | d | a | (16;dis);
+    lwarx,      (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;20) | (1;0);
+    lwbrx,      (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;534) | (1;0);
+    lwz,        (r:d), (r:a:dis)        => (6;32) | d | a | (16;dis);
+    lwzu,       (r:d), (r:a:dis)        => (6;33) | d | a | (16;dis);
+    lwzux,      (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;55) | (1;0);
+    lwzx,       (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;23) | (1;0);
+    mcrf,       (cr:d), (cr:s)          => (6;19) | d | (2;0) | s | (2;0) | (5;0) | (10;0) | (1;0);
+    mcrfs,      (cr:d), (cr:s)          => (6;63) | d | (2;0) | s | (2;0) | (5;0) | (10;64) | (1;0);
+    mcrxr,      (cr:d)                  => (6;31) | d | (2;0) | (5;0) | (5;0) | (10;512) | (1;0);
+    mfcr,       (r:d)                   => (6;31) | d | (10;0) | (10;19) | (1;0);
+    mfctr,      (r:d)                   => (6;31) | d | (10;0x120) | (10;339) | (1;0);
+    mffs[.],    (f:d)                   => (6;63) | d | (10;0) | (10;583) | (?.);
+    mflr,       (r:d)                   => (6;31) | d | (10;0x100) | (10;339) | (1;0);
+    mfmsr,      (r:d)                   => (6;31) | d | (10;0) | (10;83) | (1;0);
+    mfspr,      (r:d), (spr:s)          => (6;31) | d | s | (10;339) | (1;0);
+    mfsr,       (r:d), (i:sr)           => (6;31) | d | (1;0) | (4;sr) | (5;0) | (10;595) | (1;0);
+    mftb,       (r:d)                   => (6;31) | d | (10;0x188) | (10;371) | (1;0);
+    mftbu,      (r:d)                   => (6;31) | d | (10;0x1a8) | (10;371) | (1;0);
+    mr,         (r:a), (r:s)            => (6;31) | s | a | s | (10;444) | (1;0);
+    mtcr,       (r:s)                   => (6;31) | s | (1;0) | (8;0xff) | (1;0) | (10;144) | (1;0);
+    mtcrf,      (i:crm), (r:s)          => (6;31) | s | (1;0) | (8;crm) | (1;0) | (10;144) | (1;0);
+    mtctr,      (r:d)                   => (6;31) | d | (10;0x120) | (10;467) | (1;0);
+    mtfsb0[.],  (i:crbd)                => (6;63) | (5;crbd) | (5;0) | (5;0) | (10;70) | (?.);
+    mtfsb1[.],  (i:crbd)                => (6;63) | (5;crbd) | (5;0) | (5;0) | (10;38) | (?.);
+    mtfsf[.],   (i:fm), (f:b)           => (6;63) | (1;0) | (8;fm) | (1;0) | b | (10;711) | (?.);
+    mtfsfi[.],  (cr:d), (i:imm)         => (6;63) | d | (2;0) | (5;0) | (4;imm) | (1;0) | (10;134) | (?.);
+    mtlr,       (r:d)                   => (6;31) | d | (10;0x100) | (10;467) | (1;0);
+    mtmsr,      (r:s)                   => (6;31) | s | (10;0) | (10;146) | (1;0);
+    mtspr,      (spr:s), (r:d)          => (6;31) | d | s | (10;467) | (1;0);
+    mtsr,       (i:sr), (r:s)           => (6;31) | s | (1;0) | (4;sr) | (5;0) | (10;210) | (1;0);
+    mulhw[.],   (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (1;0) | (9;75) | (?.);
+    mulhwu[.],  (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (1;0) | (9;11) | (?.);
+    mulli,      (r:d), (r:a), (i:imm)   => (6;7) | d | a | (16;imm);
+    mullw[o][.],(r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;235) | (?.);
+    nand[.],    (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;476) | (?.);
+    neg[o][.],  (r:d), (r:a)            => (6;31) | d | a | (5;0) | (?o) | (9;104) | (?.);
+    nop                                 => (32;0x60000000);
+    nor[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;124) | (?.);
+    not[.],     (r:a), (r:s)            => (6;31) | s | a | s | (10;124) | (?.);
+    or[.],      (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;444) | (?.);
+    orc[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;412) | (?.);
+    ori,        (r:d), (r:a), (i:imm)   => (6;24) | d | a | (16;imm);
+    oris,       (r:d), (r:a), (i:imm)   => (6;25) | d | a | (16;imm);
+    ps_abs[.],  (f:d), (f:b)            => (6;4) | d | (5;0) | b | (10;264) | (?.);
+    ps_add[.],  (f:d), (f:a), (f:b)     => (6;4) | d | a | b | (5;0) | (5;21) | (?.);
+    ps_cmpo0,   (cr:a), (f:d), (f:s)    => (6;4) | a | (2;0) | d | s | (10;32) | (1;0);
+    ps_cmpo1,   (cr:a), (f:d), (f:s)    => (6;4) | a | (2;0) | d | s | (10;96) | (1;0);
+    ps_cmpu0,   (cr:a), (f:d), (f:s)    => (6;4) | a | (2;0) | d | s | (10;0) | (1;0);
+    ps_cmpu1,   (cr:a), (f:d), (f:s)    => (6;4) | a | (2;0) | d | s | (10;64) | (1;0);
+    ps_div[.],  (f:d), (f:a), (f:b)     => (6;4) | d | a | b | (5;0) | (5;18) | (?.);
+    ps_madd[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;29) | (?.);
+    ps_madds0[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;14) | (?.);
+    ps_madds1[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;15) | (?.);
+    ps_merge00[.], (f:d), (f:a), (f:b)  => (6;4) | d | a | b | (10;528) | (?.);
+    ps_merge01[.], (f:d), (f:a), (f:b)  => (6;4) | d | a | b | (10;560) | (?.);
+    ps_merge10[.], (f:d), (f:a), (f:b)  => (6;4) | d | a | b | (10;592) | (?.);
+    ps_merge11[.], (f:d), (f:a), (f:b)  => (6;4) | d | a | b | (10;624) | (?.);
+    ps_mr[.],   (f:d), (f:b)            => (6;4) | d | (5;0) | b | (10;72) | (?.);
+    ps_msub[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;28) | (?.);
+    ps_mul[.],  (f:d), (f:a), (f:c)     => (6;4) | d | a | (5;0) | c | (5;25) | (?.);
+    ps_muls0[.], (f:d), (f:a), (f:c)    => (6;4) | d | a | (5;0) | c | (5;12) | (?.);
+    ps_muls1[.], (f:d), (f:a), (f:c)    => (6;4) | d | a | (5;0) | c | (5;13) | (?.);
+    ps_nabs[.], (f:d), (f:b)            => (6;4) | d | (5;0) | b | (10;136) | (?.);
+    ps_neg[.],  (f:d), (f:b)            => (6;4) | d | (5;0) | b | (10;40) | (?.);
+    ps_nmadd[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;31) | (?.);
+    ps_nmsub[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;30) | (?.);
+    ps_res[.],  (f:d), (f:b)            => (6;4) | d | (5;0) | b | (5;0) | (5;24) | (?.);
+    ps_rsqrte[.], (f:d), (f:b)          => (6;4) | d | (5;0) | b | (5;0) | (5;26) | (?.);
+    ps_sel[.],  (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;23) | (?.);
+    ps_sub[.],  (f:d), (f:a), (f:b)     => (6;4) | d | a | b | (5;0) | (5;20) | (?.);
+    ps_sum0[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;10) | (?.);
+    ps_sum1[.], (f:d), (f:a), (f:c), (f:b) => (6;4) | d | a | b | c | (5;11) | (?.);
+    psq_l,      (f:d), (r:a:dis), (i:w), (i:q) => (6;56) | d | a | (1;w) | (3;q) | (12;dis);
+    psq_lu,     (f:d), (r:a:dis), (i:w), (i:q) => (6;57) | d | a | (1;w) | (3;q) | (12;dis);
+    psq_lux,    (f:d), (r:a), (r:b), (i:w), (i:q) => (6;4) | d | a | b | (1;w) | (3;q) | (6;38) | (1;0);
+    psq_lx,     (f:d), (r:a), (r:b), (i:w), (i:q) => (6;4) | d | a | b | (1;w) | (3;q) | (6;6) | (1;0);
+    psq_st,     (f:s), (r:a:dis), (i:w), (i:q) => (6;60) | s | a | (1;w) | (3;q) | (12;dis);
+    psq_stu,    (f:s), (r:a:dis), (i:w), (i:q) => (6;61) | s | a | (1;w) | (3;q) | (12;dis);
+    psq_stux,   (f:s), (r:a), (r:b), (i:w), (i:q) => (6;4) | s | a | b | (1;w) | (3;q) | (6;39) | (1;0);
+    psq_stx,    (f:s), (r:a), (r:b), (i:w), (i:q) => (6;4) | s | a | b | (1;w) | (3;q) | (6;7) | (1;0);
+    rfi                                 => (32;0x4c000064);
+    rotlw[.],   (r:a), (r:s), (r:b)     => (6;23) | s | a | b | (5;0) | (5;31) | (?.);
+    rotlwi[.],  (r:a), (r:s), (i:n)     => (6;21) | s | a | (5;n) | (5;0) | (5;31) | (?.);
+    sc                                  => (32;0x44000002);
+    slw[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;24) | (?.);
+    slwi,       (r:a), (r:s), (i:n)     => (6;21) | s | a | (5;{#n}) | (5;0) |(5;{31 - #n}) | (1;0);
+    sraw[.],    (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;792) | (?.);
+    srawi[.],   (r:a), (r:s), (i:sh)    => (6;31) | s | a | (5;sh) | (10;824) | (?.);
+    srw[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;536) | (?.);
+    srwi,       (r:a), (r:s), (i:n)     => (6;21) | s | a | (5;{32 - #n}) | (5;n) |(5;31) | (1;0);
+    rlwimi[.],  (r:a), (r:s), (i:sh), (i:mb), (i:me) =>
+        (6;20) | s | a | (5;sh) | (5;mb) |(5;me) | (?.);
+    rlwinm[.],  (r:a), (r:s), (i:sh), (i:mb), (i:me) =>
+        (6;21) | s | a | (5;sh) | (5;mb) |(5;me) | (?.);
+    rlwnm[.],   (r:a), (r:s), (r:b), (i:mb), (i:me) =>
+        (6;23) | s | a | b | (5;mb) |(5;me) | (?.);
+    stb,        (r:s), (r:a:dis)        => (6;38) | s | a | (16;dis);
+    stbu,       (r:s), (r:a:dis)        => (6;39) | s | a | (16;dis);
+    stbux,      (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;247) | (1;0);
+    stbx,       (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;215) | (1;0);
+    stfd,       (f:d), (r:a:dis)        => (6;54) | d | a | (16;dis);
+    stfdu,      (f:d), (r:a:dis)        => (6;55) | d | a | (16;dis);
+    stfdux,     (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;759) | (1;0);
+    stfdx,      (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;727) | (1;0);
+    stfiwx,     (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;983) | (1;0);
+    stfs,       (f:d), (r:a:dis)        => (6;52) | d | a | (16;dis);
+    stfsu,      (f:d), (r:a:dis)        => (6;53) | d | a | (16;dis);
+    stfsux,     (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;695) | (1;0);
+    stfsx,      (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;663) | (1;0);
+    sth,        (r:s), (r:a:dis)        => (6;44) | s | a | (16;dis);
+    sthbrx,     (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;918) | (1;0);
+    sthu,       (r:s), (r:a:dis)        => (6;45) | s | a | (16;dis);
+    sthux,      (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;439) | (1;0);
+    sthx,       (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;407) | (1;0);
+    stmw,       (r:s), (r:a:dis)        => (6;47) | s | a | (16;dis);
+    stw,        (r:s), (r:a:dis)        => (6;36) | s | a | (16;dis);
+    stwbrx,     (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;662) | (1;0);
+    stwcx[.],   (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;150) | (?.);
+    stwu,       (r:s), (r:a:dis)        => (6;37) | s | a | (16;dis);
+    stwux,      (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;183) | (1;0);
+    stwx,       (r:s), (r:a), (r:b)     => (6;31) | s | a | b | (10;151) | (1;0);
+    subf[o][.], (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;40) | (?.);
+    subfc[o][.],(r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;8) | (?.);
+    subfe[o][.],(r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;136) | (?.);
+    subfic,     (r:d), (r:a), (i:imm)   => (6;8) | d | a | (16;imm);
+    subfme[o][.],(r:d), (r:a)           => (6;31) | d | a | (5;0) | (?o) | (9;232) | (?.);
+    subfze[o][.],(r:d), (r:a)           => (6;31) | d | a | (5;0) | (?o) | (9;200) | (?.);
+    // `sub rD, rA, rB` is `subf rD, rB, rA`; listed after subf so the disassembler prefers subf
+    sub[o][.],  (r:d), (r:a), (r:b)     => (6;31) | d | b | a | (?o) | (9;40) | (?.);
+    sync                                => (32;0x7c0004ac);
+    trap                                => (32;0x7fe00008);
+    tw,         (i:to), (r:a), (r:b)    => (6;31) | (5;to) | a | b | (10;4) | (1;0);
+    twi,        (i:to), (r:a), (i:imm)  => (6;3) | (5;to) | a | (16;imm);
+    xor[.],     (r:a), (r:s), (r:b)     => (6;31) | s | a | b | (10;316) | (?.);
+    xori,       (r:s), (r:a), (i:imm)   => (6;26) | s | a | (16;imm);
+    xoris,      (r:s), (r:a), (i:imm)   => (6;27) | s | a | (16;imm);
+}
+
+// Special purpose registers accepted by name by mfspr/mtspr
+const SPR_NAMES: &[(&str, u32)] = &[
+    ("xer", 1), ("lr", 8), ("ctr", 9), ("dsisr", 18), ("dar", 19), ("dec", 22), ("sdr1", 25),
+    ("srr0", 26), ("srr1", 27), ("sprg0", 272), ("sprg1", 273), ("sprg2", 274), ("sprg3", 275),
+    ("ear", 282), ("tbl", 284), ("tbu", 285), ("pvr", 287),
+    ("ibat0u", 528), ("ibat0l", 529), ("ibat1u", 530), ("ibat1l", 531),
+    ("ibat2u", 532), ("ibat2l", 533), ("ibat3u", 534), ("ibat3l", 535),
+    ("dbat0u", 536), ("dbat0l", 537), ("dbat1u", 538), ("dbat1l", 539),
+    ("dbat2u", 540), ("dbat2l", 541), ("dbat3u", 542), ("dbat3l", 543),
+    ("gqr0", 912), ("gqr1", 913), ("gqr2", 914), ("gqr3", 915),
+    ("gqr4", 916), ("gqr5", 917), ("gqr6", 918), ("gqr7", 919),
+    ("hid2", 920), ("wpar", 921), ("dma_u", 922), ("dma_l", 923),
+    ("ummcr0", 936), ("upmc1", 937), ("upmc2", 938), ("usia", 939), ("ummcr1", 940),
+    ("upmc3", 941), ("upmc4", 942), ("mmcr0", 952), ("pmc1", 953), ("pmc2", 954),
+    ("sia", 955), ("mmcr1", 956), ("pmc3", 957), ("pmc4", 958),
+    ("hid0", 1008), ("hid1", 1009), ("iabr", 1010), ("dabr", 1013), ("l2cr", 1017),
+    ("ictc", 1019), ("thrm1", 1020), ("thrm2", 1021), ("thrm3", 1022),
+];
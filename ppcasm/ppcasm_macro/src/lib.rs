@@ -184,7 +184,7 @@ macro_rules! parse_part {
         }
     };
     ($dotname:ident, ($width:expr ; $value:ident)) => {
-        ($width, $value)
+        ($width, $value.clone())
     };
     ($dotname:ident, ($width:expr ; {$($value:tt)+})) => {
         {
@@ -265,6 +265,21 @@ macro_rules! parse_operand {
             Err(Error::new(ident.span(), format!("Expected UMM register name, got {}", ident)))?
         };
     };
+    ($input:ident, (spr:$i:ident)) => {
+        let named = $input.fork().parse::<Ident>().ok()
+            .and_then(|ident| SPR_NAMES.iter().find(|(n, _)| ident == n));
+        let spr: Expr = if let Some((_, n)) = named {
+            let ident: Ident = $input.parse()?;
+            let n = *n as i64;
+            parse_quote_spanned! {ident.span()=> #n }
+        } else {
+            parse_immediate($input)?
+        };
+        // The two 5-bit halves of the SPR number are swapped in the encoding
+        let $i = (10, AsmOp::Expr(parse_quote_spanned! {spr.span()=>
+            (((#spr) & 0x1f) << 5) | ((#spr) >> 5)
+        }));
+    };
     ($input:ident, (i:$i:ident)) => {
         let $i = AsmOp::Expr(parse_immediate($input)?);
     };
@@ -384,65 +399,7 @@ macro_rules! decl_instrs {
 // const BO_FALSE: i32 = 4;
 // const BO_TRUE: i32 = 12;
 
-decl_instrs! {
-    add[o][.],  (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;266) | (?.);
-    addc[o][.], (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;10) | (?.);
-    addi,       (r:d), (r:a), (i:imm)   => (6;14) | d | a | (16;imm);
-    addic[.],   (r:d), (r:a), (i:imm)   => (5;6) | (?.) | d | a | (16;imm);
-    andi,       (r:d), (r:a), (i:imm)   => (6;28) | d | a | (16;imm);
-    andis,       (r:d), (r:a), (i:imm)  => (6;29) | d | a | (16;imm);
-    b[l][a],    (l:li)                  => (6;18) | (24;li) | (?a) | (?l);
-    blr                                 => (32;0x4e800020);
-    blt[l][a],  (l:li)                  => (6;16) | (5;12) | (5;0) | (14;li) | (?a) | (?l);
-    bge[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;0) | (14;li) | (?a) | (?l);
-    bgt[l][a],  (l:li)                  => (6;16) | (5;12) | (5;1) | (14;li) | (?a) | (?l);
-    ble[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;1) | (14;li) | (?a) | (?l);
-    beq[l][a],  (l:li)                  => (6;16) | (5;12) | (5;2) | (14;li) | (?a) | (?l);
-    bne[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;2) | (14;li) | (?a) | (?l);
-    bso[l][a],  (l:li)                  => (6;16) | (5;12) | (5;3) | (14;li) | (?a) | (?l);
-    bns[l][a],  (l:li)                  => (6;16) | (5;4)  | (5;3) | (14;li) | (?a) | (?l);
-    cmplw,      (r:a), (r:s)            => (6;31) | (5;0) | a | s | (11;64);
-    cmplwi,     (r:a), (i:imm)          => (6;10) | (3;0) | (1;0) | (1;0) | a | (16;imm);
-    cmpw,       (r:a), (r:s)            => (6;31) | (5;0) | a | s | (11;0);
-    cmpwi,      (r:a), (i:imm)          => (6;11) | (3;0) | (1;0) | (1;0) | a | (16;imm);
-    cntlzw[.],  (r:a), (r:s)            => (6;31) | s | a | (5;0) | (10;26) | (?.);
-    fadds[.],   (f:d), (f:a), (f:b)     => (6;59) | d | a | b | (10;21) | (?.);
-    fcmpu,      (cr:a), (f:d), (f:s)    => (6;63) | a | (2;0) | d | s | (11;0);
-    fdivs[.],   (f:d), (f:a), (f:b)     => (6;59) | d | a | b | (10;18) | (?.);
-    fsubs[.],   (f:d), (f:a), (f:b)     => (6;59) | d | a | b | (10;20) | (?.);
-    fmr,        (f:a), (f:s)            => (6;63) | a | (5;0) | s | (11;144);
-    lbz,        (r:d), (r:a:dis)        => (6;34) | d | a | (16;dis);
-    lfd,        (f:d), (r:a:dis)        => (6;50) | d | a | (16;dis);
-    lfs,        (f:d), (r:a:dis)        => (6;48) | d | a | (16;dis);
-    lfsu,       (f:d), (r:a:dis)        => (6;49) | d | a | (16;dis);
-    lfsx,       (f:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;535) | (1;0);
-    li,         (r:d), (i:imm)          => (6;14) | d | (5;0) | (16;imm);
-    lis,        (r:d), (i:imm)          => (6;15) | d | (5;0) | (16;imm);
-    lha,        (r:d), (r:a:dis)        => (6;42) | d | a | (16;dis);
-    lhz,        (r:d), (r:a:dis)        => (6;40) | d | a | (16;dis);
-    lwz,        (r:d), (r:a:dis)        => (6;32) | d | a | (16;dis);
-    lwzx,       (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (10;23) | (1;0);
-    mflr,       (r:d)                   => (6;31) | d | (10;0x100) | (10;339) | (1;0);
-    mr,         (r:a), (r:s)            => (6;31) | s | a | s | (10;444) | (1;0);
-    mtlr,       (r:d)                   => (6;31) | d | (10;0x100) | (10;467) | (1;0);
-    mullw[o][.],(r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;235) | (?.);
-    nop                                 => (32;0x60000000);
-    ori,        (r:d), (r:a), (i:imm)   => (6;24) | d | a | (16;imm);
-    oris,       (r:d), (r:a), (i:imm)   => (6;25) | d | a | (16;imm);
-    slwi,       (r:a), (r:s), (i:n)     => (6;21) | s | a | (5;{#n}) | (5;0) |(5;{31 - #n}) | (1;0);
-    srwi,       (r:a), (r:s), (i:n)     => (6;21) | s | a | (5;{32 - #n}) | (5;n) |(5;31) | (1;0);
-    rlwimi[.],  (r:a), (r:s), (i:sh), (i:mb), (i:me) =>
-        (6;20) | s | a | (5;sh) | (5;mb) |(5;me) | (?.);
-    rlwinm[.],  (r:a), (r:s), (i:sh), (i:mb), (i:me) =>
-        (6;21) | s | a | (5;sh) | (5;mb) |(5;me) | (?.);
-    stfs,       (f:d), (r:a:dis)        => (6;52) | d | a | (16;dis);
-    stfd,       (f:d), (r:a:dis)        => (6;54) | d | a | (16;dis);
-    stw,        (r:s), (r:a:dis)        => (6;36) | s | a | (16;dis);
-    stwu,       (r:s), (r:a:dis)        => (6;37) | s | a | (16;dis);
-    stb,        (r:s), (r:a:dis)        => (6;38) | s | a | (16;dis);
-    subf[o][.], (r:d), (r:a), (r:b)     => (6;31) | d | a | b | (?o) | (9;40) | (?.);
-    xori,       (r:s), (r:a), (i:imm)   => (6;26) | s | a | (16;imm);
-}
+include!("instrs.rs");
 
 #[proc_macro]
 pub fn ppcasm(tokens: TokenStream) -> TokenStream {
@@ -0,0 +1,291 @@
+// Disassembler driven by the same instruction table ppcasm! assembles from. Every entry (and
+// every combination of its flags) is tried against the word: operand fields are pulled out of
+// the word, the entry is re-encoded from them, and the entry only matches if that reproduces
+// the original word. When several entries match, the one with the most fixed bits wins, so e.g.
+// `li` is preferred over `addi` and `mr` is preferred over a raw `or`.
+
+#[derive(Copy, Clone, Debug)]
+struct Operand
+{
+    value: i64,
+    width: u8,
+    taken: bool,
+}
+
+impl Operand
+{
+    fn new(width: u8) -> Operand
+    {
+        Operand { value: 0, width, taken: false }
+    }
+
+    // Returns how many bits a repeated operand (e.g. `mr`'s rS) constrains
+    fn set(&mut self, value: i64, width: u8) -> u32
+    {
+        let repeated = if self.taken { width as u32 } else { 0 };
+        self.value = value;
+        self.width = width;
+        self.taken = true;
+        repeated
+    }
+
+    fn signed(&self) -> i64
+    {
+        sign_extend(self.value, self.width)
+    }
+}
+
+struct Fields
+{
+    word: u32,
+    pos: u8,
+}
+
+impl Fields
+{
+    fn new(word: u32) -> Fields
+    {
+        Fields { word, pos: 32 }
+    }
+
+    fn take(&mut self, width: u8) -> i64
+    {
+        self.pos -= width;
+        ((self.word as u64 >> self.pos) & ((1u64 << width) - 1)) as i64
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct EncPart
+{
+    width: u8,
+    value: i64,
+    fixed: bool,
+}
+
+impl EncPart
+{
+    fn fixed(width: u8, value: i64) -> EncPart
+    {
+        EncPart { width, value, fixed: true }
+    }
+
+    fn field(width: u8, value: i64) -> EncPart
+    {
+        EncPart { width, value, fixed: false }
+    }
+}
+
+// Returns the encoded word and the number of fixed bits, or None if a value doesn't fit its field
+fn encode(parts: &[EncPart]) -> Option<(u32, u32)>
+{
+    let mut word = 0u64;
+    let mut fixed_bits = 0;
+    for part in parts {
+        let width = part.width as u32;
+        let max = 1i64 << width;
+        if part.value >= max || part.value < -(max >> 1) {
+            return None;
+        }
+        word = (word << width) | (part.value & (max - 1)) as u64;
+        if part.fixed {
+            fixed_bits += width;
+        }
+    }
+    Some((word as u32, fixed_bits))
+}
+
+fn sign_extend(value: i64, width: u8) -> i64
+{
+    if width == 0 || width >= 64 {
+        return value;
+    }
+    let shift = 64 - width as u32;
+    (value << shift) >> shift
+}
+
+fn hex_signed(value: i64) -> String
+{
+    if value < 0 {
+        format!("-0x{:x}", -value)
+    } else {
+        format!("0x{:x}", value)
+    }
+}
+
+// 16-bit immediates of these instructions are signed (SIMM), everything else is shown unsigned
+const SIGNED_IMM_OPS: &[&str] = &["addi", "addic", "addic.", "cmpi", "cmpwi", "li", "mulli", "subfic", "twi"];
+
+fn format_imm(opname: &str, imm: &Operand) -> String
+{
+    if imm.width == 16 {
+        if SIGNED_IMM_OPS.contains(&opname) {
+            hex_signed(imm.signed())
+        } else {
+            format!("0x{:x}", imm.value)
+        }
+    } else {
+        format!("{}", imm.value)
+    }
+}
+
+// SPR numbers are encoded with their two 5-bit halves swapped
+fn format_spr(field: i64) -> String
+{
+    let spr = (((field & 0x1f) << 5) | (field >> 5)) as u32;
+    match SPR_NAMES.iter().find(|(_, n)| *n == spr) {
+        Some((name, _)) => name.to_string(),
+        None => format!("{}", spr),
+    }
+}
+
+fn format_branch_target(addr: u32, absolute: bool, li: &Operand, symbols: &dyn Fn(u32) -> Option<String>) -> String
+{
+    let offset = li.signed() << 2;
+    let target = if absolute {
+        offset as u32
+    } else {
+        (addr as i64 + offset) as u32
+    };
+    match symbols(target) {
+        Some(name) => format!("0x{:08x} <{}>", target, name),
+        None => format!("0x{:08x}", target),
+    }
+}
+
+macro_rules! flag_ident {
+    ($dotname:ident, $id:ident) => { $id };
+    ($dotname:ident, .) => { $dotname };
+}
+
+// Strips the `#` interpolation markers from a braced field expression, e.g. `{31 - #n}`
+macro_rules! unquote {
+    (($($out:tt)*)) => { ($($out)*) };
+    (($($out:tt)*) # $id:ident $($rest:tt)*) => { unquote!(($($out)* $id.value) $($rest)*) };
+    (($($out:tt)*) ($($inner:tt)*) $($rest:tt)*) => { unquote!(($($out)* unquote!(() $($inner)*)) $($rest)*) };
+    (($($out:tt)*) $t:tt $($rest:tt)*) => { unquote!(($($out)* $t) $($rest)*) };
+}
+
+macro_rules! decl_operand {
+    ((r:$i:ident:$d:ident)) => { let mut $i = Operand::new(5); let mut $d = Operand::new(16); };
+    ((r:$i:ident)) => { let mut $i = Operand::new(5); };
+    ((f:$i:ident)) => { let mut $i = Operand::new(5); };
+    ((cr:$i:ident)) => { let mut $i = Operand::new(3); };
+    ((spr:$i:ident)) => { let mut $i = Operand::new(10); };
+    ((i:$i:ident)) => { let mut $i = Operand::new(0); };
+    ((l:$i:ident)) => { let mut $i = Operand::new(0); };
+}
+
+macro_rules! take_part {
+    ($fields:ident, (?$flag:tt)) => { { $fields.take(1); 0 } };
+    ($fields:ident, ($width:expr ; {# $id:ident})) => { $id.set($fields.take($width), $width) };
+    ($fields:ident, ($width:expr ; {$($value:tt)+})) => { { $fields.take($width); 0 } };
+    ($fields:ident, ($width:expr ; $id:ident)) => { $id.set($fields.take($width), $width) };
+    ($fields:ident, ($width:expr ; $value:expr)) => { { $fields.take($width); 0 } };
+    ($fields:ident, $id:ident) => { { let width = $id.width; $id.set($fields.take(width), width) } };
+}
+
+macro_rules! encode_part {
+    ($dotname:ident, (?$flag:tt)) => { EncPart::fixed(1, flag_ident!($dotname, $flag)) };
+    ($dotname:ident, ($width:expr ; {# $id:ident})) => { EncPart::field($width, $id.value) };
+    ($dotname:ident, ($width:expr ; {$($value:tt)+})) => { EncPart::fixed($width, unquote!(() $($value)+)) };
+    ($dotname:ident, ($width:expr ; $id:ident)) => { EncPart::field($width, $id.value) };
+    ($dotname:ident, ($width:expr ; $value:expr)) => { EncPart::fixed($width, $value as i64) };
+    ($dotname:ident, $id:ident) => { EncPart::field($id.width, $id.value) };
+}
+
+macro_rules! format_operand {
+    ($ctx:ident, (r:$i:ident:$d:ident)) => { format!("{}(r{})", hex_signed($d.signed()), $i.value) };
+    ($ctx:ident, (r:$i:ident)) => { format!("r{}", $i.value) };
+    ($ctx:ident, (f:$i:ident)) => { format!("f{}", $i.value) };
+    ($ctx:ident, (cr:$i:ident)) => { format!("cr{}", $i.value) };
+    ($ctx:ident, (spr:$i:ident)) => { format_spr($i.value) };
+    ($ctx:ident, (i:$i:ident)) => { format_imm(&$ctx.opname, &$i) };
+    ($ctx:ident, (l:$i:ident)) => { format_branch_target($ctx.addr, $ctx.absolute, &$i, $ctx.symbols) };
+}
+
+struct FormatContext<'a>
+{
+    addr: u32,
+    opname: String,
+    absolute: bool,
+    symbols: &'a dyn Fn(u32) -> Option<String>,
+}
+
+macro_rules! decl_instrs {
+    ($( $nm:ident $([$flag:tt])* $(, $arg:tt)* => $($part:tt)|* ;)+) => {
+        #[allow(unused_mut, unused_variables, unused_assignments, clippy::identity_op)]
+        fn decode(addr: u32, word: u32, symbols: &dyn Fn(u32) -> Option<String>) -> Option<String>
+        {
+            // (fixed bits, text)
+            let mut best: Option<(u32, String)> = None;
+            $(
+            {
+                let flag_names: &[&str] = &[$(stringify!($flag)),*];
+                for combo in 0u32..(1 << flag_names.len()) {
+                    let mut _flag_bit = 0;
+                    $(
+                    let flag_ident!(__dot__, $flag): i64 = ((combo >> _flag_bit) & 1) as i64;
+                    _flag_bit += 1;
+                    )*
+                    $(decl_operand!($arg);)*
+
+                    let mut fields = Fields::new(word);
+                    let mut repeated_bits = 0;
+                    $(repeated_bits += take_part!(fields, $part);)*
+
+                    let parts = [$(encode_part!(__dot__, $part)),*];
+                    let fixed_bits = match encode(&parts) {
+                        Some((encoded, fixed_bits)) if encoded == word => fixed_bits + repeated_bits,
+                        _ => continue,
+                    };
+                    if best.as_ref().map(|b| b.0 >= fixed_bits).unwrap_or(false) {
+                        continue;
+                    }
+
+                    let mut opname = stringify!($nm).to_string();
+                    let mut absolute = false;
+                    for (i, flag) in flag_names.iter().enumerate() {
+                        if combo & (1 << i) != 0 {
+                            opname.push_str(flag);
+                            absolute |= *flag == "a";
+                        }
+                    }
+                    let ctx = FormatContext { addr, opname, absolute, symbols };
+                    let operands: Vec<String> = vec![$(format_operand!(ctx, $arg)),*];
+                    let text = if operands.is_empty() {
+                        ctx.opname
+                    } else {
+                        format!("{:<10} {}", ctx.opname, operands.join(", "))
+                    };
+                    best = Some((fixed_bits, text));
+                }
+            }
+            )*
+            best.map(|b| b.1)
+        }
+    };
+}
+
+include!("../ppcasm_macro/src/instrs.rs");
+
+/// Disassembles a single instruction located at `addr`. Words that aren't in the instruction
+/// table are shown as `.long` directives. `symbols` names branch targets.
+pub fn disassemble_instr(addr: u32, word: u32, symbols: &dyn Fn(u32) -> Option<String>) -> String
+{
+    decode(addr, word, symbols).unwrap_or_else(|| format!("{:<10} 0x{:08x}", ".long", word))
+}
+
+/// Disassembles big-endian code starting at `addr`, one line per word in the form
+/// `address: encoding  instruction`. Trailing bytes that don't fill a word are ignored.
+pub fn disassemble(addr: u32, bytes: &[u8], symbols: &dyn Fn(u32) -> Option<String>) -> Vec<String>
+{
+    bytes.chunks_exact(4)
+        .enumerate()
+        .map(|(i, chunk)| {
+            let instr_addr = addr + i as u32 * 4;
+            let word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            format!("{:08x}: {:08x}  {}", instr_addr, word, disassemble_instr(instr_addr, word, symbols))
+        })
+        .collect()
+}
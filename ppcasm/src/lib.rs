@@ -1,5 +1,8 @@
 pub use ppcasm_macro::ppcasm;
 
+mod disasm;
+pub use disasm::{disassemble, disassemble_instr};
+
 use std::io;
 
 use byteorder::{BigEndian, WriteBytesExt};
@@ -59,6 +62,11 @@ impl<A, L> AsmBlock<A, L>
     {
         &self.labels
     }
+
+    pub fn disassemble(&self, symbols: &dyn Fn(u32) -> Option<String>) -> Vec<String>
+    {
+        disassemble(self.addr, &self.encoded_bytes(), symbols)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -76,3 +76,215 @@ fn test_ppcasm()
         0x64, 0x00, 0x00, 0x00,
     ]);
 }
+
+fn words(bytes: &[u8]) -> Vec<u32>
+{
+    bytes.chunks(4).map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]])).collect()
+}
+
+#[test]
+fn test_gekko_instrs()
+{
+    let asm = ppcasm!(0x0, {
+        mtctr   r12;
+        bctrl;
+        bctr;
+        blrl;
+        beqlr;
+        bdnz    0x0;
+        mfspr   r3, hid2;
+        mtspr   gqr2, r3;
+        mtspr   912, r3;
+        mftb    r3;
+        mfmsr   r3;
+        mtmsr   r3;
+        mfcr    r0;
+        mtcr    r0;
+        cror    2, 1, 2;
+        crclr   6;
+        crset   6;
+        sync;
+        isync;
+    });
+    assert_eq!(words(&asm.encoded_bytes()), vec![
+        0x7d8903a6, 0x4e800421, 0x4e800420, 0x4e800021,
+        0x4d820020, 0x42000000 | (-0x14i32 as u32 & 0xfffc),
+        0x7c78e2a6, 0x7c72e3a6, 0x7c70e3a6, 0x7c6c42e6,
+        0x7c6000a6, 0x7c600124, 0x7c000026, 0x7c0ff120,
+        0x4c411382, 0x4cc63182, 0x4cc63242, 0x7c0004ac,
+        0x4c00012c,
+    ]);
+
+    let asm = ppcasm!(0x0, {
+        stmw    r27, 0xc(r1);
+        lmw     r27, 0xc(r1);
+        lwzu    r0, 4(r3);
+        stwx    r0, r3, r4;
+        sth     r0, 2(r3);
+        extsh   r0, r3;
+        extsb   r0, r0;
+        srawi   r0, r3, 1;
+        neg     r3, r3;
+        divw    r0, r3, r4;
+        divwu   r0, r3, r4;
+        mulli   r0, r3, 12;
+        subfic  r0, r3, 0;
+        sub     r3, r4, r5;
+        or      r3, r4, r5;
+        not     r3, r4;
+        clrlwi  r0, r3, 24;
+        dcbf    r0, r3;
+        dcbz    r0, r3;
+        icbi    r0, r3;
+    });
+    assert_eq!(words(&asm.encoded_bytes()), vec![
+        0xbf61000c, 0xbb61000c, 0x84030004, 0x7c03212e,
+        0xb0030002, 0x7c600734, 0x7c000774, 0x7c600e70,
+        0x7c6300d0, 0x7c0323d6, 0x7c032396, 0x1c03000c,
+        0x20030000, 0x7c652050, 0x7c832b78, 0x7c8320f8,
+        0x5460063e, 0x7c0018ac, 0x7c001fec, 0x7c001fac,
+    ]);
+
+    let asm = ppcasm!(0x0, {
+        frsp    f1, f1;
+        fctiwz  f0, f1;
+        fmuls   f0, f1, f2;
+        fmadds  f1, f2, f3, f4;
+        fsel    f1, f2, f3, f4;
+        stfiwx  f0, r3, r4;
+        ps_add  f0, f1, f2;
+        ps_merge00 f2, f0, f1;
+        ps_muls0 f0, f1, f2;
+        ps_cmpo0 cr0, f1, f2;
+        psq_l   f1, 0(r3), 0, 0;
+        psq_st  f0, 8(r3), 0, 0;
+        psq_l   f2, -8(r4), 1, 3;
+        psq_lx  f0, r3, r4, 0, 0;
+        dcbz_l  r0, r3;
+    });
+    assert_eq!(words(&asm.encoded_bytes()), vec![
+        0xfc200818, 0xfc00081e, 0xec0100b2, 0xec2220fa,
+        0xfc2220ee, 0x7c0327ae, 0x1001102a, 0x10400c20,
+        0x10010098, 0x10011040, 0xe0230000, 0xf0030008,
+        0xe044bff8, 0x1003200c, 0x10001fec,
+    ]);
+
+    let asm = ppcasm!(0x0, {
+        cmp     cr1, 0, r3, r4;
+        cmpl    cr0, 0, r3, r4;
+        cmpi    cr1, 0, r3, -1;
+        cmpli   cr7, 0, r0, 5;
+        lwarx   r3, r4, r5;
+        stwcx.  r3, r4, r5;
+        tw      4, r3, r4;
+        twi     4, r3, 0x10;
+        mcrf    cr0, cr1;
+        mcrxr   cr0;
+        mtfsb0  30;
+        mtfsb1  31;
+        mtfsfi  cr0, 1;
+        mcrfs   cr1, cr2;
+        mfsr    r3, 1;
+        mtsr    1, r3;
+    });
+    assert_eq!(words(&asm.encoded_bytes()), vec![
+        0x7c832000, 0x7c032040, 0x2c83ffff, 0x2b800005,
+        0x7c642828, 0x7c64292d, 0x7c832008, 0x0c830010,
+        0x4c040000, 0x7c000400, 0xffc0008c, 0xffe0004c,
+        0xfc00110c, 0xfc880080, 0x7c6104a6, 0x7c6101a4,
+    ]);
+}
+
+#[test]
+fn test_disassemble()
+{
+    let no_symbols = |_| None;
+    let asm = ppcasm!(0x80001000, {
+        stwu    r1, -0x24(r1);
+        mflr    r0;
+        lwz     r3, 0x8b8(r25);
+        li      r4, -1;
+        addi    r5, r3, 0x10;
+        mr      r31, r3;
+        rlwinm  r4, r4, 2, 0, 29;
+        slwi    r4, r4, 2;
+        cmpwi   r3, 0;
+        beq     0x80001000;
+        bl      0x80002000;
+        lfs     f1, 0x40(r14);
+        fcmpu   cr0, f1, f2;
+        add.    r3, r4, r5;
+        nop;
+        blr;
+        .long   0;
+    });
+    assert_eq!(asm.disassemble(&no_symbols), vec![
+        "80001000: 9421ffdc  stwu       r1, -0x24(r1)",
+        "80001004: 7c0802a6  mflr       r0",
+        "80001008: 807908b8  lwz        r3, 0x8b8(r25)",
+        "8000100c: 3880ffff  li         r4, -0x1",
+        "80001010: 38a30010  addi       r5, r3, 0x10",
+        "80001014: 7c7f1b78  mr         r31, r3",
+        "80001018: 5484103a  slwi       r4, r4, 2",
+        "8000101c: 5484103a  slwi       r4, r4, 2",
+        "80001020: 2c030000  cmpwi      r3, 0x0",
+        "80001024: 4182ffdc  beq        0x80001000",
+        "80001028: 48000fd9  bl         0x80002000",
+        "8000102c: c02e0040  lfs        f1, 0x40(r14)",
+        "80001030: fc011000  fcmpu      cr0, f1, f2",
+        "80001034: 7c642a15  add.       r3, r4, r5",
+        "80001038: 60000000  nop",
+        "8000103c: 4e800020  blr",
+        "80001040: 00000000  .long      0x00000000",
+    ]);
+
+    let asm = ppcasm!(0x80001000, {
+        mtspr   gqr2, r3;
+        mfspr   r3, 1000;
+        mfctr   r0;
+        crclr   6;
+        not     r3, r4;
+        sub     r3, r4, r5;
+        clrlwi  r0, r3, 24;
+        ps_madd f1, f2, f3, f4;
+        psq_l   f2, -8(r4), 1, 3;
+        bctrl;
+    });
+    assert_eq!(asm.disassemble(&no_symbols), vec![
+        "80001000: 7c72e3a6  mtspr      gqr2, r3",
+        "80001004: 7c68faa6  mfspr      r3, 1000",
+        "80001008: 7c0902a6  mfctr      r0",
+        "8000100c: 4cc63182  crclr      6",
+        "80001010: 7c8320f8  not        r3, r4",
+        "80001014: 7c652050  subf       r3, r5, r4",
+        "80001018: 5460063e  clrlwi     r0, r3, 24",
+        "8000101c: 102220fa  ps_madd    f1, f2, f3, f4",
+        "80001020: e044bff8  psq_l      f2, -0x8(r4), 1, 3",
+        "80001024: 4e800421  bctrl",
+    ]);
+
+    let asm = ppcasm!(0x80001000, {
+        cmp     cr1, 0, r3, r4;
+        cmpw    r3, r4;
+        cmpi    cr1, 0, r3, -1;
+        stwcx.  r3, r4, r5;
+        mcrf    cr0, cr1;
+        mtfsb1  31;
+        trap;
+    });
+    assert_eq!(asm.disassemble(&no_symbols), vec![
+        "80001000: 7c832000  cmp        cr1, 0, r3, r4",
+        "80001004: 7c032000  cmpw       r3, r4",
+        "80001008: 2c83ffff  cmpi       cr1, 0, r3, -0x1",
+        "8000100c: 7c64292d  stwcx.     r3, r4, r5",
+        "80001010: 4c040000  mcrf       cr0, cr1",
+        "80001014: ffe0004c  mtfsb1     31",
+        "80001018: 7fe00008  trap",
+    ]);
+
+    let symbols = |addr| if addr == 0x80002000 { Some("OSInit".to_string()) } else { None };
+    assert_eq!(
+        ppcasm::disassemble_instr(0x80001028, 0x48000fd9, &symbols),
+        "bl         0x80002000 <OSInit>",
+    );
+}
@@ -4,6 +4,7 @@ use std::slice::Iter as SliceIter;
 use std::slice::IterMut as SliceIterMut;
 
 use crate::lcow::LCow;
+use crate::read_error::ReadError;
 use crate::reader::{Reader, Readable};
 use crate::writer::Writable;
 use crate::read_only_array::{RoArray, RoArrayIter};
@@ -23,6 +24,16 @@ impl<'r, T> Readable<'r> for Vec<T>
         res
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (len, args): Self::Args) -> Result<Self, ReadError>
+    {
+        // Don't trust len with an allocation before anything has been read
+        let mut res = Vec::with_capacity(len.min(reader.len()));
+        for _ in 0..len {
+            res.push(reader.try_read(args.clone())?);
+        };
+        Ok(res)
+    }
+
     fn size(&self) -> usize
     {
         T::fixed_size()
@@ -130,6 +141,11 @@ impl<'r, T> Readable<'r> for LazyArray<'r, T>
         LazyArray::Borrowed(array)
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, args: Self::Args) -> Result<Self, ReadError>
+    {
+        RoArray::try_read_from(reader, args).map(LazyArray::Borrowed)
+    }
+
     fn size(&self) -> usize
     {
         T::fixed_size()
@@ -3,6 +3,7 @@ use std::io;
 use crate::{
     derivable_array_proxy::DerivableFromIterator,
     generic_array::{GenericArray, ArrayLength},
+    read_error::ReadError,
     reader::{Reader, Readable},
     writer::Writable,
 };
@@ -26,6 +27,15 @@ impl<'r, T, N> Readable<'r> for FixedArray<T, N>
         array
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, args: Self::Args) -> Result<Self, ReadError>
+    {
+        let mut elems = Vec::with_capacity(N::to_usize());
+        for _ in 0..N::to_usize() {
+            elems.push(reader.try_read(args.clone())?);
+        }
+        Ok(GenericArray::from_exact_iter(elems).unwrap())
+    }
+
     fn size(&self) -> usize
     {
         <Self as Readable>::fixed_size()
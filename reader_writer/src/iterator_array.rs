@@ -6,6 +6,7 @@ use std::{
 
 use crate::{
     lcow::LCow,
+    read_error::ReadError,
     reader::{Reader, Readable},
     writer::Writable,
 };
@@ -72,6 +73,19 @@ impl<'r, T, I> Readable<'r> for IteratorArray<'r, T, I>
         res
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, i: I) -> Result<Self, ReadError>
+    {
+        let res = IteratorArray::Borrowed(reader.clone(), i.clone());
+        if let Some(size) = T::fixed_size() {
+            reader.try_advance(size * i.len())?;
+        } else {
+            for args in i {
+                reader.try_read::<T>(args)?;
+            }
+        }
+        Ok(res)
+    }
+
     fn size(&self) -> usize
     {
         if let Some(i) = T::fixed_size() {
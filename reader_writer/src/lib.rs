@@ -3,6 +3,7 @@ pub use byteorder;
 pub use generic_array;
 
 pub mod reader;
+pub mod read_error;
 pub mod writer;
 
 pub mod primitive_types;
@@ -25,6 +26,7 @@ pub use crate::{
     generic_array::typenum,
 
     reader::{Reader, Readable},
+    read_error::{ReadError, ReadErrorKind},
     writer::Writable,
 
     primitive_types::{FourCC, CStr, CStrConversionExtension},
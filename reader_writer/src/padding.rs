@@ -1,6 +1,7 @@
 //! Utilities for padding for alignment
 use std::io;
 use crate::{
+    read_error::ReadError,
     read_only_array::RoArray,
     reader::{Readable, Reader},
     writer::Writable,
@@ -45,6 +46,12 @@ impl<'r> Readable<'r> for PaddingBlackhole
         PaddingBlackhole(i)
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, i: Self::Args) -> Result<Self, ReadError>
+    {
+        reader.try_advance(i)?;
+        Ok(PaddingBlackhole(i))
+    }
+
     fn size(&self) -> usize
     {
         self.0
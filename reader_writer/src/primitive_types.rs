@@ -9,6 +9,7 @@ use std::marker::PhantomData;
 
 use byteorder::{ReadBytesExt, WriteBytesExt, BigEndian};
 
+use crate::read_error::{ReadError, ReadErrorKind};
 use crate::reader::{Readable, Reader};
 use crate::writer::Writable;
 
@@ -24,6 +25,12 @@ macro_rules! define_arith_readable {
                     res.unwrap()
                 }
 
+                fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<$T, ReadError>
+                {
+                    reader.check_len(mem::size_of::<$T>())?;
+                    Ok(Self::read_from(reader, ()))
+                }
+
                 fn fixed_size() -> Option<usize>
                 {
                     Some(mem::size_of::<$T>())
@@ -53,6 +60,12 @@ macro_rules! define_byte_readable {
                     res.unwrap()
                 }
 
+                fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<$T, ReadError>
+                {
+                    reader.check_len(mem::size_of::<$T>())?;
+                    Ok(Self::read_from(reader, ()))
+                }
+
                 fn fixed_size() -> Option<usize>
                 {
                     Some(mem::size_of::<$T>())
@@ -118,6 +131,12 @@ impl<'r> Readable<'r> for FourCC
         FourCC::from_bytes(&res)
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<FourCC, ReadError>
+    {
+        reader.check_len(4)?;
+        Ok(Self::read_from(reader, ()))
+    }
+
     fn fixed_size() -> Option<usize>
     {
         Some(4)
@@ -174,6 +193,11 @@ impl<'r, T> Readable<'r> for Option<T>
         }
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, args: Self::Args) -> Result<Option<T>, ReadError>
+    {
+        args.map(|args| reader.try_read(args)).transpose()
+    }
+
     fn size(&self) -> usize
     {
         self.as_ref().map(|i| i.size()).unwrap_or(0)
@@ -202,6 +226,11 @@ impl<'r, T> Readable<'r> for Box<T>
         Box::new(reader.read(args))
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, args: T::Args) -> Result<Box<T>, ReadError>
+    {
+        reader.try_read(args).map(Box::new)
+    }
+
     fn size(&self) -> usize
     {
         <T as Readable>::size(&self)
@@ -259,6 +288,15 @@ impl<'r> Readable<'r> for CStr<'r>
         cstr
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<CStr<'r>, ReadError>
+    {
+        if !reader.contains(&b'\0') {
+            let msg = "string is missing its nul terminator".to_string();
+            return Err(reader.error(ReadErrorKind::Invalid(msg)));
+        }
+        Ok(Self::read_from(reader, ()))
+    }
+
     fn size(&self) -> usize
     {
         self.to_bytes_with_nul().len()
@@ -0,0 +1,93 @@
+use std::{error, fmt};
+
+/// What was wrong with the data
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReadErrorKind
+{
+    /// The data ended before the value did
+    UnexpectedEof { needed: usize, remaining: usize },
+    /// A field with a fixed value (a magic number, version, ...) held something else
+    UnexpectedValue { expected: String, found: String },
+    /// Anything else that can't be parsed (an unknown tag, an unsupported format, ...)
+    Invalid(String),
+}
+
+impl fmt::Display for ReadErrorKind
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        match self {
+            ReadErrorKind::UnexpectedEof { needed, remaining } =>
+                write!(f, "needed {} bytes but only {} remain", needed, remaining),
+            ReadErrorKind::UnexpectedValue { expected, found } =>
+                write!(f, "expected {}, found {}", expected, found),
+            ReadErrorKind::Invalid(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Returned by `Readable::try_read_from` when the data is malformed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReadError
+{
+    pub kind: ReadErrorKind,
+    /// Offset of the bad data from the start of the buffer the `Reader` was created with
+    pub offset: usize,
+    /// The (struct name, field name) pairs that were being read, innermost first
+    pub fields: Vec<(&'static str, &'static str)>,
+}
+
+impl ReadError
+{
+    pub fn new(kind: ReadErrorKind, offset: usize) -> ReadError
+    {
+        ReadError {
+            kind,
+            offset,
+            fields: vec![],
+        }
+    }
+
+    /// Records that the error happened while reading `type_name::field`
+    pub fn in_field(mut self, type_name: &'static str, field: &'static str) -> ReadError
+    {
+        self.fields.push((type_name, field));
+        self
+    }
+
+    /// The innermost struct that was being read
+    pub fn type_name(&self) -> Option<&'static str>
+    {
+        self.fields.first().map(|(type_name, _)| *type_name)
+    }
+
+    /// The innermost field that was being read
+    pub fn field(&self) -> Option<&'static str>
+    {
+        self.fields.first().map(|(_, field)| *field)
+    }
+}
+
+impl fmt::Display for ReadError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        if let Some((type_name, field)) = self.fields.first() {
+            write!(f, "{}.{}", type_name, field)?;
+            if self.fields.len() > 1 {
+                write!(f, " (in ")?;
+                for (i, (type_name, field)) in self.fields[1..].iter().rev().enumerate() {
+                    if i > 0 {
+                        write!(f, " > ")?;
+                    }
+                    write!(f, "{}.{}", type_name, field)?;
+                }
+                write!(f, ")")?;
+            }
+            write!(f, " ")?;
+        }
+        write!(f, "at offset {:#x}: {}", self.offset, self.kind)
+    }
+}
+
+impl error::Error for ReadError { }
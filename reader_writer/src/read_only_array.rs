@@ -5,6 +5,7 @@ use std::{
 };
 
 use crate::{
+    read_error::ReadError,
     reader::{Reader, Readable},
     writer::Writable,
     derivable_array_proxy::DerivableFromIterator,
@@ -106,6 +107,29 @@ impl<'r, T> Readable<'r> for RoArray<'r, T>
         array
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (length, args): Self::Args)
+        -> Result<Self, ReadError>
+    {
+        let size = match T::fixed_size() {
+            Some(i) => i * length,
+            // The elements have to be read to find their sizes anyway, so check them as we go
+            None => {
+                let mut elems_reader = reader.clone();
+                for _ in 0..length {
+                    elems_reader.try_read::<T>(args.clone())?;
+                }
+                reader.len() - elems_reader.len()
+            },
+        };
+        let array = RoArray {
+            t_args: args,
+            length,
+            data_start: reader.try_truncated(size)?,
+        };
+        reader.advance(size);
+        Ok(array)
+    }
+
     fn size(&self) -> usize
     {
         self.data_start.len()
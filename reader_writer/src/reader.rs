@@ -4,10 +4,15 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::writer::Writable;
+use crate::{
+    read_error::{ReadError, ReadErrorKind},
+    writer::Writable,
+};
 
+// The second field is the address of the buffer `new` was called with. Sub-readers keep it, so
+// errors can report where in the original buffer they happened.
 #[derive(Clone)]
-pub struct Reader<'r>(&'r [u8]);
+pub struct Reader<'r>(&'r [u8], usize);
 
 
 impl<'r> Deref for Reader<'r>
@@ -40,12 +45,12 @@ impl <'r> Reader<'r>
 {
     pub fn new(data: &'r [u8]) -> Reader<'r>
     {
-        Reader(data)
+        Reader(data, data.as_ptr() as usize)
     }
 
     pub fn dummy() -> Reader<'r>
     {
-        Reader(&[])
+        Reader::new(&[])
     }
 
     pub fn read<T>(&mut self, args: T::Args) -> T
@@ -54,24 +59,71 @@ impl <'r> Reader<'r>
         T::read_from(self, args)
     }
 
+    pub fn try_read<T>(&mut self, args: T::Args) -> Result<T, ReadError>
+        where T : Readable<'r>
+    {
+        T::try_read_from(self, args)
+    }
+
+    /// How far into the buffer passed to `new` this reader is
+    pub fn position(&self) -> usize
+    {
+        (self.0.as_ptr() as usize).wrapping_sub(self.1)
+    }
+
+    /// An error of the given kind at the reader's current position
+    pub fn error(&self, kind: ReadErrorKind) -> ReadError
+    {
+        ReadError::new(kind, self.position())
+    }
+
+    /// Fails unless at least `len` bytes remain
+    pub fn check_len(&self, len: usize) -> Result<(), ReadError>
+    {
+        if len > self.0.len() {
+            Err(self.error(ReadErrorKind::UnexpectedEof { needed: len, remaining: self.0.len() }))
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn advance(&mut self, len: usize)
     {
         self.0 = self.0.split_at(len).1
     }
 
+    pub fn try_advance(&mut self, len: usize) -> Result<(), ReadError>
+    {
+        self.check_len(len)?;
+        self.advance(len);
+        Ok(())
+    }
+
     pub fn offset(&self, len: usize) -> Reader<'r>
     {
-        Reader(self.0.split_at(len).1)
+        Reader(self.0.split_at(len).1, self.1)
+    }
+
+    pub fn try_offset(&self, len: usize) -> Result<Reader<'r>, ReadError>
+    {
+        self.check_len(len)?;
+        Ok(self.offset(len))
     }
 
     pub fn truncate(&mut self, len: usize)
     {
-        *self = Reader(&self.0[0..len])
+        *self = Reader(&self.0[0..len], self.1)
     }
 
     pub fn truncated(&self, len: usize) -> Reader<'r>
     {
-        Reader(&self.0[0..len])
+        Reader(&self.0[0..len], self.1)
+    }
+
+    pub fn try_truncated(&self, len: usize) -> Result<Reader<'r>, ReadError>
+    {
+        self.check_len(len)?;
+        Ok(self.truncated(len))
     }
 }
 
@@ -103,6 +155,14 @@ pub trait Readable<'r> : Sized
     type Args;
     fn read_from(reader: &mut Reader<'r>, args: Self::Args) -> Self;
 
+    /// Like `read_from`, but malformed data is reported as an error instead of a panic.
+    ///
+    /// Types that can't tell when their data is malformed can keep this default.
+    fn try_read_from(reader: &mut Reader<'r>, args: Self::Args) -> Result<Self, ReadError>
+    {
+        Ok(Self::read_from(reader, args))
+    }
+
     fn size(&self) -> usize
     {
         Self::fixed_size().expect("Expected fixed size")
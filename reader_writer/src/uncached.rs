@@ -5,6 +5,7 @@ use std::{
 
 use crate::{
     lcow::LCow,
+    read_error::ReadError,
     reader::{Reader, Readable},
     writer::Writable,
 };
@@ -54,6 +55,15 @@ impl<'r, T> Readable<'r> for Uncached<'r, T>
         Uncached::Borrowed(start_reader.truncated(size), args)
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, args: Self::Args) -> Result<Self, ReadError>
+    {
+        let start_reader = reader.clone();
+        let _ = <T as Readable>::try_read_from(reader, args.clone())?;
+        let size = start_reader.len() - reader.len();
+
+        Ok(Uncached::Borrowed(start_reader.truncated(size), args))
+    }
+
     fn size(&self) -> usize
     {
         match self {
@@ -7,6 +7,7 @@ use std::{
 };
 
 use crate::{
+    read_error::ReadError,
     reader::{Readable, Reader},
     writer::Writable,
 };
@@ -37,6 +38,14 @@ impl<'r> Readable<'r> for Utf16beStr<'r>
         Utf16beStr(start_reader.truncated(read_len))
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        let start_reader = reader.clone();
+        while reader.try_read::<u16>(())? != 0 { }
+        let read_len = start_reader.len() - reader.len();
+        Ok(Utf16beStr(start_reader.truncated(read_len)))
+    }
+
     fn size(&self) -> usize
     {
         self.0.len()
@@ -196,6 +205,11 @@ impl<'r> Readable<'r> for LazyUtf16beStr<'r>
         LazyUtf16beStr::Borrowed(s)
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        reader.try_read(()).map(LazyUtf16beStr::Borrowed)
+    }
+
     fn size(&self) -> usize
     {
         match *self {
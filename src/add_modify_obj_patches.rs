@@ -32,6 +32,7 @@ use crate::{
         CounterConfig,
         SwitchConfig,
         PlayerHintConfig,
+        AddObjectConfig,
     },
     pickup_meta::PickupType,
     door_meta::DoorType,
@@ -48,6 +49,10 @@ use structs::{res_id, ResId, SclyPropertyData};
 
 macro_rules! add_edit_obj_helper {
     ($area:expr, $id:expr, $requested_layer_id:expr, $object_type:ident, $new_property_data:ident, $update_property_data:ident) => {
+        add_edit_obj_helper!($area, $id, $requested_layer_id, (structs::$object_type::OBJECT_TYPE), $new_property_data, $update_property_data);
+    };
+    ($area:expr, $id:expr, $requested_layer_id:expr, ($object_type:expr), $new_property_data:ident, $update_property_data:ident) => {
+        let object_type = $object_type;
         let area = $area;
         let id = $id;
         let requested_layer_id = $requested_layer_id;
@@ -79,8 +84,8 @@ macro_rules! add_edit_obj_helper {
                         .find(|obj| obj.instance_id & 0x00FFFFFF == id & 0x00FFFFFF);
 
                     if let Some(obj) = obj {
-                        if obj.property_data.object_type() != structs::$object_type::OBJECT_TYPE {
-                            panic!("Failed to edit existing object 0x{:X} in room 0x{:X}: Unexpected object type 0x{:X} (expected 0x{:X})", id, mrea_id, obj.property_data.object_type(), structs::$object_type::OBJECT_TYPE);
+                        if obj.property_data.object_type() != object_type {
+                            panic!("Failed to edit existing object 0x{:X} in room 0x{:X}: Unexpected object type 0x{:X} (expected 0x{:X})", id, mrea_id, obj.property_data.object_type(), object_type);
                         }
 
                         info = Some((_layer_id as u32, obj.instance_id));
@@ -152,7 +157,7 @@ macro_rules! add_edit_obj_helper {
         let property_data = $new_property_data!();
         let property_data: structs::SclyProperty = property_data.into();
 
-        assert!(property_data.object_type() == structs::$object_type::OBJECT_TYPE);
+        assert!(property_data.object_type() == object_type);
 
         objects.push(
             structs::SclyObject {
@@ -801,6 +806,31 @@ pub fn patch_add_player_hint<'r>(
     add_edit_obj_helper!(area, Some(config.id), config.layer, PlayerHint, new, update);
 }
 
+pub fn patch_add_object(
+    _ps: &mut PatcherState,
+    area: &mut mlvl_wrapper::MlvlArea,
+    config: AddObjectConfig,
+)
+    -> Result<(), String>
+{
+    let property_data = structs::SclyProperty::deserialize_named(&config.object_type, config.properties)
+        .map_err(|e| format!("Failed to parse properties of {} object: {}", config.object_type, e))?;
+
+    macro_rules! new {
+        () => {
+            property_data
+        };
+    }
+
+    macro_rules! update {
+        ($obj:expr) => {
+            $obj.property_data = property_data;
+        };
+    }
+
+    add_edit_obj_helper!(area, config.id, config.layer, (property_data.object_type()), new, update);
+}
+
 pub fn patch_add_platform<'r>(
     _ps: &mut PatcherState,
     area: &mut mlvl_wrapper::MlvlArea<'r, '_, '_, '_>,
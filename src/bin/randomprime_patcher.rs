@@ -115,12 +115,13 @@ fn main()
     was_launched_by_windows_explorer();
 
     // On non-debug builds, suppress the default panic message and print a more helpful and
-    // user-friendly one
+    // user-friendly one. Malformed input found while parsing the disc and its PAKs is reported
+    // as a regular error; this only catches data that's parsed later on.
     if !cfg!(debug_assertions) {
         panic::set_hook(Box::new(|_| {
             let _ = eprintln!("{} \
-An error occurred while parsing the input ISO. \
-This most likely means your ISO is corrupt. \
+An unexpected error occurred while patching the input ISO. \
+This may mean your ISO is corrupt or modified. \
 Please verify that your ISO matches one of the following hashes:
 MD5:  eeacd0ced8e2bae491eca14f141a4b7c
 SHA1: ac20c744db18fdf0339f37945e880708fd317231
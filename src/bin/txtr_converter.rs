@@ -7,10 +7,11 @@ use image::codecs::png::{PngDecoder, PngEncoder};
 use structs::{Txtr, TxtrFormat, TxtrPaletteFormat};
 use libsquish_wrapper::{compress_dxt1gcn_block, decompress_dxt1gcn_block};
 use reader_writer::{Readable, Reader, Writable};
+use serde::{Deserialize, Serialize};
 
 use std::convert::TryInto;
-use std::collections::HashMap;
-use std::fs::File;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
 use std::iter;
 use std::path::Path;
 
@@ -71,7 +72,13 @@ fn txtr2png(input: &Path, output: &Path, mipmap: usize) -> Result<(), String> {
     Ok(())
 }
 
-fn png2txtr(input: &Path, output: &Path, mipmap_count: Option<u8>, mut format: TxtrFormat)
+fn png2txtr(
+    input: &Path,
+    output: &Path,
+    mipmap_count: Option<u8>,
+    mut format: TxtrFormat,
+    dither: bool,
+)
     -> Result<(), String>
 {
     let input_file = File::open(input)
@@ -102,8 +109,7 @@ fn png2txtr(input: &Path, output: &Path, mipmap_count: Option<u8>, mut format: T
     decoder.read_image(&mut uncompressed_pixels[..])
         .map_err(|e| format!("Error reading input PNG: {}", e))?;
 
-    format.compute_palette(&uncompressed_pixels[..])
-        .map_err(|()| "Image contains too many colors for choosen paletted format")?;
+    format.compute_palette(&mut uncompressed_pixels[..], w, dither);
 
     let max_mipmaps_for_fomat = match format {
         TxtrFormat::C4(_, _) | TxtrFormat::C8(_, _) => 1,
@@ -177,6 +183,179 @@ fn png2txtr(input: &Path, output: &Path, mipmap_count: Option<u8>, mut format: T
     Ok(())
 }
 
+/*
+    A batch manifest lists the PNGs to convert. Paths are relative to the manifest's directory.
+
+    {
+        "textures": [
+            {
+                "input": "holorim.png", "format": "C8(RGB5A3)", "mipmapCount": 1,
+                "id": 3735928559, "usedBy": [3735928560]
+            },
+            { "input": "icon.png", "output": "icon.TXTR", "format": "RGB5A3", "dither": true }
+        ],
+        "meta": {
+            "items": { "Holorim": { "ancs": 3735928561, "cmdl": 3735928560, ... } },
+            "newAssets": [ { "new_id": 3735928561, "dependencies": [ ... ] } ]
+        }
+    }
+
+    Textures with an `id` default to "<id>.TXTR" so `ExternPickupModel::parse` can find them by
+    id. When `meta` is present, a meta.json is written next to the TXTRs holding its items and
+    new assets. `ExternPickupModel::parse` only pulls in assets reachable from an item's ANCS or
+    CMDL, so each texture's `usedBy` ids (usually the CMDL that samples it) get a TXTR
+    dependency on the texture, and an asset that isn't in `newAssets` yet is added for them.
+*/
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BatchManifest
+{
+    textures: Vec<BatchTexture>,
+    meta: Option<BatchMeta>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BatchTexture
+{
+    input: String,
+    output: Option<String>,
+    format: String,
+    mipmap_count: Option<u8>,
+    id: Option<u32>,
+    #[serde(default)]
+    used_by: Vec<u32>,
+    #[serde(default)]
+    dither: bool,
+}
+
+// Passed through to meta.json, along with the texture dependencies from `usedBy`
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct BatchMeta
+{
+    #[serde(default)]
+    items: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    new_assets: Vec<BatchNewAsset>,
+}
+
+// Same layout as meta.json's `new_assets` entries
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct BatchNewAsset
+{
+    new_id: u32,
+    #[serde(default)]
+    dependencies: Vec<BatchDependency>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct BatchDependency
+{
+    #[serde(alias = "type")]
+    fourcc: String,
+    id: u32,
+}
+
+fn batch(manifest_path: &Path, output_dir: Option<&Path>) -> Result<(), String> {
+    let manifest = fs::read_to_string(manifest_path)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    let manifest: BatchManifest = serde_json::from_str(&manifest)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    let base_dir = manifest_path.parent().unwrap_or_else(|| Path::new(""));
+    let output_dir = output_dir.unwrap_or(base_dir);
+    fs::create_dir_all(output_dir)
+        .map_err(|e| format!("Failed to create output dir '{}': {}", output_dir.display(), e))?;
+
+    let mut ids = HashSet::new();
+    for texture in manifest.textures.iter() {
+        if let Some(id) = texture.id {
+            if !ids.insert(id) {
+                Err(format!("Texture id {} is used more than once", id))?
+            }
+        }
+        if !texture.used_by.is_empty() {
+            if texture.id.is_none() {
+                Err(format!("Texture '{}' has usedBy but no id", texture.input))?
+            }
+            if manifest.meta.is_none() {
+                Err(format!("Texture '{}' has usedBy but the manifest has no meta", texture.input))?
+            }
+        }
+    }
+
+    // Keep going after a failure so every broken texture is reported in one run
+    let mut failed = 0;
+    for texture in manifest.textures.iter() {
+        let input = base_dir.join(&texture.input);
+        let output = match (&texture.output, texture.id) {
+            (Some(output), _) => output_dir.join(output),
+            (None, Some(id)) => output_dir.join(format!("{}.TXTR", id)),
+            (None, None) => {
+                let stem = Path::new(&texture.input).file_stem().unwrap_or_default();
+                output_dir.join(stem).with_extension("TXTR")
+            },
+        };
+
+        let res = TxtrFormat::from_str(&texture.format)
+            .map_err(|()| format!("Unknown format \"{}\"", texture.format))
+            .and_then(|format| png2txtr(&input, &output, texture.mipmap_count, format, texture.dither));
+        match res {
+            Ok(()) => println!("{} -> {}", input.display(), output.display()),
+            Err(e) => {
+                eprintln!("{} {}: {}", clap::Format::Error("error:"), input.display(), e);
+                failed += 1;
+            },
+        }
+    }
+
+    if let Some(meta) = manifest.meta {
+        let mut new_assets = meta.new_assets;
+        for texture in manifest.textures.iter() {
+            let id = match texture.id {
+                Some(id) => id,
+                None => continue,
+            };
+            let dependency = BatchDependency { fourcc: "TXTR".to_string(), id };
+            for &owner in texture.used_by.iter() {
+                let asset = match new_assets.iter().position(|asset| asset.new_id == owner) {
+                    Some(i) => &mut new_assets[i],
+                    None => {
+                        new_assets.push(BatchNewAsset { new_id: owner, dependencies: vec![] });
+                        new_assets.last_mut().unwrap()
+                    },
+                };
+                if !asset.dependencies.contains(&dependency) {
+                    asset.dependencies.push(dependency.clone());
+                }
+            }
+        }
+        for id in manifest.textures.iter().filter_map(|texture| texture.id) {
+            let referenced = new_assets.iter()
+                .any(|asset| asset.dependencies.iter().any(|dep| dep.id == id));
+            if !referenced {
+                println!("Warning, no asset depends on texture {}, so it won't be patched in", id);
+            }
+        }
+        let json = serde_json::json!({ "items": meta.items, "new_assets": new_assets });
+
+        let meta_path = output_dir.join("meta.json");
+        let json = serde_json::to_string_pretty(&json)
+            .map_err(|e| format!("Failed to serialize meta.json: {}", e))?;
+        fs::write(&meta_path, json)
+            .map_err(|e| format!("Failed to write '{}': {}", meta_path.display(), e))?;
+    }
+
+    if failed != 0 {
+        Err(format!("{} of {} textures failed to convert", failed, manifest.textures.len()))?
+    }
+
+    Ok(())
+}
+
 fn box_filter_pixels(pixels: &[u8], w: usize, h: usize, chan_count: usize, discretize_alpha: bool)
     -> Vec<u8>
 {
@@ -271,12 +450,165 @@ fn decode_rgb565(texel: [u8; 2]) -> [u8; 3] {
     ]
 }
 
+fn encode_palette_entry(fmt: &TxtrPaletteFormat, pixel: &[u8]) -> [u8; 2] {
+    match fmt {
+        TxtrPaletteFormat::Ia8 => pixel.try_into().unwrap(),
+        TxtrPaletteFormat::Rgb565 => encode_rgb565(pixel.try_into().unwrap()),
+        TxtrPaletteFormat::Rgb5A3 => encode_rgb5a3(pixel.try_into().unwrap()),
+    }
+}
+
+fn decode_palette_entry(fmt: &TxtrPaletteFormat, entry: [u8; 2], pixel: &mut [u8]) {
+    match fmt {
+        TxtrPaletteFormat::Ia8 => pixel.copy_from_slice(&entry[..]),
+        TxtrPaletteFormat::Rgb565 => pixel.copy_from_slice(&decode_rgb565(entry)[..]),
+        TxtrPaletteFormat::Rgb5A3 => pixel.copy_from_slice(&decode_rgb5a3(entry)[..]),
+    }
+}
+
+// Fully transparent pixels all look the same, so their color channels are ignored
+fn histogram_key(pixel: &[u8], alpha: Option<usize>) -> [u8; 4] {
+    let mut key = [0u8; 4];
+    if alpha.map(|a| pixel[a] != 0).unwrap_or(true) {
+        key[..pixel.len()].copy_from_slice(pixel);
+    }
+    key
+}
+
+// Reduces the image to at most `max_colors` colors with median cut: the set of colors is
+// repeatedly split at the (pixel count weighted) median of its widest channel, and each final
+// box is replaced by its weighted average.
+fn median_cut(pixels: &[u8], channel_count: usize, alpha: Option<usize>, max_colors: usize)
+    -> Vec<Vec<u8>>
+{
+    let mut histogram = HashMap::new();
+    for pixel in pixels.chunks(channel_count) {
+        *histogram.entry(histogram_key(pixel, alpha)).or_insert(0u64) += 1;
+    }
+
+    // HashMap iteration order changes between runs, and the order of colors that tie on the split
+    // channel decides which box they land in
+    let mut colors = histogram.into_iter().collect::<Vec<_>>();
+    colors.sort_unstable();
+    let mut boxes = vec![colors];
+    while boxes.len() < max_colors {
+        let widest = boxes.iter()
+            .enumerate()
+            .filter(|(_, colors)| colors.len() > 1)
+            .flat_map(|(i, colors)| (0..channel_count).map(move |c| {
+                let min = colors.iter().map(|(color, _)| color[c]).min().unwrap();
+                let max = colors.iter().map(|(color, _)| color[c]).max().unwrap();
+                (i, c, max - min)
+            }))
+            .max_by_key(|(_, _, range)| *range);
+        let (i, channel) = match widest {
+            Some((i, channel, _)) => (i, channel),
+            None => break,
+        };
+
+        let mut colors = boxes.swap_remove(i);
+        colors.sort_by_key(|(color, _)| color[channel]);
+        let total: u64 = colors.iter().map(|(_, count)| count).sum();
+        let mut seen = 0;
+        let mut split = colors.len() - 1;
+        for (j, (_, count)) in colors.iter().enumerate() {
+            seen += count;
+            if seen * 2 >= total {
+                split = (j + 1).clamp(1, colors.len() - 1);
+                break;
+            }
+        }
+        let upper = colors.split_off(split);
+        boxes.push(colors);
+        boxes.push(upper);
+    }
+
+    boxes.iter()
+        .map(|colors| {
+            let total: u64 = colors.iter().map(|(_, count)| count).sum();
+            (0..channel_count)
+                .map(|c| {
+                    let sum: u64 = colors.iter().map(|(color, count)| color[c] as u64 * count).sum();
+                    ((sum + total / 2) / total) as u8
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Index of the palette color closest to `wanted`
+fn nearest_palette_index(palette: &[Vec<u8>], wanted: &[u8; 4]) -> usize {
+    palette.iter()
+        .map(|color| -> u32 {
+            color.iter().zip(wanted.iter()).map(|(v, w)| (*v as i32 - *w as i32).pow(2) as u32).sum()
+        })
+        .enumerate()
+        .min_by_key(|(_, distance)| *distance)
+        .unwrap()
+        .0
+}
+
+// Replaces every pixel with the closest palette color, optionally spreading the difference to
+// its neighbours (Floyd-Steinberg). Alpha is never dithered so cutout edges stay sharp.
+// Lookups are cached by the (rounded) wanted color, since most images reuse the same handful of
+// colors many times over.
+fn remap_to_palette(
+    pixels: &mut [u8],
+    width: usize,
+    channel_count: usize,
+    alpha: Option<usize>,
+    palette: &[Vec<u8>],
+    dither: bool,
+)
+{
+    let height = pixels.len() / channel_count / width;
+    let mut error = vec![0f32; pixels.len()];
+    let mut nearest_cache = HashMap::new();
+    for y in 0..height {
+        for x in 0..width {
+            let start = (y * width + x) * channel_count;
+            let transparent = alpha.map(|a| pixels[start + a] == 0).unwrap_or(false);
+
+            let mut wanted = [0f32; 4];
+            if !transparent {
+                for c in 0..channel_count {
+                    wanted[c] = (pixels[start + c] as f32 + error[start + c]).clamp(0.0, 255.0);
+                }
+            }
+
+            let mut key = [0u8; 4];
+            for c in 0..channel_count {
+                key[c] = wanted[c].round() as u8;
+            }
+            let nearest = &palette[*nearest_cache.entry(key)
+                .or_insert_with(|| nearest_palette_index(palette, &key))];
+
+            for c in 0..channel_count {
+                if dither && !transparent && Some(c) != alpha {
+                    let err = wanted[c] - nearest[c] as f32;
+                    let mut spread = |dx: isize, dy: usize, weight: f32| {
+                        let nx = x as isize + dx;
+                        if nx >= 0 && (nx as usize) < width && y + dy < height {
+                            error[((y + dy) * width + nx as usize) * channel_count + c] += err * weight;
+                        }
+                    };
+                    spread(1, 0, 7.0 / 16.0);
+                    spread(-1, 1, 3.0 / 16.0);
+                    spread(0, 1, 5.0 / 16.0);
+                    spread(1, 1, 1.0 / 16.0);
+                }
+                pixels[start + c] = nearest[c];
+            }
+        }
+    }
+}
+
 trait TxtrFormatExt {
     fn color_type(&self) -> ColorType;
     fn bytes_per_block(&self) -> usize;
     fn block_dimensions(&self) -> (usize, usize);
     fn flipped(&self) -> bool;
-    fn compute_palette(&mut self, pixels: &[u8]) -> Result<(), ()>;
+    fn compute_palette(&mut self, pixels: &mut [u8], width: usize, dither: bool);
     fn decode_block(&self, block: &[u8], pixels: &mut[u8]);
     fn encode_block(&self, block: &mut [u8], pixels: &[u8]);
     fn from_str(s: &str) -> Result<TxtrFormat, ()>;
@@ -339,28 +671,53 @@ impl TxtrFormatExt for TxtrFormat {
     }
 
 
-    fn compute_palette(&mut self, pixels: &[u8]) -> Result<(), ()> {
+    // If the image has more colors than fit in the palette, the palette is built by quantizing
+    // the image and `pixels` is rewritten to only use the palette's colors
+    fn compute_palette(&mut self, pixels: &mut [u8], width: usize, dither: bool) {
         let (fmt, buf) = match self {
             TxtrFormat::C4(fmt, buf) => (fmt, &mut buf[..]),
             TxtrFormat::C8(fmt, buf) => (fmt, &mut buf[..]),
-            _ => return Ok(()),
+            _ => return,
         };
-        let mut palette_values = HashMap::with_capacity(buf.len() / 2);
-        for chunk in pixels.chunks(fmt.color_type().channel_count() as usize) {
-            let encoded = match fmt {
-                TxtrPaletteFormat::Ia8 => chunk.try_into().unwrap(),
-                TxtrPaletteFormat::Rgb565 => encode_rgb565(chunk.try_into().unwrap()),
-                TxtrPaletteFormat::Rgb5A3 => encode_rgb5a3(chunk.try_into().unwrap()),
-            };
+        let channel_count = fmt.color_type().channel_count() as usize;
+        let max_colors = buf.len() / 2;
+
+        let mut palette_values = HashMap::with_capacity(max_colors);
+        for chunk in pixels.chunks(channel_count) {
             let pv_len = palette_values.len();
-            let idx = *palette_values.entry(encoded)
+            palette_values.entry(encode_palette_entry(fmt, chunk))
                 .or_insert(pv_len);
-            if idx * 2 + 2 >= buf.len() {
-                Err(())?;
+            if palette_values.len() > max_colors {
+                break;
             }
+        }
+        if palette_values.len() <= max_colors {
+            for (encoded, idx) in palette_values {
+                buf[idx * 2..idx * 2 + 2].copy_from_slice(&encoded[..]);
+            }
+            return;
+        }
+
+        let alpha = fmt.alpha_channel();
+        let palette = median_cut(pixels, channel_count, alpha, max_colors)
+            .into_iter()
+            .map(|mut color| {
+                // RGB5A3's 3-bit alpha of 7 decodes as opaque, so snap it to the opaque encoding
+                // to keep palette entries distinct after a decode/encode round trip
+                if matches!(fmt, TxtrPaletteFormat::Rgb5A3) && color[3] >= 0xe0 {
+                    color[3] = 0xff;
+                }
+                let encoded = encode_palette_entry(fmt, &color);
+                decode_palette_entry(fmt, encoded, &mut color);
+                (encoded, color)
+            })
+            .collect::<Vec<_>>();
+
+        for (idx, (encoded, _)) in palette.iter().enumerate() {
             buf[idx * 2..idx * 2 + 2].copy_from_slice(&encoded[..]);
         }
-        Ok(())
+        let colors = palette.into_iter().map(|(_, color)| color).collect::<Vec<_>>();
+        remap_to_palette(pixels, width, channel_count, alpha, &colors, dither);
     }
 
     fn decode_block(&self, block: &[u8], pixels: &mut[u8]) {
@@ -567,6 +924,7 @@ impl TxtrFormatExt for TxtrFormat {
 
 trait TxtrPaletteFormatExt {
     fn color_type(&self) -> ColorType;
+    fn alpha_channel(&self) -> Option<usize>;
 }
 
 impl TxtrPaletteFormatExt for TxtrPaletteFormat {
@@ -577,6 +935,14 @@ impl TxtrPaletteFormatExt for TxtrPaletteFormat {
             TxtrPaletteFormat::Rgb5A3 => ColorType::Rgba8,
         }
     }
+
+    fn alpha_channel(&self) -> Option<usize> {
+        match self {
+            TxtrPaletteFormat::Ia8 => Some(1),
+            TxtrPaletteFormat::Rgb565 => None,
+            TxtrPaletteFormat::Rgb5A3 => Some(3),
+        }
+    }
 }
 
 
@@ -620,6 +986,19 @@ fn main() {
                 "Number of mipmaps to generate. Defaults to the maximum number for the \
                  image & format."
             )
+            (@arg dither: -d --dither
+                "Dither the image when it has more colors than a C4/C8 palette can hold."
+            )
+        )
+        (@subcommand batch =>
+            (about: "Converts every PNG listed in a JSON manifest to a TXTR.")
+            (@arg manifest: -i --manifest +takes_value +required
+                "Manifest listing the PNGs with their format, mipmap count and resource id."
+            )
+            (@arg output_dir: -o --output_dir +takes_value
+                "Directory to write the TXTRs and meta.json to. Defaults to the manifest's \
+                 directory."
+            )
         )
     );
     let matches = app.get_matches();
@@ -635,6 +1014,11 @@ fn main() {
             matches.value_of("output").unwrap().as_ref(),
             matches.value_of("mipmap_count").map(|s| s.parse().unwrap()),
             TxtrFormat::from_str(matches.value_of("format").unwrap()).unwrap(),
+            matches.is_present("dither"),
+        ),
+        ("batch", Some(matches)) => batch(
+            matches.value_of("manifest").unwrap().as_ref(),
+            matches.value_of("output_dir").map(Path::new),
         ),
         _ => return,
     };
@@ -642,3 +1026,95 @@ fn main() {
         eprintln!("{} {}", clap::Format::Error("error:"), s);
     }
 }
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    // Deterministic noise so the quantizer always has more colors than fit in a palette
+    fn noise_pixels(count: usize, channel_count: usize) -> Vec<u8>
+    {
+        let mut state = 0x12345678u32;
+        (0..count * channel_count)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    fn distinct_colors(pixels: &[u8], channel_count: usize) -> HashSet<Vec<u8>>
+    {
+        pixels.chunks(channel_count).map(|pixel| pixel.to_vec()).collect()
+    }
+
+    #[test]
+    fn test_palette_size_limits()
+    {
+        let pixels = noise_pixels(64 * 64, 4);
+        for (mut format, max_colors) in [
+            (TxtrFormat::C4(TxtrPaletteFormat::Rgb5A3, Default::default()), 16),
+            (TxtrFormat::C8(TxtrPaletteFormat::Rgb5A3, Default::default()), 256),
+        ] {
+            let mut quantized = pixels.clone();
+            format.compute_palette(&mut quantized, 64, true);
+            assert!(distinct_colors(&quantized, 4).len() <= max_colors);
+            assert!(median_cut(&pixels, 4, Some(3), max_colors).len() <= max_colors);
+        }
+    }
+
+    #[test]
+    fn test_few_colors_are_kept_exactly()
+    {
+        let colors: Vec<[u8; 3]> = (0..16).map(|i| [i * 16, 255 - i * 8, i * 3]).collect();
+        let pixels: Vec<u8> = (0..32 * 32).flat_map(|i| colors[(i * 7) % colors.len()]).collect();
+
+        let palette = median_cut(&pixels, 3, None, 16);
+        assert_eq!(
+            palette.into_iter().collect::<HashSet<_>>(),
+            colors.iter().map(|color| color.to_vec()).collect::<HashSet<_>>(),
+        );
+
+        let mut remapped = pixels.clone();
+        let palette = colors.iter().map(|color| color.to_vec()).collect::<Vec<_>>();
+        remap_to_palette(&mut remapped, 32, 3, None, &palette, true);
+        assert_eq!(remapped, pixels);
+    }
+
+    #[test]
+    fn test_palette_keeps_alpha()
+    {
+        for (fmt, channel_count) in [(TxtrPaletteFormat::Rgb5A3, 4), (TxtrPaletteFormat::Ia8, 2)] {
+            let mut pixels = noise_pixels(32 * 32, channel_count);
+            for (i, pixel) in pixels.chunks_mut(channel_count).enumerate() {
+                pixel[channel_count - 1] = if i % 3 == 0 { 0 } else { 0xff };
+            }
+            let mut quantized = pixels.clone();
+            let mut format = TxtrFormat::C4(fmt, Default::default());
+            format.compute_palette(&mut quantized, 32, true);
+            for (before, after) in pixels.chunks(channel_count).zip(quantized.chunks(channel_count)) {
+                assert_eq!(before[channel_count - 1], after[channel_count - 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dithering_is_deterministic()
+    {
+        let pixels = noise_pixels(48 * 48, 3);
+        let quantize = || {
+            let mut quantized = pixels.clone();
+            let mut format = TxtrFormat::C8(TxtrPaletteFormat::Rgb565, Default::default());
+            format.compute_palette(&mut quantized, 48, true);
+            (quantized, format)
+        };
+        let (first, first_format) = quantize();
+        let (second, second_format) = quantize();
+        assert_eq!(first, second);
+        match (first_format, second_format) {
+            (TxtrFormat::C8(_, a), TxtrFormat::C8(_, b)) => assert_eq!(a, b),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -17,10 +17,11 @@ use std::{
 
 use serde::{Serialize};
 
+/// Messages passed to the progress callback, serialized as JSON
 #[derive(Serialize)]
 #[serde(tag = "type")]
 #[serde(rename_all = "camelCase")]
-enum CbMessage<'a>
+pub enum CbMessage<'a>
 {
     Success,
     Error {
@@ -34,23 +35,24 @@ enum CbMessage<'a>
 
 impl<'a> CbMessage<'a>
 {
-    fn success_json() -> CString
+    pub fn error(msg: &'a str) -> CbMessage<'a>
     {
-        CString::new(serde_json::to_string(&CbMessage::Success).unwrap()).unwrap()
+        CbMessage::Error { msg: CbMessage::fix_msg(msg) }
     }
 
-    fn error_json(msg: &str) -> CString
+    pub fn progress(percent: f64, msg: &'a str) -> CbMessage<'a>
     {
-        let msg = CbMessage::fix_msg(msg);
-        let cbmsg = CbMessage::Error { msg };
-        CString::new(serde_json::to_string(&cbmsg).unwrap()).unwrap()
+        CbMessage::Progress { percent, msg: CbMessage::fix_msg(msg) }
     }
 
-    fn progress_json(percent: f64, msg: &str) -> CString
+    pub fn to_json(&self) -> String
     {
-        let msg = CbMessage::fix_msg(msg);
-        let cbmsg = CbMessage::Progress { percent, msg };
-        CString::new(serde_json::to_string(&cbmsg).unwrap()).unwrap()
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn to_cstring(&self) -> CString
+    {
+        CString::new(self.to_json()).unwrap()
     }
 
     /// Remove all of the bytes after the first null byte
@@ -65,28 +67,30 @@ impl<'a> CbMessage<'a>
 }
 
 
-struct ProgressNotifier
+/// Reports the patcher's progress as `CbMessage::Progress` messages
+pub struct ProgressNotifier<F>
+    where F: FnMut(&CbMessage)
 {
     total_size: usize,
     bytes_so_far: usize,
-    cb_data: *const (),
-    cb: extern fn(*const (), *const c_char)
+    cb: F,
 }
 
-impl ProgressNotifier
+impl<F> ProgressNotifier<F>
+    where F: FnMut(&CbMessage)
 {
-    fn new(cb_data: *const (), cb: extern fn(*const (), *const c_char))
-        -> ProgressNotifier
+    pub fn new(cb: F) -> ProgressNotifier<F>
     {
         ProgressNotifier {
             total_size: 0,
             bytes_so_far: 0,
-            cb, cb_data
+            cb,
         }
     }
 }
 
-impl structs::ProgressNotifier for ProgressNotifier
+impl<F> structs::ProgressNotifier for ProgressNotifier<F>
+    where F: FnMut(&CbMessage)
 {
     fn notify_total_bytes(&mut self, total_size: usize)
     {
@@ -97,22 +101,19 @@ impl structs::ProgressNotifier for ProgressNotifier
     {
         let percent = self.bytes_so_far as f64 / self.total_size as f64 * 100.;
         let msg = format!("Writing file {:?}", file_name);
-        (self.cb)(self.cb_data, CbMessage::progress_json(percent, &msg).as_ptr());
+        (self.cb)(&CbMessage::progress(percent, &msg));
         self.bytes_so_far += file_bytes;
     }
 
     fn notify_writing_header(&mut self)
     {
         let percent = self.bytes_so_far as f64 / self.total_size as f64 * 100.;
-        (self.cb)(self.cb_data, CbMessage::progress_json(percent, "Writing ISO header").as_ptr());
+        (self.cb)(&CbMessage::progress(percent, "Writing ISO header"));
     }
 
     fn notify_flushing_to_disk(&mut self)
     {
-        (self.cb)(
-            self.cb_data,
-            CbMessage::progress_json(100., "Flushing written data to the disk").as_ptr(),
-        );
+        (self.cb)(&CbMessage::progress(100., "Flushing written data to the disk"));
     }
 }
 
@@ -124,7 +125,7 @@ fn inner(config_json: *const c_char, cb_data: *const (), cb: extern fn(*const ()
 
     let patch_config = PatchConfig::from_json(config_json)?;
 
-    let pn = ProgressNotifier::new(cb_data, cb);
+    let pn = ProgressNotifier::new(|msg: &CbMessage| cb(cb_data, msg.to_cstring().as_ptr()));
     patches::patch_iso(patch_config, pn)?;
     Ok(())
 }
@@ -174,7 +175,7 @@ pub extern fn randomprime_patch_iso(config_json: *const c_char , cb_data: *const
         .and_then(|i| i);
 
     match r {
-        Ok(()) => cb(cb_data, CbMessage::success_json().as_ptr()),
-        Err(msg) => cb(cb_data, CbMessage::error_json(&msg).as_ptr()),
+        Ok(()) => cb(cb_data, CbMessage::Success.to_cstring().as_ptr()),
+        Err(msg) => cb(cb_data, CbMessage::error(&msg).to_cstring().as_ptr()),
     };
 }
@@ -71,7 +71,7 @@ impl<W: Write + Seek> Write for CisoWriter<W>
     }
 }
 
-impl<W: Write + Seek + 'static> structs::WriteExt for CisoWriter<W>
+impl<W: Write + Seek> structs::WriteExt for CisoWriter<W>
 {
     fn skip_bytes(&mut self, bytes: u64) -> io::Result<()>
     {
@@ -7,7 +7,7 @@ use reader_writer::{
 use structs::{res_id, ResId, Resource, ResourceKind};
 
 use crate::{
-    patch_config::{Version, PatchConfig, GenericTexture},
+    patch_config::{Version, PatchConfig, GenericTexture, LocalizedString},
     elevators::{World, SpawnRoomData},
     pickup_meta::{self, PickupType, PickupModel},
     door_meta::{DoorType, BlastShieldType},
@@ -511,7 +511,7 @@ fn extern_assets_compile_time<'r>() -> Vec<Resource<'r>>
 pub fn custom_assets<'r>(
     resources: &HashMap<(u32, FourCC),
     structs::Resource<'r>>,
-    starting_memo: Option<&str>,
+    starting_memo: Option<&LocalizedString>,
     pickup_hudmemos: &mut HashMap::<PickupHashKey, ResId<res_id::STRG>>,
     pickup_scans: &mut HashMap<PickupHashKey, (ResId<res_id::SCAN>, ResId<res_id::STRG>)>,
     extra_scans: &mut HashMap<PickupHashKey, (ResId<res_id::SCAN>, ResId<res_id::STRG>)>,
@@ -551,7 +551,7 @@ pub fn custom_assets<'r>(
     /* Mapping of strings and their corresponding scan_id. Use this to avoid
        redundant usage of percious memory card space
     */
-    let mut string_to_scan_strg: HashMap::<String, (ResId<res_id::SCAN>, ResId<res_id::STRG>)> = HashMap::new();
+    let mut string_to_scan_strg: HashMap::<LocalizedString, (ResId<res_id::SCAN>, ResId<res_id::STRG>)> = HashMap::new();
 
     /* Mapping of SCAN id to logbook category for easier SAVW entry creation */
     let mut savw_scan_logbook_category: HashMap::<u32, u32> = HashMap::new();
@@ -572,52 +572,52 @@ pub fn custom_assets<'r>(
         custom_asset_ids::NOTHING_ANCS,
         custom_asset_ids::NOTHING_TXTR,
         ResId::<res_id::TXTR>::new(0xF68DF7F1),
-    ));
+    )?);
     assets.extend_from_slice(&create_randovania_gamecube_cmdl_and_ancs(
         resources,
         custom_asset_ids::RANDOVANIA_GAMECUBE_CMDL,
         custom_asset_ids::RANDOVANIA_GAMECUBE_ANCS,
         custom_asset_ids::RANDOVANIA_GAMECUBE0_TXTR,
         custom_asset_ids::RANDOVANIA_GAMECUBE1_TXTR,
-    ));
+    )?);
     assets.extend_from_slice(&create_suit_icon_cmdl_and_ancs(
         resources,
         custom_asset_ids::PHAZON_SUIT_CMDL,
         custom_asset_ids::PHAZON_SUIT_ANCS,
         custom_asset_ids::PHAZON_SUIT_TXTR1,
         custom_asset_ids::PHAZON_SUIT_TXTR2,
-    ));
+    )?);
     assets.extend_from_slice(&create_visor_cmdl_and_ancs(
         resources,
         custom_asset_ids::THERMAL_CMDL,
         custom_asset_ids::THERMAL_ANCS,
         ResId::<res_id::TXTR>::new(0xFC095F6C),
-    ));
+    )?);
     assets.extend_from_slice(&create_visor_cmdl_and_ancs(
         resources,
         custom_asset_ids::XRAY_CMDL,
         custom_asset_ids::XRAY_ANCS,
         ResId::<res_id::TXTR>::new(0xBE4CD99D),
-    ));
+    )?);
     assets.extend_from_slice(&create_visor_cmdl_and_ancs(
         resources,
         custom_asset_ids::COMBAT_CMDL,
         custom_asset_ids::COMBAT_ANCS,
         ResId::<res_id::TXTR>::new(0x1D588B22),
-    ));
+    )?);
     
-    assets.extend_from_slice(&create_shiny_missile_assets(resources));
-    assets.extend_from_slice(&create_flamethrower_assets(resources));
+    assets.extend_from_slice(&create_shiny_missile_assets(resources)?);
+    assets.extend_from_slice(&create_flamethrower_assets(resources)?);
 
     assets.extend_from_slice(&create_ice_trap_icon_ancs(
         resources,
         ResId::<res_id::CMDL>::new(0xA3108E43), // new_ice_parasite_bound.CMDL
         custom_asset_ids::ICE_TRAP_ANCS,
-    ));
+    )?);
     assets.extend_from_slice(&create_item_scan_strg_pair(
         custom_asset_ids::SHORELINES_POI_SCAN,
         custom_asset_ids::SHORELINES_POI_STRG,
-        "task failed successfully\0".to_string(),
+        "task failed successfully\0".into(),
         config.version,
     ));
     local_savw_scans_to_add[World::PhendranaDrifts as usize].push(custom_asset_ids::SHORELINES_POI_SCAN);
@@ -648,12 +648,12 @@ pub fn custom_assets<'r>(
     ));
     local_savw_scans_to_add[World::TallonOverworld as usize].push(custom_asset_ids::TOURNEY_WINNERS_SCAN);
 
-    if starting_memo.is_some() {
+    if let Some(starting_memo) = starting_memo {
         assets.push(build_resource(
             custom_asset_ids::STARTING_ITEMS_HUDMEMO_STRG,
-            structs::ResourceKind::Strg(structs::Strg::from_strings(vec![
-                format!("&just=center;{}\0", starting_memo.clone().unwrap()),
-            ])),
+            structs::ResourceKind::Strg(build_localized_strg(&[
+                starting_memo.map(|memo| format!("&just=center;{}\0", memo)),
+            ], config.version)),
         ));
     }
 
@@ -719,62 +719,22 @@ pub fn custom_assets<'r>(
                         }
                     };
 
-                    let mut strings: Vec<String> = vec![];
-                    let mut contents = contents.to_string() + "\0";
-                    let mut content_len = contents.len();
-
-                    // "The &push;&main-color=#c300ff;Phazon Suit&pop; can be found in &push;&main-color=#89a1ff;Phazon Mines - Processing Center Access&pop;.",
-                    // TODO: the game will actually crash if we paginate the color wrong
-                    for x in contents.split("&") {
-                        let semicolon_index = x.find(";").unwrap_or(0);
-                        if semicolon_index != 0 {
-                            content_len -= semicolon_index + 2;
-                        }
-                    }
-
-                    let mut category = false;
-                    const PAGINATION_SIZE: usize = 123;
-                    while content_len > PAGINATION_SIZE {
-                        let mut i = PAGINATION_SIZE - 1;
-                        while contents.chars().nth(i).unwrap_or(' ') != ' ' {
-                            i -= 1;
-                        }
-
-                        i += 1;
-
-                        let page = (contents.clone().to_string())[..i].to_string();
-                        strings.push(page + "\0");
-
-                        contents = (contents.clone().to_string())[i..].to_string();
-                        content_len -= i;
-
-                        if !category {
-                            strings.push("\0".to_string()); // logbook category
-                            category = true;
-                        }
-                    }
-
-                    if content_len > 0 {
-                        strings.push(contents.clone() + "\0");
-                    }
-
-                    if !category {
-                        strings.push("\0".to_string()); // logbook category
-                    }
-
                     if custom_scan.logbook_title.is_some() || custom_scan.logbook_category.is_some() {
                         if !custom_scan.logbook_title.is_some() || !custom_scan.logbook_category.is_some() {
                             panic!("Both logbook title and logbook category are required.");
                         }
-                        strings[1] = custom_scan.logbook_title.clone().unwrap() + "\0";
                         savw_scan_logbook_category.insert(scan_id.to_u32(), custom_scan.logbook_category.clone().unwrap());
                     }
 
+                    // Paginate each language separately, as translations rarely share a length
                     assets.extend_from_slice(
-                        &create_item_scan_strg_pair_2(
+                        &create_item_scan_strg_pair_per_language(
                         scan_id,
                         strg_id,
-                        strings,
+                        |lang| paginate_scan_text(
+                            contents.get(lang),
+                            custom_scan.logbook_title.as_ref().map(|title| title.get(lang)),
+                        ),
                         is_red,
                         *custom_scan.logbook_category.as_ref().unwrap_or(&0),
                         config.version,
@@ -787,7 +747,7 @@ pub fn custom_assets<'r>(
                     local_savw_scans_to_add[world as usize].push(scan_id);
 
                     // Cache this scan/strg pair for re-use //
-                    string_to_scan_strg.insert(contents, (scan_id, strg_id));
+                    string_to_scan_strg.insert(contents.map(|text| format!("{}\0", text)), (scan_id, strg_id));
 
                     extra_scans_idx = extra_scans_idx + 1;
                 }
@@ -797,7 +757,7 @@ pub fn custom_assets<'r>(
                 for (_, door) in room.doors.as_ref().unwrap().iter() {
                     if door.destination.is_none() { continue; }
 
-                    let string = door.destination.as_ref().unwrap().room_name.clone() + "\0";
+                    let string: LocalizedString = (door.destination.as_ref().unwrap().room_name.clone() + "\0").into();
 
                     // Check if this string already has a scan_id //
                     if string_to_scan_strg.contains_key(&string.clone()) {
@@ -848,7 +808,7 @@ pub fn custom_assets<'r>(
                         continue;
                     }
 
-                    let string = format!("{}\0", hudmemo_config.text.as_ref().unwrap());
+                    let string = hudmemo_config.text.as_ref().unwrap().map(|text| format!("{}\0", text));
 
                     // todo: subroutine
 
@@ -906,15 +866,9 @@ pub fn custom_assets<'r>(
                     custom_asset_offset = custom_asset_offset + 1;
 
                     // Build resource //
-                    let strg = structs::ResourceKind::Strg(structs::Strg {
-                        string_tables: vec![
-                            structs::StrgStringTable {
-                                lang: b"ENGL".into(),
-                                strings: vec![format!("&just=center;{}\u{0}",
-                                                        hudmemo_text).into()].into(),
-                            },
-                        ].into(),
-                    });
+                    let strg = structs::ResourceKind::Strg(build_localized_strg(&[
+                        hudmemo_text.map(|text| format!("&just=center;{}\u{0}", text)),
+                    ], config.version));
                     let resource = build_resource(strg_id, strg);
                     assets.push(resource);
 
@@ -952,10 +906,10 @@ pub fn custom_assets<'r>(
                         // Build resource //
                         if room_name.trim().to_lowercase() == "research core" // make the research core scan red because it goes on the terminal
                         {
-                            assets.extend_from_slice(&create_item_scan_strg_pair_2(
+                            assets.extend_from_slice(&create_item_scan_strg_pair_per_language(
                                 scan_id,
                                 strg_id,
-                                vec![format!("{}\0", scan_text)],
+                                |lang| vec![format!("{}\0", scan_text.get(lang))],
                                 1,
                                 0,
                                 config.version,
@@ -966,7 +920,7 @@ pub fn custom_assets<'r>(
                             assets.extend_from_slice(&create_item_scan_strg_pair(
                                 scan_id,
                                 strg_id,
-                                format!("{}\0", scan_text),
+                                scan_text.map(|text| format!("{}\0", text)),
                                 config.version,
                             ));
                         }
@@ -977,7 +931,7 @@ pub fn custom_assets<'r>(
                         local_savw_scans_to_add[world as usize].push(scan_id);
 
                         // Cache this scan/strg pair for re-use //
-                        string_to_scan_strg.insert(scan_text.to_string(), (scan_id, strg_id));
+                        string_to_scan_strg.insert(scan_text.clone(), (scan_id, strg_id));
                     }
                 }
 
@@ -1025,14 +979,14 @@ pub fn custom_assets<'r>(
                 resources,
                 gt.txtr(),
                 gt.cmdl(),
-            )
+            )?
         );
     }
 
     // Custom door assets
     for door_type in DoorType::iter() {
         if door_type.shield_cmdl().to_u32() >= 0xDEAF0000 && door_type.shield_cmdl().to_u32() <= custom_asset_ids::EXTRA_IDS_START.to_u32() + 50 { // only if it doesn't exist in-game already
-            assets.push(create_custom_door_cmdl(resources, door_type));
+            assets.push(create_custom_door_cmdl(resources, door_type)?);
 
             if door_type.scan() != ResId::invalid() || door_type.strg() != ResId::invalid() {
                 if door_type.scan() == ResId::invalid() || door_type.strg() == ResId::invalid() {
@@ -1061,7 +1015,7 @@ pub fn custom_assets<'r>(
     // Custom blast shield assets
     for blast_shield in BlastShieldType::iter() {
         if blast_shield.cmdl().to_u32() >= 0xDEAF0000 && blast_shield.cmdl().to_u32() <= custom_asset_ids::EXTRA_IDS_START.to_u32() + 50 { // only if it doesn't exist in-game already
-            assets.push(create_custom_blast_shield_cmdl(blast_shield));
+            assets.push(create_custom_blast_shield_cmdl(blast_shield)?);
 
             if blast_shield.scan() != ResId::invalid() || blast_shield.strg() != ResId::invalid() {
                 if blast_shield.scan() == ResId::invalid() || blast_shield.strg() == ResId::invalid() {
@@ -1122,7 +1076,7 @@ pub fn custom_assets<'r>(
 // assests used. Create a cache of all the resources needed by any pickup, door, etc...
 pub fn collect_game_resources<'r>(
     gc_disc: &structs::GcDisc<'r>,
-    starting_memo: Option<&str>,
+    starting_memo: Option<&LocalizedString>,
     config: &PatchConfig,
 )
     ->
@@ -1242,7 +1196,10 @@ pub fn collect_game_resources<'r>(
         let file_entry = gc_disc.find_file(pak_name).unwrap();
         let pak = match *file_entry.file().unwrap() {
             structs::FstEntryFile::Pak(ref pak) => Cow::Borrowed(pak),
-            structs::FstEntryFile::Unknown(ref reader) => Cow::Owned(reader.clone().read(())),
+            structs::FstEntryFile::Unknown(ref reader) => Cow::Owned(
+                reader.clone().try_read(())
+                    .map_err(|e| format!("Failed to parse {}: {}", pak_name, e))?
+            ),
             _ => panic!(),
         };
 
@@ -1283,14 +1240,15 @@ fn create_custom_block_cmdl<'r>(
     structs::Resource<'r>>,
     txtr_id: ResId::<res_id::TXTR>,
     new_cmdl_id: ResId::<res_id::CMDL>,
-) -> structs::Resource<'r>
+) -> Result<structs::Resource<'r>, String>
 {
     // Find and read the vanilla block cmdl
     let old_cmdl = ResourceData::new(&resources[&resource_info!("27D0663B.CMDL").into()]);
 
     // Create a copy
     let old_cmdl_bytes = old_cmdl.decompress().into_owned();
-    let mut new_cmdl = Reader::new(&old_cmdl_bytes[..]).read::<structs::Cmdl>(());
+    let mut new_cmdl = Reader::new(&old_cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+        .map_err(|e| format!("Failed to parse 27D0663B.CMDL: {}", e))?;
 
     // Modify the new CMDL to use custom textures
     new_cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = txtr_id;
@@ -1303,19 +1261,20 @@ fn create_custom_block_cmdl<'r>(
     new_cmdl_bytes.extend(reader_writer::pad_bytes(32, new_cmdl_bytes.len()).iter());
 
     // Return resource
-    build_resource(
+    Ok(build_resource(
         new_cmdl_id,
         structs::ResourceKind::External(new_cmdl_bytes, b"CMDL".into())
-    )
+    ))
 }
 
 fn create_custom_blast_shield_cmdl<'r>(
     blast_shield_type: BlastShieldType,
-) -> structs::Resource<'r>
+) -> Result<structs::Resource<'r>, String>
 {
     // Create a copy of the bast model
     let old_cmdl = include_bytes!("../extra_assets/EFDFFB8C.CMDL");
-    let mut new_cmdl = Reader::new(&old_cmdl[..]).read::<structs::Cmdl>(());
+    let mut new_cmdl = Reader::new(&old_cmdl[..]).try_read::<structs::Cmdl>(())
+        .map_err(|e| format!("Failed to parse EFDFFB8C.CMDL: {}", e))?;
 
     // Modify the new CMDL to use custom textures
     new_cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = blast_shield_type.glow_border_txtr();
@@ -1332,17 +1291,17 @@ fn create_custom_blast_shield_cmdl<'r>(
     new_cmdl_bytes.extend(reader_writer::pad_bytes(32, new_cmdl_bytes.len()).iter());
 
     // Return resource
-    build_resource(
+    Ok(build_resource(
         blast_shield_type.cmdl(),
         structs::ResourceKind::External(new_cmdl_bytes, b"CMDL".into())
-    )
+    ))
 }
 
 fn create_custom_door_cmdl<'r>(
     resources: &HashMap<(u32, FourCC),
     structs::Resource<'r>>,
     door_type: DoorType,
-) -> structs::Resource<'r>
+) -> Result<structs::Resource<'r>, String>
 {
     let new_cmdl_id: ResId<res_id::CMDL> = door_type.shield_cmdl();
     let new_txtr_id: ResId<res_id::TXTR> = door_type.holorim_txtr();
@@ -1359,7 +1318,8 @@ fn create_custom_door_cmdl<'r>(
 
         // Deserialize the blue door CMDL into a new mutable CMDL
         let blue_door_cmdl_bytes = blue_door_cmdl.decompress().into_owned();
-        let mut new_cmdl = Reader::new(&blue_door_cmdl_bytes[..]).read::<structs::Cmdl>(());
+        let mut new_cmdl = Reader::new(&blue_door_cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse the {:?} door CMDL: {}", door_type, e))?;
 
         // Modify the new CMDL to make it unique
         new_cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = new_txtr_id;
@@ -1379,7 +1339,7 @@ fn create_custom_door_cmdl<'r>(
         )
     };
 
-    new_door_cmdl
+    Ok(new_door_cmdl)
 }
 
 fn create_nothing_icon_cmdl_and_ancs<'r>(
@@ -1388,14 +1348,15 @@ fn create_nothing_icon_cmdl_and_ancs<'r>(
     new_ancs_id: ResId<res_id::ANCS>,
     new_txtr1: ResId<res_id::TXTR>,
     _new_txtr2: ResId<res_id::TXTR>,
-) -> [structs::Resource<'r>; 2]
+) -> Result<[structs::Resource<'r>; 2], String>
 {
     let new_suit_cmdl = {
         let grav_suit_cmdl = ResourceData::new(
             &resources[&resource_info!("Metroid.CMDL").into()]
         );
         let cmdl_bytes = grav_suit_cmdl.decompress().into_owned();
-        let mut cmdl: structs::Cmdl = Reader::new(&cmdl_bytes[..]).read::<structs::Cmdl>(());
+        let mut cmdl: structs::Cmdl = Reader::new(&cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse Metroid.CMDL: {}", e))?;
 
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = new_txtr1;
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[1] = new_txtr1;
@@ -1419,7 +1380,8 @@ fn create_nothing_icon_cmdl_and_ancs<'r>(
             &resources[&resource_info!("Node1_11.ANCS").into()]
         );
         let ancs_bytes = grav_suit_ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse Node1_11.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[0].cmdl = new_cmdl_id;
 
@@ -1431,7 +1393,7 @@ fn create_nothing_icon_cmdl_and_ancs<'r>(
             structs::ResourceKind::External(new_ancs_bytes, b"ANCS".into())
         )
     };
-    [new_suit_cmdl, new_suit_ancs]
+    Ok([new_suit_cmdl, new_suit_ancs])
 }
 
 fn create_randovania_gamecube_cmdl_and_ancs<'r>(
@@ -1440,11 +1402,12 @@ fn create_randovania_gamecube_cmdl_and_ancs<'r>(
     new_ancs_id: ResId<res_id::ANCS>,
     new_txtr_id0: ResId<res_id::TXTR>,
     new_txtr_id1: ResId<res_id::TXTR>,
-) -> [structs::Resource<'r>; 2]
+) -> Result<[structs::Resource<'r>; 2], String>
 {
     let new_cmdl = {
         let old_cmdl = include_bytes!("../extra_assets/randovania_gamecube.CMDL");
-        let mut new_cmdl = Reader::new(&old_cmdl[..]).read::<structs::Cmdl>(());
+        let mut new_cmdl = Reader::new(&old_cmdl[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse randovania_gamecube.CMDL: {}", e))?;
 
         new_cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = new_txtr_id0;
         new_cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[1] = new_txtr_id1;
@@ -1463,7 +1426,8 @@ fn create_randovania_gamecube_cmdl_and_ancs<'r>(
             &resources[&resource_info!("Node1_11.ANCS").into()]
         );
         let ancs_bytes = grav_suit_ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse Node1_11.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[0].cmdl = new_cmdl_id;
 
@@ -1476,7 +1440,7 @@ fn create_randovania_gamecube_cmdl_and_ancs<'r>(
         )
     };
 
-    [new_cmdl, new_ancs]
+    Ok([new_cmdl, new_ancs])
 }
 
 fn create_visor_cmdl_and_ancs<'r>(
@@ -1484,14 +1448,15 @@ fn create_visor_cmdl_and_ancs<'r>(
     new_cmdl_id: ResId<res_id::CMDL>,
     new_ancs_id: ResId<res_id::ANCS>,
     new_txtr: ResId<res_id::TXTR>,
-) -> [structs::Resource<'r>; 2]
+) -> Result<[structs::Resource<'r>; 2], String>
 {
     let new_cmdl = {
         let old_cmdl = ResourceData::new(
             &resources[&resource_info!("Node1_39_1.CMDL").into()]
         );
         let cmdl_bytes = old_cmdl.decompress().into_owned();
-        let mut cmdl = Reader::new(&cmdl_bytes[..]).read::<structs::Cmdl>(());
+        let mut cmdl = Reader::new(&cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse Node1_39_1.CMDL: {}", e))?;
 
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = new_txtr;
 
@@ -1508,7 +1473,8 @@ fn create_visor_cmdl_and_ancs<'r>(
             &resources[&resource_info!("Node1_39_1.ANCS").into()]
         );
         let ancs_bytes = old_ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse Node1_39_1.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[0].cmdl = new_cmdl_id;
 
@@ -1520,7 +1486,7 @@ fn create_visor_cmdl_and_ancs<'r>(
             structs::ResourceKind::External(new_ancs_bytes, b"ANCS".into())
         )
     };
-    [new_cmdl, new_ancs]
+    Ok([new_cmdl, new_ancs])
 }
 
 fn create_suit_icon_cmdl_and_ancs<'r>(
@@ -1529,14 +1495,15 @@ fn create_suit_icon_cmdl_and_ancs<'r>(
     new_ancs_id: ResId<res_id::ANCS>,
     new_txtr1: ResId<res_id::TXTR>,
     new_txtr2: ResId<res_id::TXTR>,
-) -> [structs::Resource<'r>; 2]
+) -> Result<[structs::Resource<'r>; 2], String>
 {
     let new_suit_cmdl = {
         let grav_suit_cmdl = ResourceData::new(
             &resources[&resource_info!("Node1_11.CMDL").into()]
         );
         let cmdl_bytes = grav_suit_cmdl.decompress().into_owned();
-        let mut cmdl = Reader::new(&cmdl_bytes[..]).read::<structs::Cmdl>(());
+        let mut cmdl = Reader::new(&cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse Node1_11.CMDL: {}", e))?;
 
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = new_txtr1;
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[3] = new_txtr2;
@@ -1554,7 +1521,8 @@ fn create_suit_icon_cmdl_and_ancs<'r>(
             &resources[&resource_info!("Node1_11.ANCS").into()]
         );
         let ancs_bytes = grav_suit_ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse Node1_11.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[0].cmdl = new_cmdl_id;
 
@@ -1566,19 +1534,20 @@ fn create_suit_icon_cmdl_and_ancs<'r>(
             structs::ResourceKind::External(new_ancs_bytes, b"ANCS".into())
         )
     };
-    [new_suit_cmdl, new_suit_ancs]
+    Ok([new_suit_cmdl, new_suit_ancs])
 }
 
 fn create_shiny_missile_assets<'r>(
     resources: &HashMap<(u32, FourCC), structs::Resource<'r>>,
-) -> [structs::Resource<'r>; 4]
+) -> Result<[structs::Resource<'r>; 4], String>
 {
     let shiny_missile_cmdl = {
         let shiny_missile_cmdl = ResourceData::new(
             &resources[&resource_info!("Node1_36_0.CMDL").into()]
         );
         let cmdl_bytes = shiny_missile_cmdl.decompress().into_owned();
-        let mut cmdl = Reader::new(&cmdl_bytes[..]).read::<structs::Cmdl>(());
+        let mut cmdl = Reader::new(&cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse Node1_36_0.CMDL: {}", e))?;
 
         cmdl.material_sets.as_mut_vec()[0].texture_ids = vec![
             custom_asset_ids::SHINY_MISSILE_TXTR0,
@@ -1599,7 +1568,8 @@ fn create_shiny_missile_assets<'r>(
             &resources[&resource_info!("Node1_37_0.ANCS").into()]
         );
         let ancs_bytes = shiny_missile_ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse Node1_37_0.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[0].cmdl = custom_asset_ids::SHINY_MISSILE_CMDL;
         ancs.char_set.char_info.as_mut_vec()[0].particles.part_assets = vec![
@@ -1650,19 +1620,20 @@ fn create_shiny_missile_assets<'r>(
             structs::ResourceKind::External(anim_bytes, b"ANIM".into())
         )
     };
-    [shiny_missile_cmdl, shiny_missile_ancs, shiny_missile_evnt, shiny_missile_anim]
+    Ok([shiny_missile_cmdl, shiny_missile_ancs, shiny_missile_evnt, shiny_missile_anim])
 }
 
 fn create_flamethrower_assets<'r>(
     resources: &HashMap<(u32, FourCC), structs::Resource<'r>>,
-) -> [structs::Resource<'r>; 2]
+) -> Result<[structs::Resource<'r>; 2], String>
 {
     let cmdl = {
         let cmdl = ResourceData::new(
             &resources[&resource_info!("plasma_combo.CMDL").into()]
         );
         let cmdl_bytes = cmdl.decompress().into_owned();
-        let mut cmdl = Reader::new(&cmdl_bytes[..]).read::<structs::Cmdl>(());
+        let mut cmdl = Reader::new(&cmdl_bytes[..]).try_read::<structs::Cmdl>(())
+            .map_err(|e| format!("Failed to parse plasma_combo.CMDL: {}", e))?;
 
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[0] = custom_asset_ids::FLAMETHROWER_PICKUP_TXTR1; // 0x0a515bbb
         cmdl.material_sets.as_mut_vec()[0].texture_ids.as_mut_vec()[1] = custom_asset_ids::FLAMETHROWER_PICKUP_TXTR2; // 0x3494c49b
@@ -1683,7 +1654,8 @@ fn create_flamethrower_assets<'r>(
             &resources[&resource_info!("power_combo.ANCS").into()]
         );
         let ancs_bytes = ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse power_combo.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[1].cmdl = custom_asset_ids::FLAMETHROWER_PICKUP_CMDL;
 
@@ -1696,21 +1668,22 @@ fn create_flamethrower_assets<'r>(
         )
     };
 
-    [cmdl, ancs]
+    Ok([cmdl, ancs])
 }
 
 fn create_ice_trap_icon_ancs<'r>(
     resources: &HashMap<(u32, FourCC), structs::Resource<'r>>,
     new_cmdl_id: ResId<res_id::CMDL>,
     new_ancs_id: ResId<res_id::ANCS>,
-) -> [structs::Resource<'r>; 2]
+) -> Result<[structs::Resource<'r>; 2], String>
 {
     let new_suit_ancs = {
         let grav_suit_ancs = ResourceData::new(
             &resources[&resource_info!("Node1_11.ANCS").into()]
         );
         let ancs_bytes = grav_suit_ancs.decompress().into_owned();
-        let mut ancs = Reader::new(&ancs_bytes[..]).read::<structs::Ancs>(());
+        let mut ancs = Reader::new(&ancs_bytes[..]).try_read::<structs::Ancs>(())
+            .map_err(|e| format!("Failed to parse Node1_11.ANCS: {}", e))?;
 
         ancs.char_set.char_info.as_mut_vec()[0].cmdl = new_cmdl_id;
 
@@ -1722,17 +1695,17 @@ fn create_ice_trap_icon_ancs<'r>(
             structs::ResourceKind::External(new_ancs_bytes, b"ANCS".into())
         )
     };
-    [resources[&resource_info!("new_ice_parasite_bound.CMDL").into()].clone(), new_suit_ancs]
+    Ok([resources[&resource_info!("new_ice_parasite_bound.CMDL").into()].clone(), new_suit_ancs])
 }
 
 fn create_item_scan_strg_pair<'r>(
     new_scan: ResId<res_id::SCAN>,
     new_strg: ResId<res_id::STRG>,
-    contents: String,
+    contents: LocalizedString,
     version: Version,
 ) -> [structs::Resource<'r>; 2]
 {
-    create_item_scan_strg_pair_2(new_scan, new_strg, vec![contents], 0, 0, version)
+    create_item_scan_strg_pair_per_language(new_scan, new_strg, |lang| vec![contents.get(lang).to_string()], 0, 0, version)
 }
 
 fn create_item_scan_strg_pair_2<'r>(
@@ -1743,6 +1716,19 @@ fn create_item_scan_strg_pair_2<'r>(
     logbook_category: u32,
     version: Version,
 ) -> [structs::Resource<'r>; 2]
+{
+    create_item_scan_strg_pair_per_language(new_scan, new_strg, |_| contents.clone(), is_important, logbook_category, version)
+}
+
+fn create_item_scan_strg_pair_per_language<'r, F>(
+    new_scan: ResId<res_id::SCAN>,
+    new_strg: ResId<res_id::STRG>,
+    contents: F,
+    is_important: u8,
+    logbook_category: u32,
+    version: Version,
+) -> [structs::Resource<'r>; 2]
+    where F: FnMut(&[u8; 4]) -> Vec<String>
 {
     let scan = build_resource(
         new_scan,
@@ -1795,18 +1781,95 @@ fn create_item_scan_strg_pair_2<'r>(
         }),
     );
 
-    let kind = if version == Version::Pal {
-        structs::ResourceKind::Strg(structs::Strg::from_strings_pal(contents))
-    } else if version == Version::NtscJ {
-        structs::ResourceKind::Strg(structs::Strg::from_strings_jpn(contents))
-    } else {
-        structs::ResourceKind::Strg(structs::Strg::from_strings(contents))
-    };
-
     let strg = build_resource(
         new_strg,
-        kind,
+        structs::ResourceKind::Strg(build_strg_per_language(contents, version)),
     );
 
     [scan, strg]
 }
+
+// Builds a STRG with a table for each language shipped by `version`
+pub fn build_strg_per_language<'r, F>(mut strings: F, version: Version) -> structs::Strg<'r>
+    where F: FnMut(&[u8; 4]) -> Vec<String>
+{
+    match version {
+        Version::Pal => structs::Strg::from_strings_per_language(
+            &[b"ENGL", b"FREN", b"GERM", b"SPAN", b"ITAL", b"JAPN"],
+            strings,
+        ),
+        Version::NtscJ => structs::Strg::from_strings_per_language(
+            &[b"ENGL", b"JAPN"],
+            |lang| strings(lang).into_iter()
+                .map(|string| format!("&line-extra-space=4;&font=C29C51F1;{}", string))
+                .collect(),
+        ),
+        _ => structs::Strg::from_strings_per_language(&[b"ENGL"], strings),
+    }
+}
+
+// Text which is the same in every language keeps the single English table that the game falls
+// back to, only localized text gets a table per language.
+pub fn build_localized_strg<'r>(strings: &[LocalizedString], version: Version) -> structs::Strg<'r>
+{
+    if strings.iter().any(|string| string.is_localized()) {
+        build_strg_per_language(
+            |lang| strings.iter().map(|string| string.get(lang).to_string()).collect(),
+            version,
+        )
+    } else {
+        structs::Strg::from_strings(strings.iter().map(|string| string.english().to_string()).collect())
+    }
+}
+
+// "The &push;&main-color=#c300ff;Phazon Suit&pop; can be found in &push;&main-color=#89a1ff;Phazon Mines - Processing Center Access&pop;.",
+// TODO: the game will actually crash if we paginate the color wrong
+fn paginate_scan_text(text: &str, logbook_title: Option<&str>) -> Vec<String>
+{
+    let mut strings: Vec<String> = vec![];
+    let mut contents = text.to_string() + "\0";
+    let mut content_len = contents.len();
+
+    for x in contents.split("&") {
+        let semicolon_index = x.find(";").unwrap_or(0);
+        if semicolon_index != 0 {
+            content_len -= semicolon_index + 2;
+        }
+    }
+
+    let mut category = false;
+    const PAGINATION_SIZE: usize = 123;
+    while content_len > PAGINATION_SIZE {
+        let mut i = PAGINATION_SIZE - 1;
+        while contents.chars().nth(i).unwrap_or(' ') != ' ' {
+            i -= 1;
+        }
+
+        i += 1;
+
+        let page = (contents.clone().to_string())[..i].to_string();
+        strings.push(page + "\0");
+
+        contents = (contents.clone().to_string())[i..].to_string();
+        content_len -= i;
+
+        if !category {
+            strings.push("\0".to_string()); // logbook category
+            category = true;
+        }
+    }
+
+    if content_len > 0 {
+        strings.push(contents.clone() + "\0");
+    }
+
+    if !category {
+        strings.push("\0".to_string()); // logbook category
+    }
+
+    if let Some(logbook_title) = logbook_title {
+        strings[1] = logbook_title.to_string() + "\0";
+    }
+
+    strings
+}
@@ -6,6 +6,7 @@ use std::{
     borrow::Cow,
     io,
     iter,
+    panic::Location,
     vec,
 };
 
@@ -13,7 +14,7 @@ use std::{
 struct BinaryPatcher<'a>
 {
     data: &'a [u8],
-    patches: Vec<(usize, Cow<'a, [u8]>)>
+    patches: Vec<(usize, Cow<'a, [u8]>, String)>
 }
 
 impl<'a> BinaryPatcher<'a>
@@ -26,17 +27,15 @@ impl<'a> BinaryPatcher<'a>
         }
     }
 
-    fn patch(&mut self, start: usize, data: Cow<'a, [u8]>) -> Result<(), String>
+    fn find_overlap(&self, start: usize, len: usize) -> Option<&(usize, Cow<'a, [u8]>, String)>
     {
-        for patch in &self.patches {
-            if (patch.0 < start && patch.0 + patch.1.len() > start) ||
-               (start < patch.0 && start + data.len() > patch.0)
-            {
-                Err("Overlapping patches".to_owned())?
-            }
-        }
-        self.patches.push((start, data));
-        Ok(())
+        self.patches.iter()
+            .find(|patch| start < patch.0 + patch.1.len() && patch.0 < start + len)
+    }
+
+    fn patch(&mut self, start: usize, data: Cow<'a, [u8]>, label: String)
+    {
+        self.patches.push((start, data, label));
     }
 
 
@@ -274,7 +273,15 @@ impl<'a> DolPatcher<'a>
     }
 
 
+    // Patches are labeled with the caller's source location unless a label is given
+    #[track_caller]
     pub fn patch(&mut self, start: u32, data: Cow<'a, [u8]>) -> Result<&mut Self, String>
+    {
+        let label = Location::caller().to_string();
+        self.labeled_patch(&label, start, data)
+    }
+
+    pub fn labeled_patch(&mut self, label: &str, start: u32, data: Cow<'a, [u8]>) -> Result<&mut Self, String>
     {
         let mut matching_seg = None;
         for seg in self.text_segments.iter_mut().chain(&mut self.data_segments) {
@@ -294,14 +301,97 @@ impl<'a> DolPatcher<'a>
             None => Err(format!("Failed to find segment to patch at {:x}", start))?,
         };
 
-        patcher.patch((start - *addr) as usize, data)?;
+        let offset = (start - *addr) as usize;
+        if let Some((other_offset, other_data, other_label)) = patcher.find_overlap(offset, data.len()) {
+            let other_start = *addr + *other_offset as u32;
+            Err(format!(
+                "Patch '{}' at 0x{:x}-0x{:x} overlaps patch '{}' at 0x{:x}-0x{:x}",
+                label, start, start + data.len() as u32,
+                other_label, other_start, other_start + other_data.len() as u32,
+            ))?
+        }
+        patcher.patch(offset, data, label.to_owned());
         Ok(self)
     }
 
+    #[track_caller]
     pub fn ppcasm_patch<A, L>(&mut self, asm: &ppcasm::AsmBlock<A, L>) -> Result<&mut Self, String>
         where A: AsRef<[u32]>
     {
-        self.patch(asm.addr(), asm.encoded_bytes().into())
+        let label = Location::caller().to_string();
+        self.labeled_ppcasm_patch(&label, asm)
+    }
+
+    pub fn labeled_ppcasm_patch<A, L>(&mut self, label: &str, asm: &ppcasm::AsmBlock<A, L>) -> Result<&mut Self, String>
+        where A: AsRef<[u32]>
+    {
+        self.labeled_patch(label, asm.addr(), asm.encoded_bytes().into())
+    }
+
+    // Every patched range, sorted by address: (start, end, label)
+    pub fn patched_ranges(&self) -> Vec<(u32, u32, &str)>
+    {
+        let mut ranges = vec![];
+        for seg in self.text_segments.iter().chain(&self.data_segments) {
+            if let DolSegment::PatchedSegment(addr, patcher) = seg {
+                for (offset, data, label) in &patcher.patches {
+                    let start = addr + *offset as u32;
+                    ranges.push((start, start + data.len() as u32, &label[..]));
+                }
+            }
+        }
+        ranges.sort_by_key(|r| r.0);
+        ranges
+    }
+
+    pub fn patch_report(&self) -> String
+    {
+        let mut report = String::new();
+        for (start, end, label) in self.patched_ranges() {
+            report += &format!("0x{:08x}-0x{:08x} {:>6} bytes  {}\n", start, end, end - start, label);
+        }
+        report
+    }
+
+    // Disassembly of everything that was changed: each patch as a diff against the original
+    // code, then every added text segment in full
+    pub fn patch_listing(&self, symbols: &dyn Fn(u32) -> Option<String>) -> String
+    {
+        let mut patches = vec![];
+        for seg in self.text_segments.iter().chain(&self.data_segments) {
+            if let DolSegment::PatchedSegment(addr, patcher) = seg {
+                for (offset, data, label) in &patcher.patches {
+                    let original = &patcher.data[*offset..*offset + data.len()];
+                    patches.push((addr + *offset as u32, original, &data[..], label));
+                }
+            }
+        }
+        patches.sort_by_key(|p| p.0);
+
+        let mut listing = String::new();
+        for (addr, original, data, label) in patches {
+            let name = symbols(addr).map(|name| format!(" <{}>", name)).unwrap_or_default();
+            listing += &format!("; patch at 0x{:08x}{} ({} bytes) from {}\n", addr, name, data.len(), label);
+            for line in ppcasm::disassemble(addr, original, symbols) {
+                listing += &format!("- {}\n", line);
+            }
+            for line in ppcasm::disassemble(addr, data, symbols) {
+                listing += &format!("+ {}\n", line);
+            }
+            listing += "\n";
+        }
+
+        for seg in self.text_segments.iter() {
+            if let DolSegment::NewSegment(addr, bytes) = seg {
+                listing += &format!("; new text segment at 0x{:08x} ({} bytes)\n", addr, bytes.len());
+                for line in ppcasm::disassemble(*addr, bytes, symbols) {
+                    listing += &format!("+ {}\n", line);
+                }
+                listing += "\n";
+            }
+        }
+
+        listing
     }
 }
 
@@ -411,3 +501,58 @@ impl<I> io::Read for ReadIteratorChain<I>
         Ok(total_bytes_written)
     }
 }
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    const TEXT_ADDR: u32 = 0x80003000;
+
+    // A DOL with a single, zeroed, 0x100 byte text segment
+    fn dol_bytes() -> Vec<u8>
+    {
+        let mut bytes = vec![0; 0x200];
+        bytes[0x00..0x04].copy_from_slice(&0x100u32.to_be_bytes());
+        bytes[0x48..0x4C].copy_from_slice(&TEXT_ADDR.to_be_bytes());
+        bytes[0x90..0x94].copy_from_slice(&0x100u32.to_be_bytes());
+        bytes[0xE0..0xE4].copy_from_slice(&TEXT_ADDR.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_find_overlap()
+    {
+        let data = [0; 0x40];
+        let mut patcher = BinaryPatcher::new(&data);
+        patcher.patch(0x10, vec![0; 8].into(), "first".to_owned());
+
+        // Same start
+        assert_eq!(patcher.find_overlap(0x10, 4).unwrap().2, "first");
+        // Partially overlapping either end
+        assert_eq!(patcher.find_overlap(0x0C, 8).unwrap().2, "first");
+        assert_eq!(patcher.find_overlap(0x14, 8).unwrap().2, "first");
+        // Adjacent on either side
+        assert!(patcher.find_overlap(0x08, 8).is_none());
+        assert!(patcher.find_overlap(0x18, 8).is_none());
+    }
+
+    #[test]
+    fn test_labeled_patch_overlap()
+    {
+        let bytes = dol_bytes();
+        let mut patcher = DolPatcher::new(Reader::new(&bytes));
+        patcher.labeled_patch("first", TEXT_ADDR + 0x10, vec![1; 8].into()).unwrap();
+        patcher.labeled_patch("adjacent", TEXT_ADDR + 0x18, vec![2; 4].into()).unwrap();
+
+        let err = patcher.labeled_patch("second", TEXT_ADDR + 0x14, vec![3; 8].into()).err().unwrap();
+        assert_eq!(
+            err,
+            "Patch 'second' at 0x80003014-0x8000301c overlaps patch 'first' at 0x80003010-0x80003018",
+        );
+        assert_eq!(patcher.patched_ranges(), vec![
+            (TEXT_ADDR + 0x10, TEXT_ADDR + 0x18, "first"),
+            (TEXT_ADDR + 0x18, TEXT_ADDR + 0x1C, "adjacent"),
+        ]);
+    }
+}
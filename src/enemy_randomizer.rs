@@ -0,0 +1,444 @@
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, HashMap},
+    convert::TryInto,
+};
+
+use rand::{
+    rngs::StdRng,
+    seq::SliceRandom,
+    SeedableRng,
+};
+use reader_writer::{FourCC, Writable};
+use structs::{Dependency, SclyPropertyData};
+
+use crate::{
+    elevators::World,
+    mlvl_wrapper,
+    patch_config::EnemyRandomizerConfig,
+    patcher::PatcherState,
+    pickup_meta,
+    GcDiscLookupExtensions,
+    ResourceData,
+};
+
+/*
+    Replaces enemies with other enemy types that move the same way. Every replacement is built
+    from a "donor" instance of the new type found elsewhere on the disc, so only enemy types
+    whose property layout is known to `structs` are eligible.
+
+    The donor's dependencies are taken from its own area's dependency list: any asset id which
+    appears in the donor's property data, or (transitively) inside one of the assets it
+    references, is copied over.
+
+    Bosses have their own object types and are never eligible. Enemies which take part in a
+    scripted sequence are excluded, either by room below or because they send messages to an
+    object which drives the room's scripting.
+*/
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Movement
+{
+    Ground,
+    Air,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EnemyType
+{
+    SpacePirate,
+    FlyingPirate,
+    WarWasp,
+    Beetle,
+    Puffer,
+    Metroid,
+    Babygoth,
+    Seedling,
+    FlickerBat,
+}
+
+const ENEMY_TYPES: &[EnemyType] = &[
+    EnemyType::SpacePirate,
+    EnemyType::FlyingPirate,
+    EnemyType::WarWasp,
+    EnemyType::Beetle,
+    EnemyType::Puffer,
+    EnemyType::Metroid,
+    EnemyType::Babygoth,
+    EnemyType::Seedling,
+    EnemyType::FlickerBat,
+];
+
+impl EnemyType
+{
+    fn object_type(&self) -> u8
+    {
+        match self {
+            EnemyType::SpacePirate  => structs::SpacePirate::OBJECT_TYPE,
+            EnemyType::FlyingPirate => structs::FlyingPirate::OBJECT_TYPE,
+            EnemyType::WarWasp      => structs::WarWasp::OBJECT_TYPE,
+            EnemyType::Beetle       => structs::Beetle::OBJECT_TYPE,
+            EnemyType::Puffer       => structs::Puffer::OBJECT_TYPE,
+            EnemyType::Metroid      => structs::Metroid::OBJECT_TYPE,
+            EnemyType::Babygoth     => structs::Babygoth::OBJECT_TYPE,
+            EnemyType::Seedling     => structs::Seedling::OBJECT_TYPE,
+            EnemyType::FlickerBat   => structs::FlickerBat::OBJECT_TYPE,
+        }
+    }
+
+    fn movement(&self) -> Movement
+    {
+        match self {
+            EnemyType::SpacePirate  => Movement::Ground,
+            EnemyType::FlyingPirate => Movement::Air,
+            EnemyType::WarWasp      => Movement::Air,
+            EnemyType::Beetle       => Movement::Ground,
+            EnemyType::Puffer       => Movement::Air,
+            EnemyType::Metroid      => Movement::Air,
+            EnemyType::Babygoth     => Movement::Ground,
+            EnemyType::Seedling     => Movement::Ground,
+            EnemyType::FlickerBat   => Movement::Air,
+        }
+    }
+
+    fn from_object_type(object_type: u8) -> Option<Self>
+    {
+        ENEMY_TYPES.iter().find(|x| x.object_type() == object_type).cloned()
+    }
+}
+
+// Rooms whose enemies are spawned, animated or counted by a scripted sequence
+const EXCLUDED_ROOMS: &[(World, &str)] = &[
+    (World::ChozoRuins, "Hive Totem"),
+    (World::PhendranaDrifts, "Control Tower"),
+    (World::PhendranaDrifts, "Research Lab Hydra"),
+    (World::PhendranaDrifts, "Research Lab Aether"),
+    (World::PhazonMines, "Metroid Quarantine B"),
+];
+
+// Enemies connected to one of these usually unlock the room once they're dead (by counting down or
+// relaying to the doors), so replacing them risks soft-locking the player
+const SCRIPT_CRITICAL_TARGETS: &[u8] = &[
+    structs::Relay::OBJECT_TYPE,
+    structs::Counter::OBJECT_TYPE,
+    structs::Door::OBJECT_TYPE,
+];
+
+// Assets which can reference other assets by id
+const REFERENCING_TYPES: &[&[u8; 4]] = &[
+    b"ANCS", b"CMDL", b"EVNT", b"SCAN", b"PART", b"ELSC", b"SWHC", b"WPSC", b"CRSC", b"DPSC",
+];
+
+struct Donor<'r>
+{
+    property_data: structs::SclyProperty<'r>,
+    dependencies: Vec<Dependency>,
+}
+
+pub struct EnemyRandomizer<'r>
+{
+    donors: HashMap<EnemyType, Donor<'r>>,
+    swaps: HashMap<(&'static str, u32), HashMap<u32, EnemyType>>, // (pak, mrea) -> instance id -> new type
+    resources: HashMap<(u32, FourCC), structs::Resource<'r>>,
+}
+
+impl<'r> EnemyRandomizer<'r>
+{
+    pub fn rooms(&self) -> impl Iterator<Item=&(&'static str, u32)>
+    {
+        self.swaps.keys()
+    }
+
+    // The type an enemy is going to be replaced with, if any
+    pub fn enemy_type(&self, pak_name: &'static str, mrea_id: u32, instance_id: u32) -> Option<EnemyType>
+    {
+        self.swaps.get(&(pak_name, mrea_id))
+            .and_then(|swaps| swaps.get(&instance_id))
+            .cloned()
+    }
+
+    // Every resource needed by a replacement enemy, to be merged into the patcher's resource pool
+    pub fn take_resources(&mut self) -> HashMap<(u32, FourCC), structs::Resource<'r>>
+    {
+        std::mem::take(&mut self.resources)
+    }
+}
+
+struct Candidate
+{
+    pak_name: &'static str,
+    mrea_id: u32,
+    instance_id: u32,
+    enemy_type: EnemyType,
+}
+
+fn is_excluded_room(config: &EnemyRandomizerConfig, world: World, room_name: &str) -> bool
+{
+    if EXCLUDED_ROOMS.iter().any(|(w, name)| *w == world && *name == room_name) {
+        return true;
+    }
+
+    let key = format!("{}:{}", world.to_json_key(), room_name);
+    config.exclude_rooms.as_ref()
+        .map(|rooms| rooms.iter().any(|room| room.trim().eq_ignore_ascii_case(&key)))
+        .unwrap_or(false)
+}
+
+// Collects every dependency of the area reachable from `property_data`, in the order the
+// area loads them
+fn donor_dependencies<'r>(
+    pak: &structs::Pak<'r>,
+    area_deps: &[Dependency],
+    property_data: &structs::SclyProperty<'r>,
+    resources: &mut HashMap<(u32, FourCC), structs::Resource<'r>>,
+)
+    -> Result<Vec<Dependency>, String>
+{
+    let area_deps: HashMap<u32, (usize, FourCC)> = area_deps.iter()
+        .enumerate()
+        .map(|(i, dep)| (dep.asset_id, (i, dep.asset_type)))
+        .collect();
+
+    let mut bytes = vec![];
+    property_data.write_to(&mut bytes).unwrap();
+
+    let mut found = HashMap::<u32, (usize, FourCC)>::new();
+    let mut to_scan = vec![bytes];
+    while let Some(bytes) = to_scan.pop() {
+        for window in bytes.windows(4) {
+            let asset_id = u32::from_be_bytes(window.try_into().unwrap());
+            let (order, fourcc) = match area_deps.get(&asset_id) {
+                Some(dep) => *dep,
+                None => continue,
+            };
+            if found.insert(asset_id, (order, fourcc)).is_some() {
+                continue;
+            }
+
+            let res = pak.resources.iter()
+                .find(|res| res.file_id == asset_id && res.fourcc() == fourcc)
+                .ok_or_else(|| format!("Failed to find dependency 0x{:08X} ({:?}) in pak", asset_id, fourcc))?;
+            if REFERENCING_TYPES.iter().any(|x| FourCC::from_bytes(x) == fourcc) {
+                to_scan.push(ResourceData::new(&res).decompress().into_owned());
+            }
+            resources.insert((asset_id, fourcc), res.into_owned());
+        }
+    }
+
+    let mut deps: Vec<_> = found.into_iter().collect();
+    deps.sort_by_key(|(_, (order, _))| *order);
+
+    Ok(deps.into_iter()
+        .map(|(asset_id, (_, asset_type))| Dependency { asset_id, asset_type })
+        .collect())
+}
+
+pub fn plan_enemy_randomizer<'r>(
+    gc_disc: &structs::GcDisc<'r>,
+    config: &EnemyRandomizerConfig,
+    seed: u64,
+)
+    -> Result<EnemyRandomizer<'r>, String>
+{
+    let worlds: Vec<_> = pickup_meta::ROOM_INFO.iter()
+        .map(|(pak_name, rooms)| {
+            let world = World::from_pak(pak_name).unwrap();
+            let rooms = rooms.iter()
+                .map(|room_info| (room_info.room_id.to_u32(), room_info.name().trim()))
+                .collect();
+            (*pak_name, world, rooms)
+        })
+        .filter(|(_, world, _)| *world != World::ImpactCrater && *world != World::EndCinema)
+        .collect();
+    plan_enemy_randomizer_for_rooms(gc_disc, config, seed, &worlds)
+}
+
+// A PAK and the (MREA id, name) of every room in it to consider
+pub type WorldRooms<'a> = (&'static str, World, Vec<(u32, &'a str)>);
+
+pub fn plan_enemy_randomizer_for_rooms<'r>(
+    gc_disc: &structs::GcDisc<'r>,
+    config: &EnemyRandomizerConfig,
+    seed: u64,
+    worlds: &[WorldRooms],
+)
+    -> Result<EnemyRandomizer<'r>, String>
+{
+    let excluded_objects = config.exclude_objects.clone().unwrap_or_default();
+
+    let mut donors = HashMap::new();
+    let mut resources = HashMap::new();
+    let mut candidates = Vec::new();
+
+    for (pak_name, world, rooms) in worlds.iter() {
+        let (pak_name, world) = (*pak_name, *world);
+        let file_entry = gc_disc.find_file(pak_name)
+            .ok_or_else(|| format!("Failed to find {}", pak_name))?;
+        let pak = match *file_entry.file().unwrap() {
+            structs::FstEntryFile::Pak(ref pak) => Cow::Borrowed(pak),
+            structs::FstEntryFile::Unknown(ref reader) => Cow::Owned(
+                reader.clone().try_read(())
+                    .map_err(|e| format!("Failed to parse {}: {}", pak_name, e))?
+            ),
+            _ => return Err(format!("{} is not a pak", pak_name)),
+        };
+
+        let mlvl_res = pak.resources.iter()
+            .find(|res| res.file_id == world.mlvl() && res.fourcc() == b"MLVL".into())
+            .ok_or_else(|| format!("Failed to find MLVL 0x{:08X} in {}", world.mlvl(), pak_name))?;
+        let mlvl = mlvl_res.kind.try_as_mlvl()
+            .map_err(|e| format!("Failed to parse MLVL 0x{:08X} in {}: {}", world.mlvl(), pak_name, e))?
+            .unwrap();
+
+        for (mrea_id, room_name) in rooms.iter() {
+            let mrea_id = *mrea_id;
+            if is_excluded_room(config, world, room_name) {
+                continue;
+            }
+
+            let mrea_res = pak.resources.iter()
+                .find(|res| res.file_id == mrea_id && res.fourcc() == b"MREA".into())
+                .ok_or_else(|| format!("Failed to find MREA 0x{:08X} in {}", mrea_id, pak_name))?;
+            let mrea = mrea_res.kind.try_as_mrea()
+                .map_err(|e| format!("Failed to parse MREA 0x{:08X} in {}: {}", mrea_id, pak_name, e))?
+                .unwrap();
+            let scly = mrea.try_scly_section()
+                .map_err(|e| format!("Failed to parse MREA 0x{:08X} in {}: {}", mrea_id, pak_name, e))?;
+
+            let object_types: HashMap<u32, u8> = scly.layers.iter()
+                .flat_map(|layer| {
+                    layer.objects.iter()
+                        .map(|obj| (obj.instance_id & 0x00FFFFFF, obj.property_data.object_type()))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+
+            for layer in scly.layers.iter() {
+                for obj in layer.objects.iter() {
+                    let enemy_type = match EnemyType::from_object_type(obj.property_data.object_type()) {
+                        Some(enemy_type) => enemy_type,
+                        None => continue,
+                    };
+                    if excluded_objects.iter().any(|id| id & 0x00FFFFFF == obj.instance_id & 0x00FFFFFF) {
+                        continue;
+                    }
+                    let is_script_critical = obj.connections.iter().any(|conn| {
+                        object_types.get(&(conn.target_object_id & 0x00FFFFFF))
+                            .map(|object_type| SCRIPT_CRITICAL_TARGETS.contains(object_type))
+                            .unwrap_or(false)
+                    });
+                    if is_script_critical {
+                        continue;
+                    }
+
+                    if let Entry::Vacant(entry) = donors.entry(enemy_type) {
+                        let area = mlvl.areas.iter()
+                            .find(|area| area.mrea.to_u32() == mrea_id)
+                            .ok_or_else(|| format!("MREA 0x{:08X} is not part of {}", mrea_id, pak_name))?;
+                        let area_deps: Vec<Dependency> = area.dependencies.deps.iter()
+                            .flat_map(|layer| layer.iter().map(|dep| dep.into_owned()).collect::<Vec<_>>())
+                            .collect();
+                        let property_data = obj.property_data.clone();
+                        let dependencies = donor_dependencies(&pak, &area_deps, &property_data, &mut resources)?;
+                        entry.insert(Donor { property_data, dependencies });
+                    }
+
+                    candidates.push(Candidate {
+                        pak_name,
+                        mrea_id,
+                        instance_id: obj.instance_id,
+                        enemy_type,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut rng = StdRng::seed_from_u64(config.seed.unwrap_or(seed));
+    let mut swaps: HashMap<(&'static str, u32), HashMap<u32, EnemyType>> = HashMap::new();
+    for candidate in candidates {
+        let choices: Vec<EnemyType> = ENEMY_TYPES.iter()
+            .filter(|x| x.movement() == candidate.enemy_type.movement() && donors.contains_key(x))
+            .cloned()
+            .collect();
+        let new_type = *choices.choose(&mut rng).unwrap();
+        if new_type == candidate.enemy_type {
+            continue;
+        }
+
+        swaps.entry((candidate.pak_name, candidate.mrea_id))
+            .or_default()
+            .insert(candidate.instance_id, new_type);
+    }
+
+    // Only keep the resources of donors which ended up being used
+    let mut used = HashMap::new();
+    for enemy_type in swaps.values().flat_map(|x| x.values()) {
+        for dep in donors[enemy_type].dependencies.iter() {
+            let key = (dep.asset_id, dep.asset_type);
+            if let Some(res) = resources.remove(&key) {
+                used.insert(key, res);
+            }
+        }
+    }
+
+    Ok(EnemyRandomizer {
+        donors,
+        swaps,
+        resources: used,
+    })
+}
+
+pub fn patch_randomize_enemies<'r>(
+    _ps: &mut PatcherState,
+    area: &mut mlvl_wrapper::MlvlArea<'r, '_, '_, '_>,
+    game_resources: &HashMap<(u32, FourCC), structs::Resource<'r>>,
+    enemy_randomizer: &EnemyRandomizer<'r>,
+    pak_name: &'static str,
+)
+    -> Result<(), String>
+{
+    let mrea_id = area.mlvl_area.mrea.to_u32();
+    let swaps = match enemy_randomizer.swaps.get(&(pak_name, mrea_id)) {
+        Some(swaps) => swaps,
+        None => return Ok(()),
+    };
+
+    let layer_count = area.layer_flags.layer_count as usize;
+    for layer_id in 0..layer_count {
+        let mut deps = Vec::new();
+
+        let scly = area.mrea().scly_section_mut();
+        let layer = &mut scly.layers.as_mut_vec()[layer_id];
+        for obj in layer.objects.as_mut_vec().iter_mut() {
+            let donor = match swaps.get(&obj.instance_id) {
+                Some(enemy_type) => &enemy_randomizer.donors[enemy_type],
+                None => continue,
+            };
+
+            // Keep where the enemy is, its size and whether it waits to be activated by the
+            // room's scripting; the instance id and connections are left untouched
+            let position = obj.property_data.get_position();
+            let rotation = obj.property_data.get_rotation();
+            let scale = obj.property_data.get_scale();
+            let active = obj.property_data.get_patterned_infos()[0].active;
+
+            let mut property_data = donor.property_data.clone();
+            property_data.set_position(position);
+            property_data.set_rotation(rotation);
+            property_data.set_scale(scale);
+            let mut patterned_infos = property_data.get_patterned_infos();
+            for info in patterned_infos.iter_mut() {
+                info.active = active;
+            }
+            property_data.set_patterned_infos(patterned_infos);
+
+            obj.property_data = property_data;
+            deps.extend(donor.dependencies.iter().cloned());
+        }
+
+        area.add_dependencies(game_resources, layer_id, deps.into_iter());
+    }
+
+    Ok(())
+}
@@ -5,10 +5,11 @@ use crate::{
 };
 
 use reader_writer::CStrConversionExtension;
+use structs::SclyPropertyData;
 
 use std::collections::HashMap;
 
-use crate::patch_config::EditObjConfig;
+use crate::patch_config::{EditObjConfig, EnemyStatsConfig};
 
 pub fn patch_edit_objects<'r>
 (
@@ -181,6 +182,89 @@ pub fn patch_edit_objects<'r>
 
 /* Interface */
 
+const BOSS_OBJECT_TYPES: &[u8] = &[
+    structs::NewIntroBoss::OBJECT_TYPE,
+    structs::ActorContraption::OBJECT_TYPE,
+    structs::Flaahgra::OBJECT_TYPE,
+    structs::IceSheegoth::OBJECT_TYPE,
+    structs::Thardus::OBJECT_TYPE,
+    structs::ElitePirate::OBJECT_TYPE,
+    structs::OmegaPirate::OBJECT_TYPE,
+    structs::RidleyV1::OBJECT_TYPE,
+    structs::RidleyV2::OBJECT_TYPE,
+    structs::MetroidPrimeStage1::OBJECT_TYPE,
+    structs::MetroidPrimeStage2::OBJECT_TYPE,
+];
+
+fn enemy_stats_rule_matches(rule: &EnemyStatsConfig, world: &str, object_type_name: &str, is_boss: bool) -> bool
+{
+    if let Some(worlds) = rule.worlds.as_ref() {
+        if !worlds.iter().any(|x| x.trim().eq_ignore_ascii_case(world)) {
+            return false;
+        }
+    }
+
+    if let Some(enemies) = rule.enemies.as_ref() {
+        if !enemies.iter().any(|x| x.trim().eq_ignore_ascii_case(object_type_name)) {
+            return false;
+        }
+    }
+
+    match rule.bosses {
+        Some(bosses) => bosses == is_boss,
+        None => true,
+    }
+}
+
+// Scales every enemy in the room by each rule which matches it
+pub fn patch_enemy_stats<'r>
+(
+    _ps: &mut PatcherState,
+    area: &mut mlvl_wrapper::MlvlArea<'r, '_, '_, '_>,
+    rules: &[EnemyStatsConfig],
+    world: &str,
+)
+-> Result<(), String>
+{
+    let scly = area.mrea().scly_section_mut();
+    for layer in scly.layers.as_mut_vec().iter_mut() {
+        for obj in layer.objects.as_mut_vec().iter_mut() {
+            if !obj.property_data.supports_patterned_infos() {
+                continue;
+            }
+
+            let object_type_name = obj.property_data.object_type_name();
+            let is_boss = BOSS_OBJECT_TYPES.contains(&obj.property_data.object_type());
+
+            for rule in rules.iter() {
+                if !enemy_stats_rule_matches(rule, world, object_type_name, is_boss) {
+                    continue;
+                }
+
+                if let Some(value) = rule.health {
+                    if obj.property_data.supports_health_infos() {
+                        set_health(obj, value, None);
+                    }
+                }
+
+                if let Some(value) = rule.damage {
+                    set_damage(obj, value);
+                }
+
+                if let Some(value) = rule.speed {
+                    set_patterned_speed(obj, value, None);
+                }
+
+                if let Some(value) = rule.detection_range {
+                    set_detection_range(obj, value, None);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn set_position(obj: &mut structs::SclyObject, value: [f32; 3], relative: bool) {
     if !obj.property_data.supports_position() {
         panic!("object 0x{:X} does not support property \"position\"", obj.instance_id);
@@ -2,6 +2,7 @@
 
 pub use structs;
 pub use reader_writer;
+#[cfg(feature = "mmap")]
 pub use memmap;
 
 use reader_writer::{
@@ -24,6 +25,7 @@ pub mod extern_assets;
 pub mod ciso_writer;
 pub mod dol_patcher;
 pub mod elevators;
+pub mod enemy_randomizer;
 pub mod gcz_writer;
 pub mod mlvl_wrapper;
 pub mod patch_config;
@@ -31,7 +33,12 @@ pub mod patcher;
 pub mod patches;
 pub mod pickup_meta;
 pub mod door_meta;
+pub mod save_file;
+pub mod scly_dump;
+pub mod scly_graph;
+pub mod memory_map;
 pub mod starting_items;
+pub mod strg_translation;
 pub mod txtr_conversions;
 pub mod room_lookup;
 
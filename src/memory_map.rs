@@ -0,0 +1,244 @@
+use std::{collections::BTreeMap, fs::File, io::Write, mem::offset_of};
+
+use dol_symbol_table::mp1_symbol;
+use serde::Serialize;
+
+use crate::{
+    patch_config::{PatchConfig, Version},
+    patches::{version_symbol_addr, RelConfig},
+    pickup_meta::PickupType,
+};
+
+/*
+    Addresses external tools (e.g. auto-trackers reading Dolphin's memory) need to find the
+    game's state. Globals come from the symbol table of the disc's version; the struct offsets
+    below are the same for every GameCube build.
+
+    g_StateManager (CStateManager)
+        +0x84C  CPlayer*
+        +0x850  CWorld*
+                    +0x08  mlvl id
+                    +0x68  current area index
+        +0x8B8  rc_ptr<CPlayerState> (deref twice)
+                    +0x28  items, 8 bytes each: u32 amount, u32 capacity
+    g_GameState (CGameState*)
+        +0x84   mlvl id
+        +0xA0   f64 in-game time (seconds)
+
+    patches.rel is loaded onto the heap, so its globals can only be given relative to their
+    section. Once linked, the module is in the OS's module list and its section table holds the
+    address of each section.
+*/
+
+const STATE_MANAGER_OFFSETS: &[(&str, u32)] = &[
+    ("player", 0x84C),
+    ("world", 0x850),
+    ("playerState", 0x8B8),
+];
+
+const WORLD_OFFSETS: &[(&str, u32)] = &[
+    ("mlvl", 0x08),
+    ("currentArea", 0x68),
+];
+
+const PLAYER_STATE_OFFSETS: &[(&str, u32)] = &[
+    ("items", 0x28),
+];
+
+const GAME_STATE_OFFSETS: &[(&str, u32)] = &[
+    ("mlvl", 0x84),
+    ("playTime", 0xA0),
+];
+
+const PLAYER_STATE_ITEM_SIZE: u32 = 8;
+
+// Head of the OS's list of linked modules (OSModuleInfo*), at the same address in every build
+const OS_MODULE_LIST: u32 = 0x800030C8;
+
+// Globals defined by patches.rel, by their (unmangled) name in its link map
+const REL_GLOBALS: &[&str] = &["REL_CONFIG"];
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RelLocation
+{
+    section: u32,
+    offset: u32,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RelMap
+{
+    module_list: u32,
+    globals: BTreeMap<&'static str, RelLocation>,
+    // Field offsets in REL_CONFIG
+    rel_config: BTreeMap<&'static str, u32>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct MemoryMap
+{
+    version: String,
+    // Raw bytes etched over the DOL's build string, starting at `buildInfo`
+    uuid: Option<String>,
+    build_info: u32,
+    globals: BTreeMap<&'static str, u32>,
+    rel: Option<RelMap>,
+    offsets: BTreeMap<&'static str, BTreeMap<&'static str, u32>>,
+    item_size: u32,
+    items: BTreeMap<&'static str, u32>,
+}
+
+fn offsets(list: &[(&'static str, u32)]) -> BTreeMap<&'static str, u32>
+{
+    list.iter().copied().collect()
+}
+
+fn rel_link_map(version: Version) -> Option<&'static str>
+{
+    match version {
+        Version::NtscU0_00    => Some(rel_files::PATCHES_100_REL_LINKMAP),
+        Version::NtscU0_01    => Some(rel_files::PATCHES_101_REL_LINKMAP),
+        Version::NtscU0_02    => Some(rel_files::PATCHES_102_REL_LINKMAP),
+        Version::Pal          => Some(rel_files::PATCHES_PAL_REL_LINKMAP),
+        Version::NtscK        => Some(rel_files::PATCHES_KOR_REL_LINKMAP),
+        Version::NtscJ        => Some(rel_files::PATCHES_JPN_REL_LINKMAP),
+        Version::NtscUTrilogy => None,
+        Version::NtscJTrilogy => None,
+        Version::PalTrilogy   => None,
+    }
+}
+
+// Symbols in the link map's layout are listed as "<section>:0x<offset> 0x<size> <name>"
+fn rel_location(link_map: &str, name: &str) -> Option<RelLocation>
+{
+    link_map.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let location = parts.next()?;
+        let _size = parts.next()?;
+        if parts.next()? != name {
+            return None;
+        }
+        let (section, offset) = location.split_once(':')?;
+        Some(RelLocation {
+            section: section.parse().ok()?,
+            offset: u32::from_str_radix(offset.trim_start_matches("0x"), 16).ok()?,
+        })
+    })
+}
+
+fn rel_map(version: Version) -> Result<Option<RelMap>, String>
+{
+    let link_map = match rel_link_map(version) {
+        Some(link_map) => link_map,
+        None => return Ok(None),
+    };
+
+    let mut globals = BTreeMap::new();
+    for name in REL_GLOBALS {
+        let location = rel_location(link_map, name)
+            .ok_or_else(|| format!("{} is missing from the link map of the {} REL", name, version))?;
+        globals.insert(*name, location);
+    }
+
+    let rel_config = [
+        ("quickplayMlvl", offset_of!(RelConfig, quickplay_mlvl)),
+        ("quickplayMrea", offset_of!(RelConfig, quickplay_mrea)),
+        ("version", offset_of!(RelConfig, version)),
+        ("seedHash", offset_of!(RelConfig, seed_hash)),
+    ];
+
+    Ok(Some(RelMap {
+        module_list: OS_MODULE_LIST,
+        globals,
+        rel_config: rel_config.iter().map(|(name, offset)| (*name, *offset as u32)).collect(),
+    }))
+}
+
+pub fn export_memory_map(config: &PatchConfig, filename: &str) -> Result<(), String>
+{
+    let version = config.version;
+
+    let symbols = [
+        ("g_StateManager", mp1_symbol!("g_StateManager")),
+        ("g_GameState", mp1_symbol!("g_GameState")),
+        ("g_Main", mp1_symbol!("g_Main")),
+        // Patched by the DOL patches
+        ("g_EtankCapacity", mp1_symbol!("g_EtankCapacity")),
+        ("g_maxPhazonLagBeforeDamaging", mp1_symbol!("g_maxPhazonLagBeforeDamaging")),
+        // Start of the section holding the REL loader and other added code
+        ("OSArenaHi", mp1_symbol!("OSArenaHi")),
+        ("__build_info", mp1_symbol!("__build_info")),
+    ];
+    let mut globals = BTreeMap::new();
+    let mut missing = vec![];
+    for (name, s) in symbols.iter() {
+        match version_symbol_addr(s, version) {
+            Some(addr) => { globals.insert(*name, addr); },
+            None => missing.push(*name),
+        }
+    }
+    if !missing.is_empty() {
+        return Err(format!(
+            "Can't export a memory map for {}, its symbol table is missing: {}",
+            version, missing.join(", "),
+        ));
+    }
+    let build_info = globals.remove("__build_info").unwrap();
+
+    let mut offsets_map = BTreeMap::new();
+    offsets_map.insert("stateManager", offsets(STATE_MANAGER_OFFSETS));
+    offsets_map.insert("world", offsets(WORLD_OFFSETS));
+    offsets_map.insert("playerState", offsets(PLAYER_STATE_OFFSETS));
+    offsets_map.insert("gameState", offsets(GAME_STATE_OFFSETS));
+
+    let items = PickupType::iter()
+        .filter(|pt| pt.kind() == *pt as u32 && *pt != PickupType::Nothing)
+        .map(|pt| (pt.name(), pt.kind()))
+        .collect();
+
+    let map = MemoryMap {
+        version: version.to_string(),
+        uuid: config.uuid.map(|uuid| uuid.iter().map(|b| format!("{:02x}", b)).collect()),
+        // Matches where patch_dol writes the UUID
+        build_info: build_info + "!#$Met".len() as u32,
+        globals,
+        rel: rel_map(version)?,
+        offsets: offsets_map,
+        item_size: PLAYER_STATE_ITEM_SIZE,
+        items,
+    };
+
+    let json = serde_json::to_string_pretty(&map)
+        .map_err(|e| format!("Failed to serialize memory map: {}", e))?;
+    let mut file = File::create(filename)
+        .map_err(|e| format!("Failed to create memory map file: {}", e))?;
+    file.write_all(json.as_bytes())
+        .map_err(|e| format!("Failed to write memory map file: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_rel_location()
+    {
+        let link_map = "\
+Layout
+  Location       Size       Name
+  1:0x00000000   0x00000040 .text.setup_global_state
+    1:0x00000000 0x00000040 setup_global_state
+  5:0x00000020   0x0000003c .bss.REL_CONFIG
+    5:0x00000020 0x0000003c REL_CONFIG (hidden)
+";
+        let location = rel_location(link_map, "REL_CONFIG").unwrap();
+        assert_eq!((location.section, location.offset), (5, 0x20));
+        assert!(rel_location(link_map, "REL_CONFIG_2").is_none());
+    }
+}
@@ -1,12 +1,15 @@
 use std::{
-    ffi::CStr,
-    collections::{HashMap, HashSet},
-    fs::{File, OpenOptions},
+    collections::{BTreeMap, HashMap, HashSet},
+    convert::TryFrom,
+    fs::OpenOptions,
     fs,
     fmt,
+    io::{Read, Seek, SeekFrom, Write},
+    ops::Deref,
     str::FromStr,
 };
 
+#[cfg(feature = "cli")]
 use clap::{
     Arg,
     App,
@@ -20,11 +23,12 @@ use crate::{
     pickup_meta::PickupType,
     custom_assets::custom_asset_ids, door_meta::DoorType,
     room_lookup::ROOM_BY_INTERNAL_ID,
+    strg_translation::{StrgTable, parse_strg_translation},
 };
 
 use reader_writer::{FourCC, Reader};
 
-use structs::{res_id, ResId, MapaObjectVisibilityMode};
+use structs::{res_id, ResId, Languages, MapaObjectVisibilityMode};
 
 use json_data::*;
 use json_strip::strip_jsonc_comments;
@@ -40,6 +44,16 @@ pub enum RunMode
     CreateIso,
     ExportLogbook,
     ExportAssets,
+    ExportStrg,
+    ExportScly,
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub enum SclyGraphFormat
+{
+    Dot,
+    Mermaid,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -92,6 +106,120 @@ pub enum Beam
     Plasma,
 }
 
+// User-facing text which can either be a single string used for every language, or a map of
+// STRG language code to text (e.g. {"ENGL": "...", "FREN": "..."}). Languages which aren't
+// specified fall back to English.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+enum LocalizedStringJson
+{
+    Single(String),
+    PerLanguage(BTreeMap<String, String>),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(try_from = "LocalizedStringJson", into = "LocalizedStringJson")]
+pub enum LocalizedString
+{
+    Single(String),
+    PerLanguage(BTreeMap<String, String>),
+}
+
+impl TryFrom<LocalizedStringJson> for LocalizedString
+{
+    type Error = String;
+
+    fn try_from(json: LocalizedStringJson) -> Result<Self, Self::Error>
+    {
+        match json {
+            LocalizedStringJson::Single(string) => Ok(LocalizedString::Single(string)),
+            LocalizedStringJson::PerLanguage(strings) => {
+                for lang in strings.keys() {
+                    if Languages::from_code(lang.as_bytes()).is_none() {
+                        Err(format!("Unsupported language '{}' in localized text", lang))?
+                    }
+                }
+                if !strings.contains_key("ENGL") {
+                    Err(format!("Localized text is missing the 'ENGL' fallback: {:?}", strings))?
+                }
+                Ok(LocalizedString::PerLanguage(strings))
+            },
+        }
+    }
+}
+
+impl From<LocalizedString> for LocalizedStringJson
+{
+    fn from(string: LocalizedString) -> Self
+    {
+        match string {
+            LocalizedString::Single(string) => LocalizedStringJson::Single(string),
+            LocalizedString::PerLanguage(strings) => LocalizedStringJson::PerLanguage(strings),
+        }
+    }
+}
+
+impl Default for LocalizedString
+{
+    fn default() -> Self
+    {
+        LocalizedString::Single(String::new())
+    }
+}
+
+impl From<String> for LocalizedString
+{
+    fn from(string: String) -> Self
+    {
+        LocalizedString::Single(string)
+    }
+}
+
+impl From<&str> for LocalizedString
+{
+    fn from(string: &str) -> Self
+    {
+        LocalizedString::Single(string.to_string())
+    }
+}
+
+impl LocalizedString
+{
+    pub fn get(&self, lang: &[u8; 4]) -> &str
+    {
+        match self {
+            LocalizedString::Single(string) => string,
+            LocalizedString::PerLanguage(strings) => {
+                std::str::from_utf8(lang).ok()
+                    .and_then(|lang| strings.get(lang))
+                    .unwrap_or_else(|| &strings["ENGL"])
+            },
+        }
+    }
+
+    pub fn english(&self) -> &str
+    {
+        self.get(b"ENGL")
+    }
+
+    pub fn is_localized(&self) -> bool
+    {
+        matches!(self, LocalizedString::PerLanguage(_))
+    }
+
+    // Applies the same formatting (e.g. null terminators or text tags) to every language
+    pub fn map<F>(&self, mut f: F) -> LocalizedString
+        where F: FnMut(&str) -> String
+    {
+        match self {
+            LocalizedString::Single(string) => LocalizedString::Single(f(string)),
+            LocalizedString::PerLanguage(strings) => LocalizedString::PerLanguage(
+                strings.iter().map(|(lang, string)| (lang.clone(), f(string))).collect()
+            ),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct GameBanner
@@ -113,8 +241,8 @@ pub struct PickupConfig
     pub curr_increase: Option<i32>,
     pub max_increase: Option<i32>,
     pub model: Option<String>,
-    pub scan_text: Option<String>,
-    pub hudmemo_text: Option<String>,
+    pub scan_text: Option<LocalizedString>,
+    pub hudmemo_text: Option<LocalizedString>,
     pub respawn: Option<bool>,
     pub position: Option<[f32;3]>,
     pub modal_hudmemo: Option<bool>,
@@ -136,8 +264,8 @@ pub struct ScanConfig
     pub rotation: Option<f32>,
     pub is_red: Option<bool>,
     pub logbook_category: Option<u32>,
-    pub logbook_title: Option<String>,
-    pub text: String,
+    pub logbook_title: Option<LocalizedString>,
+    pub text: LocalizedString,
 }
 
 #[derive(Deserialize, Debug, Default, Clone)]
@@ -584,7 +712,7 @@ pub struct HudmemoConfig
     pub id: u32,
     pub layer: Option<u32>,
     pub active: Option<bool>,
-    pub text: Option<String>,
+    pub text: Option<LocalizedString>,
     pub message_time: Option<f32>,
     pub modal: Option<bool>,
 }
@@ -657,6 +785,19 @@ pub struct PlayerHintConfig
     pub face_object_on_unmorph: Option<bool>,
 }
 
+// Adds (or replaces) a script object of any type, using the same "type"/"properties" layout as
+// the SCLY dump
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AddObjectConfig
+{
+    pub id: Option<u32>,
+    pub layer: Option<u32>,
+    #[serde(rename = "type")]
+    pub object_type: String,
+    pub properties: serde_json::Value,
+}
+
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub enum EnviornmentalEffect {
@@ -867,6 +1008,7 @@ pub struct RoomConfig
     pub counters: Option<Vec<CounterConfig>>,
     pub switches: Option<Vec<SwitchConfig>>,
     pub player_hints: Option<Vec<PlayerHintConfig>>,
+    pub add_objects: Option<Vec<AddObjectConfig>>,
     // Don't forget to update merge_json when adding here
 }
 
@@ -954,6 +1096,47 @@ pub struct CtwkConfig
     pub hud_color: Option<[f32;3]>, // RGB, 0 - 1.0
 }
 
+// A pre-built memory card save, written next to the output ISO
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SaveFileConfig
+{
+    pub output_gci: String,
+    pub room: String, // same format as `startingRoom`
+    pub slot: Option<u8>,
+    pub items: Option<StartingItems>, // defaults to `startingItems`
+    pub artifacts: Option<Vec<String>>,
+    pub hard_mode: Option<bool>,
+    pub play_time: Option<f64>, // seconds
+    pub all_scans: Option<bool>,
+    pub scans: Option<Vec<u32>>,
+    pub map_all: Option<bool>,
+    pub layers: Option<HashMap<String, HashMap<u32, bool>>>, // "<world>:<room>": { <layer #>: active }
+    pub memory_relays: Option<HashMap<String, Vec<u32>>>, // "<world>": [<active memory relay instance id>]
+}
+
+// Replaces enemies with other enemy types that move the same way (ground/air)
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EnemyRandomizerConfig {
+    pub seed: Option<u64>, // defaults to the top-level seed
+    pub exclude_rooms: Option<Vec<String>>, // "<world>:<room>"
+    pub exclude_objects: Option<Vec<u32>>, // instance ids
+}
+
+// Multiplies the stats of every enemy matched by all of the rule's filters
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EnemyStatsConfig {
+    pub enemies: Option<Vec<String>>, // object type names, e.g. "SpacePirate"
+    pub worlds: Option<Vec<String>>,
+    pub bosses: Option<bool>, // only bosses (true) or only regular enemies (false)
+    pub health: Option<f32>,
+    pub damage: Option<f32>,
+    pub speed: Option<f32>,
+    pub detection_range: Option<f32>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct IncineratorDroneConfig {
@@ -1048,10 +1231,17 @@ impl fmt::Display for Version
 }
 
 #[derive(Debug, Serialize)]
-pub struct PatchConfig
+pub struct PatchConfig<'a>
 {
     pub run_mode: RunMode,
     pub logbook_filename: Option<String>,
+    pub strg_filename: Option<String>,
+    pub scly_filename: Option<String>,
+    pub scly_rooms: Vec<String>,
+    pub scly_graph_dir: Option<String>,
+    pub scly_graph_format: SclyGraphFormat,
+    pub memory_map_filename: Option<String>,
+    pub dol_patch_listing: Option<String>,
     pub export_asset_dir: Option<String>,
     pub extern_assets_dir: Option<String>,
     pub seed: u64,
@@ -1062,10 +1252,10 @@ pub struct PatchConfig
     pub version: Version,
 
     #[serde(skip_serializing)]
-    pub input_iso: memmap::Mmap,
+    pub input_iso: InputIso,
     pub iso_format: IsoFormat,
     #[serde(skip_serializing)]
-    pub output_iso: File,
+    pub output_iso: Box<dyn IsoWrite + 'a>,
 
     pub qol_cutscenes: CutsceneMode,
     pub qol_game_breaking: bool,
@@ -1086,6 +1276,8 @@ pub struct PatchConfig
     pub visible_bounding_box: bool,
 
     pub incinerator_drone_config: Option<IncineratorDroneConfig>,
+    pub enemy_randomizer: Option<EnemyRandomizerConfig>,
+    pub enemy_stats: Vec<EnemyStatsConfig>,
     pub hall_of_the_elders_bomb_slot_covers: Option<HallOfTheEldersBombSlotCoversConfig>,
     pub maze_seeds: Option<Vec<u32>>,
 
@@ -1094,8 +1286,13 @@ pub struct PatchConfig
 
     pub strg: HashMap<String, Vec<String>>, // "<decimal asset ID>": <non-null terminated table of strings>
 
+    #[serde(skip_serializing)]
+    pub strg_translation: Vec<StrgTable>,
+
+    pub save_file: Option<SaveFileConfig>,
+
     pub starting_room: String,
-    pub starting_memo: Option<String>,
+    pub starting_memo: Option<LocalizedString>,
     pub spring_ball: bool,
     pub warp_to_start: bool,
     pub warp_to_start_delay_s: f32,
@@ -1131,6 +1328,7 @@ pub struct PatchConfig
 
     pub artifact_hint_behavior: ArtifactHintBehavior,
 
+    #[cfg(feature = "trilogy-music")]
     #[serde(skip_serializing)]
     pub flaahgra_music_files: Option<[nod_wrapper::FileWrapper; 2]>,
 
@@ -1145,11 +1343,12 @@ pub struct PatchConfig
 
     pub game_banner: GameBanner,
     pub comment: String,
-    pub main_menu_message: String,
+    pub main_menu_message: LocalizedString,
+    pub seed_hash: Option<String>,
 
-    pub credits_string: Option<String>,
-    pub results_string: Option<String>,
-    pub artifact_hints: Option<HashMap<String,String>>, // e.g. "Strength":"This item can be found in Ruined Fountain"
+    pub credits_string: Option<LocalizedString>,
+    pub results_string: Option<LocalizedString>,
+    pub artifact_hints: Option<HashMap<String,LocalizedString>>, // e.g. "Strength":"This item can be found in Ruined Fountain"
     pub required_artifact_count: Option<u32>,
     pub artifact_temple_layer_overrides: Option<HashMap<String,bool>>,
     pub no_doors: bool,
@@ -1194,7 +1393,7 @@ struct Preferences
 struct GameConfig
 {
     starting_room: Option<String>,
-    starting_memo: Option<String>,
+    starting_memo: Option<LocalizedString>,
     spring_ball: Option<bool>,
     warp_to_start: Option<bool>,
     warp_to_start_delay_s: Option<f32>,
@@ -1238,16 +1437,19 @@ struct GameConfig
     power_bomb_arboretum_sandstone: Option<bool>,
 
     incinerator_drone_config: Option<IncineratorDroneConfig>,
+    enemy_randomizer: Option<EnemyRandomizerConfig>,
+    enemy_stats: Option<Vec<EnemyStatsConfig>>,
     maze_seeds: Option<Vec<u32>>,
     hall_of_the_elders_bomb_slot_covers: Option<HallOfTheEldersBombSlotCoversConfig>,
 
     game_banner: Option<GameBanner>,
     comment: Option<String>,
-    main_menu_message: Option<String>,
+    main_menu_message: Option<LocalizedString>,
+    seed_hash: Option<String>,
 
-    credits_string: Option<String>,
-    results_string: Option<String>,
-    artifact_hints: Option<HashMap<String,String>>, // e.g. "Strength":"This item can be found in Ruined Fountain"
+    credits_string: Option<LocalizedString>,
+    results_string: Option<LocalizedString>,
+    artifact_hints: Option<HashMap<String,LocalizedString>>, // e.g. "Strength":"This item can be found in Ruined Fountain"
     artifact_temple_layer_overrides: Option<HashMap<String,bool>>,
     required_artifact_count: Option<u32>,
     no_doors: Option<bool>, // Remove every door from the game
@@ -1266,6 +1468,15 @@ struct PatchConfigPrivate
 
     run_mode: Option<String>,
     logbook_filename: Option<String>,
+    strg_filename: Option<String>,
+    strg_translation: Option<String>,
+    scly_filename: Option<String>,
+    scly_rooms: Option<Vec<String>>, // "<world>" or "<world>:<room>"
+    scly_graph_dir: Option<String>,
+    scly_graph_format: Option<SclyGraphFormat>,
+    memory_map_filename: Option<String>,
+    dol_patch_listing: Option<String>,
+    save_file: Option<SaveFileConfig>,
     export_asset_dir: Option<String>,
     input_iso: Option<String>,
     output_iso: Option<String>,
@@ -1328,16 +1539,14 @@ macro_rules! merge_optional {
     };
 }
 
-impl PatchConfig
+impl PatchConfig<'static>
 {
     pub fn from_json(json: &str) -> Result<Self, String>
     {
-        let result = strip_jsonc_comments(json, true);
-        let result = serde_json::from_str(result.as_str());
-        let result: PatchConfigPrivate = result.map_err(|e| format!("JSON parse failed: {}", e))?;
-        result.parse()
+        PatchConfigBuilder::from_json(json)?.build()
     }
 
+    #[cfg(feature = "cli")]
     pub fn from_cli_options() -> Result<Self, String>
     {
         let matches = App::new("randomprime ISO patcher")
@@ -1569,7 +1778,7 @@ impl PatchConfig
             );
         }
 
-        patch_config.parse()
+        PatchConfigBuilder::new(patch_config).build()
     }
 }
 
@@ -1698,6 +1907,7 @@ impl PatchConfigPrivate
                 extend_option_vec!(counters          , self_room_config, other_room_config);
                 extend_option_vec!(switches          , self_room_config, other_room_config);
                 extend_option_vec!(player_hints      , self_room_config, other_room_config);
+                extend_option_vec!(add_objects       , self_room_config, other_room_config);
 
                 if let Some(other_layers) = &other_room_config.layers {
                     if self_room_config.layers.is_none() {
@@ -1810,18 +2020,14 @@ impl PatchConfigPrivate
     }
 
     // parse and then handle configuration macros (e.g. a bool loading in several pages of JSON changes)
-    fn parse(&self) -> Result<PatchConfig, String>
+    fn parse<'a>(&self, input_iso: InputIso, iso_format: IsoFormat, output_iso: Box<dyn IsoWrite + 'a>)
+        -> Result<PatchConfig<'a>, String>
     {
         // Parse version
         let version = {
-            let input_iso_path = self.input_iso.as_deref().unwrap_or("prime.iso");
-            let input_iso_file = File::open(input_iso_path.trim())
-                .map_err(|e| format!("Failed to open {}: {}", input_iso_path, e))?;
-            let input_iso = unsafe { memmap::Mmap::map(&input_iso_file) }
-                .map_err(|e| format!("Failed to open {}: {}", input_iso_path,  e))?;
-
             let mut reader = Reader::new(&input_iso[..]);
-            let gc_disc: structs::GcDisc = reader.read(());
+            let gc_disc: structs::GcDisc = reader.try_read(())
+                .map_err(|e| format!("Failed to parse the input ISO: {}", e))?;
         
             match (&gc_disc.header.game_identifier(), gc_disc.header.disc_id, gc_disc.header.version) {
                 (b"GM8E01", 0, 0)  => Version::NtscU0_00,
@@ -1863,10 +2069,17 @@ impl PatchConfigPrivate
             merge_json(&mut result, QOL)?;
         }
 
-        result.parse_inner(version)
+        result.parse_inner(version, input_iso, iso_format, output_iso)
     }
 
-    fn parse_inner(&self, version: Version) -> Result<PatchConfig, String>
+    fn parse_inner<'a>(
+        &self,
+        version: Version,
+        input_iso: InputIso,
+        iso_format: IsoFormat,
+        output_iso: Box<dyn IsoWrite + 'a>,
+    )
+        -> Result<PatchConfig<'a>, String>
     {
         let run_mode = {
             if self.run_mode.is_some() {
@@ -1874,6 +2087,8 @@ impl PatchConfigPrivate
                     "create_iso" => RunMode::CreateIso,
                     "export_logbook" => RunMode::ExportLogbook,
                     "export_assets" => RunMode::ExportAssets,
+                    "export_strg" => RunMode::ExportStrg,
+                    "export_scly" => RunMode::ExportScly,
                     _ => panic!("Unsupported run mode: {}", self.run_mode.as_ref().unwrap())
                 }
             } else {
@@ -1881,30 +2096,6 @@ impl PatchConfigPrivate
             }
         };
 
-        let input_iso_path = self.input_iso.as_deref().unwrap_or("prime.iso");
-        let input_iso_file = File::open(input_iso_path.trim())
-            .map_err(|e| format!("Failed to open {}: {}", input_iso_path, e))?;
-
-        let input_iso = unsafe { memmap::Mmap::map(&input_iso_file) }
-            .map_err(|e| format!("Failed to open {}: {}", input_iso_path,  e))?;
-
-        let output_iso_path = self.output_iso.as_deref().unwrap_or("prime_out.iso");
-
-        let output_iso = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&output_iso_path)
-            .map_err(|e| format!("Failed to open {}: {}", output_iso_path, e))?;
-
-        let iso_format = if output_iso_path.ends_with(".gcz") {
-            IsoFormat::Gcz
-        } else if output_iso_path.ends_with(".ciso") {
-            IsoFormat::Ciso
-        } else {
-            IsoFormat::Iso
-        };
-
         let force_vanilla_layout = self.force_vanilla_layout.unwrap_or(false);
 
         let artifact_hint_behavior = {
@@ -1944,9 +2135,19 @@ impl PatchConfigPrivate
                 )
         };
 
+        let strg_translation = match self.strg_translation.as_ref() {
+            Some(filename) => parse_strg_translation(filename)?,
+            None => Vec::new(),
+        };
+
+        #[cfg(feature = "trilogy-music")]
         let flaahgra_music_files = self.preferences.trilogy_disc_path.as_ref()
             .map(|path| extract_flaahgra_music_files(path))
             .transpose()?;
+        #[cfg(not(feature = "trilogy-music"))]
+        if self.preferences.trilogy_disc_path.is_some() {
+            Err("trilogyDiscPath requires randomprime to be built with the trilogy-music feature")?
+        }
 
         let mut item_max_capacity = match &self.game_config.item_max_capacity {
             Some(max_capacity) => {
@@ -2059,13 +2260,13 @@ impl PatchConfigPrivate
 
             match message {
                 Some(message) => {
-                    message.to_string()
+                    message.clone()
                 },
                 None => {
                     if force_vanilla_layout {
-                        "".to_string()
+                        "".into()
                     } else {
-                        "randomprime".to_string()
+                        "randomprime".into()
                     }
                 }
             }
@@ -2076,11 +2277,11 @@ impl PatchConfigPrivate
 
             match message {
                 Some(message) => {
-                    Some(message.to_string())
+                    Some(message.clone())
                 },
                 None => {
                     if force_vanilla_layout {
-                        Some("".to_string())
+                        Some("".into())
                     } else {
                         None
                     }
@@ -2093,11 +2294,11 @@ impl PatchConfigPrivate
 
             match message {
                 Some(message) => {
-                    Some(message.to_string())
+                    Some(message.clone())
                 },
                 None => {
                     if force_vanilla_layout {
-                        Some("".to_string())
+                        Some("".into())
                     } else {
                         None
                     }
@@ -2125,6 +2326,13 @@ impl PatchConfigPrivate
         let result = PatchConfig {
             run_mode,
             logbook_filename: self.logbook_filename.clone(),
+            strg_filename: self.strg_filename.clone(),
+            scly_filename: self.scly_filename.clone(),
+            scly_rooms: self.scly_rooms.clone().unwrap_or_default(),
+            scly_graph_dir: self.scly_graph_dir.clone(),
+            scly_graph_format: self.scly_graph_format.unwrap_or(SclyGraphFormat::Dot),
+            memory_map_filename: self.memory_map_filename.clone(),
+            dol_patch_listing: self.dol_patch_listing.clone(),
             export_asset_dir: self.export_asset_dir.clone(),
             version,
             input_iso,
@@ -2138,6 +2346,8 @@ impl PatchConfigPrivate
 
             level_data: self.level_data.clone(),
             strg: self.strg.clone(),
+            strg_translation,
+            save_file: self.save_file.clone(),
 
             qol_game_breaking,
             qol_cosmetic,
@@ -2157,6 +2367,8 @@ impl PatchConfigPrivate
             power_bomb_arboretum_sandstone: self.game_config.power_bomb_arboretum_sandstone.unwrap_or(false),
 
             incinerator_drone_config: self.game_config.incinerator_drone_config.clone(),
+            enemy_randomizer: self.game_config.enemy_randomizer.clone(),
+            enemy_stats: self.game_config.enemy_stats.clone().unwrap_or_default(),
             maze_seeds: self.game_config.maze_seeds.clone(),
             hall_of_the_elders_bomb_slot_covers: self
                 .game_config
@@ -2166,6 +2378,7 @@ impl PatchConfigPrivate
             automatic_crash_screen: self.preferences.automatic_crash_screen.unwrap_or(true),
             visible_bounding_box: self.preferences.visible_bounding_box.unwrap_or(false),
             artifact_hint_behavior,
+            #[cfg(feature = "trilogy-music")]
             flaahgra_music_files,
             suit_colors: self.preferences.suit_colors.clone(),
             force_fusion: self.preferences.force_fusion.clone().unwrap_or(false),
@@ -2220,6 +2433,7 @@ impl PatchConfigPrivate
             game_banner: self.game_config.game_banner.clone().unwrap_or_default(),
             comment: self.game_config.comment.clone().unwrap_or(String::new()),
             main_menu_message,
+            seed_hash: self.game_config.seed_hash.clone(),
 
             credits_string,
             results_string,
@@ -2235,15 +2449,200 @@ impl PatchConfigPrivate
     }
 }
 
+/*** Builder ***/
+
+/// Parses a config and supplies the discs to patch. Input and output discs which aren't given
+/// explicitly are opened from the config's `inputIso` and `outputIso` paths when building, so
+/// nothing touches the filesystem if both are supplied.
+///
+/// ```no_run
+/// # use randomprime::{c_interface::{CbMessage, ProgressNotifier}, patch_config::PatchConfigBuilder, patches};
+/// # fn f(json: &str, iso: Vec<u8>) -> Result<Vec<u8>, String> {
+/// let mut output = std::io::Cursor::new(Vec::new());
+/// let config = PatchConfigBuilder::from_json(json)?
+///     .input_bytes(iso)
+///     .output(&mut output)
+///     .build()?;
+/// patches::patch_iso(config, ProgressNotifier::new(|_: &CbMessage| ()))?;
+/// Ok(output.into_inner())
+/// # }
+/// ```
+pub struct PatchConfigBuilder<'a>
+{
+    config: PatchConfigPrivate,
+    input_iso: Option<InputIso>,
+    iso_format: Option<IsoFormat>,
+    output_iso: Option<Box<dyn IsoWrite + 'a>>,
+}
+
+impl<'a> PatchConfigBuilder<'a>
+{
+    fn new(config: PatchConfigPrivate) -> Self
+    {
+        PatchConfigBuilder {
+            config,
+            input_iso: None,
+            iso_format: None,
+            output_iso: None,
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String>
+    {
+        let result = strip_jsonc_comments(json, true);
+        let result = serde_json::from_str(result.as_str());
+        let result: PatchConfigPrivate = result.map_err(|e| format!("JSON parse failed: {}", e))?;
+        Ok(PatchConfigBuilder::new(result))
+    }
+
+    /// Patches a disc image held in memory
+    pub fn input_bytes(mut self, bytes: impl Into<Vec<u8>>) -> Self
+    {
+        self.input_iso = Some(InputIso::Memory(bytes.into()));
+        self
+    }
+
+    /// Reads the whole disc image from `reader`, starting at its beginning
+    pub fn input_reader<R: Read + Seek>(mut self, mut reader: R) -> Result<Self, String>
+    {
+        let mut bytes = Vec::new();
+        reader.seek(SeekFrom::Start(0))
+            .and_then(|_| reader.read_to_end(&mut bytes))
+            .map_err(|e| format!("Failed to read input ISO: {}", e))?;
+        self.input_iso = Some(InputIso::Memory(bytes));
+        Ok(self)
+    }
+
+    pub fn input_iso(mut self, input_iso: InputIso) -> Self
+    {
+        self.input_iso = Some(input_iso);
+        self
+    }
+
+    /// Writes the patched disc to `writer` instead of the config's `outputIso`. Unless `iso_format`
+    /// is called, the format is still picked from `outputIso`'s extension.
+    pub fn output<W: Write + Seek + 'a>(mut self, writer: W) -> Self
+    {
+        self.output_iso = Some(Box::new(writer));
+        self
+    }
+
+    pub fn iso_format(mut self, iso_format: IsoFormat) -> Self
+    {
+        self.iso_format = Some(iso_format);
+        self
+    }
+
+    pub fn build(self) -> Result<PatchConfig<'a>, String>
+    {
+        let input_iso = match self.input_iso {
+            Some(input_iso) => input_iso,
+            None => open_input_iso(self.config.input_iso.as_deref().unwrap_or("prime.iso"))?,
+        };
+
+        let output_iso_path = self.config.output_iso.as_deref().unwrap_or("prime_out.iso");
+        let iso_format = self.iso_format.unwrap_or_else(|| IsoFormat::from_path(output_iso_path));
+
+        let output_iso = match self.output_iso {
+            Some(output_iso) => output_iso,
+            None => open_output_iso(output_iso_path)?,
+        };
+
+        self.config.parse(input_iso, iso_format, output_iso)
+    }
+}
+
 /*** Helper Methods ***/
 
+/// The unmodified disc. Files are memory mapped when the mmap feature is enabled, otherwise
+/// they're read into memory.
+pub enum InputIso
+{
+    #[cfg(feature = "mmap")]
+    Mapped(memmap::Mmap),
+    Memory(Vec<u8>),
+}
+
+impl Deref for InputIso
+{
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8]
+    {
+        match self {
+            #[cfg(feature = "mmap")]
+            InputIso::Mapped(mmap) => &mmap[..],
+            InputIso::Memory(bytes) => &bytes[..],
+        }
+    }
+}
+
+impl fmt::Debug for InputIso
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "InputIso({} bytes)", self.len())
+    }
+}
+
+/// Where the patched disc is written
+pub trait IsoWrite: Write + Seek {}
+
+impl<T: Write + Seek> IsoWrite for T {}
+
+impl fmt::Debug for dyn IsoWrite + '_
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "IsoWrite")
+    }
+}
+
+impl IsoFormat
+{
+    fn from_path(path: &str) -> IsoFormat
+    {
+        if path.ends_with(".gcz") {
+            IsoFormat::Gcz
+        } else if path.ends_with(".ciso") {
+            IsoFormat::Ciso
+        } else {
+            IsoFormat::Iso
+        }
+    }
+}
+
+fn open_input_iso(path: &str) -> Result<InputIso, String>
+{
+    #[cfg(feature = "mmap")]
+    let input_iso = fs::File::open(path.trim())
+        .and_then(|file| unsafe { memmap::Mmap::map(&file) })
+        .map(InputIso::Mapped);
+    #[cfg(not(feature = "mmap"))]
+    let input_iso = fs::read(path.trim()).map(InputIso::Memory);
+
+    input_iso.map_err(|e| format!("Failed to open {}: {}", path, e))
+}
+
+fn open_output_iso(path: &str) -> Result<Box<dyn IsoWrite>, String>
+{
+    let output_iso = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path, e))?;
+    Ok(Box::new(output_iso))
+}
+
+#[cfg(feature = "trilogy-music")]
 pub fn extract_flaahgra_music_files(iso_path: &str) -> Result<[nod_wrapper::FileWrapper; 2], String>
 {
     let res = (|| {
         let dw = nod_wrapper::DiscWrapper::new(iso_path)?;
         Ok([
-            dw.open_file(CStr::from_bytes_with_nul(b"rui_flaaghraR.dsp\0").unwrap())?,
-            dw.open_file(CStr::from_bytes_with_nul(b"rui_flaaghraL.dsp\0").unwrap())?,
+            dw.open_file(std::ffi::CStr::from_bytes_with_nul(b"rui_flaaghraR.dsp\0").unwrap())?,
+            dw.open_file(std::ffi::CStr::from_bytes_with_nul(b"rui_flaaghraL.dsp\0").unwrap())?,
         ])
     })();
     res.map_err(|s: String| format!("Failed to extract Flaahgra music files: {}", s))
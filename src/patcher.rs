@@ -97,7 +97,8 @@ impl<'r, 's> PrimePatcher<'r, 's>
 
         for (name, fst_entry) in files {
             if let Some(patches) = self.file_patches.get_mut(&name[..]) {
-                fst_entry.guess_kind();
+                fst_entry.try_guess_kind()
+                    .map_err(|e| format!("Failed to parse {}: {}", String::from_utf8_lossy(&name), e))?;
                 for patch in patches.iter_mut() {
                     (*patch)(&mut fst_entry.file_mut().unwrap())?
                 }
@@ -111,7 +112,8 @@ impl<'r, 's> PrimePatcher<'r, 's>
                 continue;
             }
 
-            fst_entry.guess_kind();
+            fst_entry.try_guess_kind()
+                .map_err(|e| format!("Failed to parse {}: {}", String::from_utf8_lossy(&name), e))?;
             let pak = match fst_entry.file_mut().unwrap() {
                 structs::FstEntryFile::Pak(pak) => pak,
                 _ => panic!(),
@@ -131,10 +133,15 @@ impl<'r, 's> PrimePatcher<'r, 's>
                     continue;
                 }
 
-                let mlvl = pak.resources.iter()
+                let mlvl_res = pak.resources.iter()
                     .find(|i| i.fourcc() == reader_writer::FourCC::from_bytes(b"MLVL"))
-                    .unwrap()
-                    .kind.as_mlvl().unwrap().into_owned();
+                    .unwrap();
+                let mlvl = mlvl_res.kind.try_as_mlvl()
+                    .map_err(|e| format!(
+                        "Failed to parse MLVL 0x{:08X} in {}: {}",
+                        mlvl_res.file_id, String::from_utf8_lossy(&name), e,
+                    ))?
+                    .unwrap().into_owned();
                 Some(MlvlEditor::new(mlvl))
             } else {
                 None
@@ -149,6 +156,15 @@ impl<'r, 's> PrimePatcher<'r, 's>
                     id: cursor.peek().unwrap().file_id,
                 };
 
+                // Parse the resource up front so malformed data is reported as an error rather
+                // than a panic from inside the patch
+                if self.resource_patches.iter().any(|(patch_key, _)| *patch_key == res_key) {
+                    cursor.value().unwrap().kind.try_guess_kind()
+                        .map_err(|e| format!(
+                            "Failed to parse {} 0x{:08X} in {}: {}",
+                            res_key.kind, res_key.id, String::from_utf8_lossy(res_key.pak_name), e,
+                        ))?;
+                }
                 for (patch_key, patch_func) in self.resource_patches.iter_mut() {
                     if *patch_key == res_key {
                         patch_func(cursor.value().unwrap())?;
@@ -160,6 +176,16 @@ impl<'r, 's> PrimePatcher<'r, 's>
                     room_id: cursor.peek().unwrap().file_id,
                 };
                 if let Some((_, patches)) = self.scly_patches.iter_mut().find(|p| p.0 == mrea_key) {
+                    let kind = &mut cursor.value().unwrap().kind;
+                    kind.try_guess_kind()
+                        .and_then(|()| match kind {
+                            ResourceKind::Mrea(mrea) => mrea.try_scly_section_mut().map(|_| ()),
+                            _ => Ok(()),
+                        })
+                        .map_err(|e| format!(
+                            "Failed to parse MREA 0x{:08X} in {}: {}",
+                            mrea_key.room_id, String::from_utf8_lossy(mrea_key.pak_name), e,
+                        ))?;
                     let mut mlvl_area = mlvl_editor.as_mut().unwrap().get_area(&mut cursor);
                     for patch in patches.iter_mut() {
                         patch(&mut patcher_state, &mut mlvl_area)?;
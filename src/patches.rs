@@ -40,6 +40,7 @@ use crate::patch_config::{
     ConnectionState,
     ConnectionMsg,
     DifficultyBehavior,
+    LocalizedString,
 };
 
 use std::{fs::{self, File}, io::Read, path::Path};
@@ -48,10 +49,11 @@ use std::hash::{Hash, Hasher};
 
 use crate::{
     add_modify_obj_patches::*,
-    generic_edit::patch_edit_objects,
+    generic_edit::{patch_edit_objects, patch_enemy_stats},
     custom_assets::{custom_asset_ids, PickupHashKey, collect_game_resources, custom_asset_filename},
     dol_patcher::DolPatcher,
     ciso_writer::CisoWriter,
+    enemy_randomizer::{plan_enemy_randomizer, patch_randomize_enemies},
     elevators::{Elevator, SpawnRoom, SpawnRoomData, World, is_elevator, is_teleporter},
     gcz_writer::GczWriter,
     mlvl_wrapper,
@@ -72,9 +74,14 @@ use crate::{
     },
     GcDiscLookupExtensions,
     extern_assets::ExternPickupModel,
+    strg_translation::{export_strgs, patch_translate_strg, vanilla_strg_languages},
+    save_file::write_save_file,
+    memory_map::export_memory_map,
+    scly_dump::export_scly,
+    scly_graph::add_scly_graph_patches,
 };
 
-use dol_symbol_table::mp1_symbol;
+use dol_symbol_table::{mp1_symbol, Mp1Symbol};
 use resource_info_table::{resource_info, ResourceInfo};
 use ppcasm::ppcasm;
 
@@ -94,7 +101,7 @@ use std::{
     collections::HashMap,
     convert::TryInto,
     ffi::CString,
-    io::Write,
+    io::{self, Seek, SeekFrom, Write},
     iter,
     mem,
     time::Instant,
@@ -170,10 +177,10 @@ fn post_pickup_relay_template<'r>(instance_id: u32, connections: &'static [struc
 fn build_artifact_temple_totem_scan_strings<R>(
     level_data: &HashMap<String, LevelConfig>,
     rng: &mut R,
-    artifact_hints: Option<HashMap<String,String>>,
+    artifact_hints: Option<HashMap<String,LocalizedString>>,
 
 )
-    -> [String; 12]
+    -> [LocalizedString; 12]
     where R: Rng
 {
     let mut generic_text_templates = [
@@ -268,6 +275,7 @@ fn build_artifact_temple_totem_scan_strings<R>(
         }
     }
 
+    let mut scan_text_localized: [Option<LocalizedString>; 12] = Default::default();
     if artifact_hints.is_some() {
         for (artifact_name, hint) in artifact_hints.unwrap() {
             let words: Vec<&str> = artifact_name.split(" ").collect();
@@ -287,24 +295,28 @@ fn build_artifact_temple_totem_scan_strings<R>(
                 "strength"  => 11,
                 _ => panic!("Error - Unknown artifact - '{}'", artifact_name)
             };
-            scan_text[idx] = format!("{}\0",hint.to_owned());
+            scan_text_localized[idx] = Some(hint.map(|hint| format!("{}\0", hint)));
         }
     }
 
-    scan_text
+    let mut scan_text = scan_text.into_iter().map(LocalizedString::from);
+    scan_text_localized.map(|hint| {
+        let text = scan_text.next().unwrap();
+        hint.unwrap_or(text)
+    })
 }
 
-fn patch_artifact_totem_scan_strg(res: &mut structs::Resource, text: &str, version: Version)
+fn patch_artifact_totem_scan_strg(res: &mut structs::Resource, text: &LocalizedString, version: Version)
     -> Result<(), String>
 {
-    let mut string = format!("{}", text);
-    if version == Version::NtscJ {
-        string = format!("&line-extra-space=4;&font=C29C51F1;{}", string);
-    }
     let strg = res.kind.as_strg_mut().unwrap();
     for st in strg.string_tables.as_mut_vec().iter_mut() {
+        let mut string = text.get(st.lang.as_bytes()).to_string();
+        if version == Version::NtscJ {
+            string = format!("&line-extra-space=4;&font=C29C51F1;{}", string);
+        }
         let strings = st.strings.as_mut_vec();
-        *strings.last_mut().unwrap() = format!("{}", string).into();
+        *strings.last_mut().unwrap() = string.into();
     }
     Ok(())
 }
@@ -8867,7 +8879,7 @@ fn patch_memorycard_strg(res: &mut structs::Resource, version: Version) -> Resul
     Ok(())
 }
 
-fn patch_main_strg(res: &mut structs::Resource, version: Version, msg: &str) -> Result<(), String>
+fn patch_main_strg(res: &mut structs::Resource, version: Version, msg: &LocalizedString) -> Result<(), String>
 {
     if version == Version::NtscJ {
         let strings_jpn = res.kind.as_strg_mut().unwrap()
@@ -8883,7 +8895,7 @@ fn patch_main_strg(res: &mut structs::Resource, version: Version, msg: &str) ->
             .nth(37)
             .unwrap();
         *s = "&main-color=#FFFFFF;エクストラ\u{0}".to_string().into();
-        strings_jpn.push(format!("{}\0", msg).into());
+        strings_jpn.push(format!("{}\0", msg.get(b"JAPN")).into());
     }
 
     if version == Version::Pal {
@@ -8896,7 +8908,7 @@ fn patch_main_strg(res: &mut structs::Resource, version: Version, msg: &str) ->
                 .unwrap()
                 .strings
                 .as_mut_vec();
-            strings_pal.push(format!("{}\0", msg).into());
+            strings_pal.push(format!("{}\0", msg.get(lang)).into());
         }
     }
 
@@ -8913,7 +8925,7 @@ fn patch_main_strg(res: &mut structs::Resource, version: Version, msg: &str) ->
         .find(|s| *s == "Metroid Fusion Connection Bonuses\u{0}")
         .unwrap();
     *s = "Extras\u{0}".to_string().into();
-    strings.push(format!("{}\0", msg).into());
+    strings.push(format!("{}\0", msg.english()).into());
 
     Ok(())
 }
@@ -8995,15 +9007,14 @@ fn patch_credits(
 )
     -> Result<(), String>
 {
-    let mut output = "\n\n\n\n\n\n\n".to_string();
+    let mut header = "\n\n\n\n\n\n\n".to_string();
 
     if version == Version::NtscJ {
-        output = format!("&line-extra-space=8;&font=5D696116;{}", &output[..output.len()-2]);
+        header = format!("&line-extra-space=8;&font=5D696116;{}", &header[..header.len()-2]);
     }
 
-    if config.credits_string.is_some() {
-        output = format!("{}{}", output, config.credits_string.as_ref().unwrap());
-    } else {
+    let mut output = String::new();
+    if config.credits_string.is_none() {
         output = format!(
             "{}{}",
             output,
@@ -9064,14 +9075,20 @@ fn patch_credits(
             write!(output, "\n\n{}: {}", pickup_name, room_name).unwrap();
         }
     }
-    output = format!("{}{}", output, "\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0");
+    let footer = "\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\n\0";
+    let credits_strings = |lang: &[u8; 4]| {
+        let body = match config.credits_string.as_ref() {
+            Some(credits_string) => credits_string.get(lang),
+            None => &output,
+        };
+        vec![format!("{}{}{}", header, body, footer)]
+    };
     if version == Version::NtscJ {
         res.kind.as_strg_mut().unwrap()
-                              .add_strings(&[ format!("{}", output) ],
-                                             Languages::Some(&[ b"ENGL", b"JAPN" ]));
+                              .add_strings_per_language(Languages::Some(&[ b"ENGL", b"JAPN" ]), credits_strings);
     } else {
         res.kind.as_strg_mut().unwrap()
-                              .add_strings(&[ format!("{}", output) ], Languages::All);
+                              .add_strings_per_language(Languages::All, credits_strings);
     }
 
     /* We are who we choose to be */
@@ -9084,20 +9101,21 @@ fn patch_credits(
 
 fn patch_completion_screen(
     res: &mut structs::Resource,
-    mut results_string: String,
+    results_string: &LocalizedString,
     version: Version,
 )
     -> Result<(), String>
 {
-    if version == Version::NtscJ {
-        results_string = format!("&line-extra-space=4;&font=C29C51F1;{}", results_string);
-    }
-    results_string += "\nPercentage Complete\0";
-
     let strg = res.kind.as_strg_mut().unwrap();
     for st in strg.string_tables.as_mut_vec().iter_mut() {
+        let mut results_string = results_string.get(st.lang.as_bytes()).to_string();
+        if version == Version::NtscJ {
+            results_string = format!("&line-extra-space=4;&font=C29C51F1;{}", results_string);
+        }
+        results_string += "\nPercentage Complete\0";
+
         let strings = st.strings.as_mut_vec();
-        strings[1] = results_string.to_owned().into();
+        strings[1] = results_string.into();
     }
     Ok(())
 }
@@ -9251,16 +9269,45 @@ fn patch_starting_pickups<'r>(
 include!("../compile_to_ppc/patches_config.rs");
 fn create_rel_config_file(
     spawn_room: SpawnRoomData,
-    quickplay: bool,
-) -> Vec<u8>
+    config: &PatchConfig,
+) -> Result<Vec<u8>, String>
 {
-    let config = RelConfig {
-        quickplay_mlvl: if quickplay { spawn_room.mlvl } else { 0xFFFFFFFF },
-        quickplay_mrea: if quickplay { spawn_room.mrea } else { 0xFFFFFFFF },
-    };
+    let mut rel_config = RelConfig::DEFAULT;
+
+    if config.quickplay {
+        rel_config.quickplay_mlvl = spawn_room.mlvl;
+        rel_config.quickplay_mrea = spawn_room.mrea;
+    }
+
+    if let Some(seed_hash) = &config.seed_hash {
+        if !seed_hash.is_ascii() || seed_hash.len() > SEED_HASH_LEN {
+            return Err(format!(
+                "seedHash must be at most {} ASCII characters, got '{}'",
+                SEED_HASH_LEN,
+                seed_hash,
+            ));
+        }
+        rel_config.seed_hash[..seed_hash.len()].copy_from_slice(seed_hash.as_bytes());
+    }
+
     let mut buf = vec![0; mem::size_of::<RelConfig>()];
-    ssmarshal::serialize(&mut buf, &config).unwrap();
-    buf
+    ssmarshal::serialize(&mut buf, &rel_config).unwrap();
+    Ok(buf)
+}
+
+pub(crate) fn version_symbol_addr(s: &Mp1Symbol, version: Version) -> Option<u32>
+{
+    match version {
+        Version::NtscU0_00    => s.addr_0_00,
+        Version::NtscU0_01    => s.addr_0_01,
+        Version::NtscU0_02    => s.addr_0_02,
+        Version::NtscK        => s.addr_kor,
+        Version::NtscJ        => s.addr_jpn,
+        Version::Pal          => s.addr_pal,
+        Version::NtscUTrilogy => s.addr_trilogy_ntsc_u,
+        Version::NtscJTrilogy => s.addr_trilogy_ntsc_j,
+        Version::PalTrilogy   => s.addr_trilogy_pal,
+    }
 }
 
 fn patch_dol<'r>(
@@ -9277,25 +9324,19 @@ fn patch_dol<'r>(
     shoot_in_grapple: bool,
 ) -> Result<(), String>
 {
-    if version == Version::NtscUTrilogy || version == Version::NtscJTrilogy || version == Version::PalTrilogy {
-        return Ok(())
-    }
-
+    // The DOL is only replaced once every patch has been built, so a version whose symbol table
+    // hasn't been filled in yet (e.g. Trilogy) is left unpatched instead of failing the whole run
     macro_rules! symbol_addr {
         ($sym:tt, $version:expr) => {
-            {
-                let s = mp1_symbol!($sym);
-                match &$version {
-                    Version::NtscU0_00    => s.addr_0_00,
-                    Version::NtscU0_01    => s.addr_0_01,
-                    Version::NtscU0_02    => s.addr_0_02,
-                    Version::NtscK        => s.addr_kor,
-                    Version::NtscJ        => s.addr_jpn,
-                    Version::Pal          => s.addr_pal,
-                    Version::NtscUTrilogy => unreachable!(),
-                    Version::NtscJTrilogy => unreachable!(),
-                    Version::PalTrilogy   => unreachable!(),
-                }.unwrap_or_else(|| panic!("Symbol {} unknown for version {}", $sym, $version))
+            match version_symbol_addr(&mp1_symbol!($sym), $version) {
+                Some(addr) => addr,
+                None => {
+                    println!(
+                        "Warning, skipping the DOL patches, {} is missing from the {} symbol table",
+                        $sym, $version,
+                    );
+                    return Ok(());
+                },
             }
         }
     }
@@ -9318,30 +9359,22 @@ fn patch_dol<'r>(
         let uuid = uuid.unwrap();
 
         // e.g. "!#$MetroidBuildInfo!#$ Build v1.088 10/29/2002 2:21:25"
-        let build_info_address: u32 = match version {
-            Version::NtscU0_00    => 0x803cc588,
-            Version::NtscU0_01    => 0x803cc768,
-            Version::NtscU0_02    => 0x803cd648,
-            Version::NtscK        => 0x803cc688,
-            Version::NtscJ        => 0x803b86cc,
-            Version::Pal          => 0x803b6924,
-            _ => panic!("This version of the game does not support etching a UUID into the dol"),
-        };
+        let build_info_address = symbol_addr!("__build_info", version);
 
         // Leave the start alone for easier pattern matching
         let build_info_address = build_info_address + "!#$Met".len() as u32;
 
         // Replace characters with raw bytes
-        dol_patcher.patch(build_info_address, uuid.to_vec().clone().into())?;
+        dol_patcher.labeled_patch("uuid", build_info_address, uuid.to_vec().clone().into())?;
     }
 
     if version == Version::Pal || version == Version::NtscJ {
         dol_patcher
-            .patch(symbol_addr!("aMetroidprime", version), b"randomprime\0"[..].into())?;
+            .labeled_patch("save file name", symbol_addr!("aMetroidprime", version), b"randomprime\0"[..].into())?;
     } else {
         dol_patcher
-            .patch(symbol_addr!("aMetroidprimeA", version), b"randomprime A\0"[..].into())?
-            .patch(symbol_addr!("aMetroidprimeB", version), b"randomprime B\0"[..].into())?;
+            .labeled_patch("save file name", symbol_addr!("aMetroidprimeA", version), b"randomprime A\0"[..].into())?
+            .labeled_patch("save file name", symbol_addr!("aMetroidprimeB", version), b"randomprime B\0"[..].into())?;
     }
 
     if config.difficulty_behavior != DifficultyBehavior::Either {
@@ -9353,7 +9386,7 @@ fn patch_dol<'r>(
         let only_one_option_patch = ppcasm!(symbol_addr!("ActivateNewGamePopup__19SNewFileSelectFrameFv", version) + 0x110, {
             b   { symbol_addr!("ActivateNewGamePopup__19SNewFileSelectFrameFv", version) + only_one_option_jump_offset };
         });
-        dol_patcher.ppcasm_patch(&only_one_option_patch)?;
+        dol_patcher.labeled_ppcasm_patch("difficulty behavior", &only_one_option_patch)?;
     }
 
     match config.difficulty_behavior {
@@ -9361,14 +9394,14 @@ fn patch_dol<'r>(
             let normal_is_only_patch = ppcasm!(symbol_addr!("DoPopupAdvance__19SNewFileSelectFrameFPC14CGuiTableGroup", version) + 0x78, {
                 b   { symbol_addr!("DoPopupAdvance__19SNewFileSelectFrameFPC14CGuiTableGroup", version) + 0xd0 };
             });
-            dol_patcher.ppcasm_patch(&normal_is_only_patch)?;
+            dol_patcher.labeled_ppcasm_patch("difficulty behavior", &normal_is_only_patch)?;
         },
         DifficultyBehavior::HardOnly => {},
         DifficultyBehavior::Either => {
             let normal_is_default_patch = ppcasm!(symbol_addr!("ActivateNewGamePopup__19SNewFileSelectFrameFv", version) + 0x3C, {
                 li      r4, 2;
             });
-            dol_patcher.ppcasm_patch(&normal_is_default_patch)?;
+            dol_patcher.labeled_ppcasm_patch("difficulty behavior", &normal_is_default_patch)?;
         },
     };
 
@@ -9384,14 +9417,14 @@ fn patch_dol<'r>(
         let escape_seq_timer_count_up_patch = ppcasm!(symbol_addr!("UpdateEscapeSequenceTimer__13CStateManagerFf", version) + 0x30, {
             fadds   f2, f2, f1;
         });
-        dol_patcher.ppcasm_patch(&escape_seq_timer_count_up_patch)?;
+        dol_patcher.labeled_ppcasm_patch("escape sequence counts up", &escape_seq_timer_count_up_patch)?;
 
         // Escape Sequences don't check for rumbling
         // NTSC-U (0x80044fa8 - 0x80044ef4) => b (0x80045058 - 0x80044ef4)
         let remove_escape_sequence_rumble_patch = ppcasm!(symbol_addr!("UpdateEscapeSequenceTimer__13CStateManagerFf", version) + 0xb4, {
                 b       { symbol_addr!("UpdateEscapeSequenceTimer__13CStateManagerFf", version) + 0x164 };
         });
-        dol_patcher.ppcasm_patch(&remove_escape_sequence_rumble_patch)?;
+        dol_patcher.labeled_ppcasm_patch("escape sequence counts up", &remove_escape_sequence_rumble_patch)?;
 
         // Never hide the escape sequence timer
         // NTSC-U (0x80066e78 - 0x80066380)
@@ -9403,7 +9436,7 @@ fn patch_dol<'r>(
         let remove_escape_sequence_rumble_patch = ppcasm!(symbol_addr!("Update__9CSamusHudFfRC13CStateManagerUibb", version) + patch_offset, {
                 nop
         });
-        dol_patcher.ppcasm_patch(&remove_escape_sequence_rumble_patch)?;
+        dol_patcher.labeled_ppcasm_patch("escape sequence counts up", &remove_escape_sequence_rumble_patch)?;
     }
     // byte pattern to find GetIsFusionEnabled__12CPlayerStateFv
     // 88030000 5403dffe 7c0300d0
@@ -9411,18 +9444,18 @@ fn patch_dol<'r>(
         let force_fusion_patch = ppcasm!(symbol_addr!("GetIsFusionEnabled__12CPlayerStateFv", version) + 4, {
                 li  r0, 1;
         });
-        dol_patcher.ppcasm_patch(&force_fusion_patch)?;
+        dol_patcher.labeled_ppcasm_patch("force fusion", &force_fusion_patch)?;
     }
 
     if remove_ball_color {
         let colors = b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00".to_vec();
-        dol_patcher.patch(symbol_addr!("skBallInnerGlowColors"  , version), colors.clone().into())?;
-        dol_patcher.patch(symbol_addr!("BallAuxGlowColors"      , version), colors.clone().into())?;
-        dol_patcher.patch(symbol_addr!("BallTransFlashColors"   , version), colors.clone().into())?;
-        dol_patcher.patch(symbol_addr!("BallSwooshColors"       , version), colors.clone().into())?;
-        dol_patcher.patch(symbol_addr!("BallSwooshColorsJaggy"  , version), colors.clone().into())?;
-        dol_patcher.patch(symbol_addr!("BallSwooshColorsCharged", version), colors.clone().into())?;
-        dol_patcher.patch(symbol_addr!("BallGlowColors"         , version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("skBallInnerGlowColors"  , version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("BallAuxGlowColors"      , version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("BallTransFlashColors"   , version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("BallSwooshColors"       , version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("BallSwooshColorsJaggy"  , version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("BallSwooshColorsCharged", version), colors.clone().into())?;
+        dol_patcher.labeled_patch("remove ball color", symbol_addr!("BallGlowColors"         , version), colors.clone().into())?;
     } else if config.suit_colors.is_some() {
         let suit_colors = config.suit_colors.as_ref().unwrap();
         let mut colors: Vec<Vec<u8>> = Vec::new();
@@ -9466,13 +9499,13 @@ fn patch_dol<'r>(
         }
 
         let mut i = 0;
-        dol_patcher.patch(symbol_addr!("skBallInnerGlowColors"  , version), colors[i].clone().into())?; i+=1;
-        dol_patcher.patch(symbol_addr!("BallAuxGlowColors"      , version), colors[i].clone().into())?; i+=1;
-        dol_patcher.patch(symbol_addr!("BallTransFlashColors"   , version), colors[i].clone().into())?; i+=1;
-        dol_patcher.patch(symbol_addr!("BallSwooshColors"       , version), colors[i].clone().into())?; i+=1;
-        dol_patcher.patch(symbol_addr!("BallSwooshColorsJaggy"  , version), colors[i].clone().into())?; i+=1;
-        dol_patcher.patch(symbol_addr!("BallSwooshColorsCharged", version), colors[i].clone().into())?; i+=1;
-        dol_patcher.patch(symbol_addr!("BallGlowColors"         , version), colors[i].clone().into())?;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("skBallInnerGlowColors"  , version), colors[i].clone().into())?; i+=1;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("BallAuxGlowColors"      , version), colors[i].clone().into())?; i+=1;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("BallTransFlashColors"   , version), colors[i].clone().into())?; i+=1;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("BallSwooshColors"       , version), colors[i].clone().into())?; i+=1;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("BallSwooshColorsJaggy"  , version), colors[i].clone().into())?; i+=1;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("BallSwooshColorsCharged", version), colors[i].clone().into())?; i+=1;
+        dol_patcher.labeled_patch("suit colors", symbol_addr!("BallGlowColors"         , version), colors[i].clone().into())?;
     }
 
     if config.starting_visor != Visor::Combat {
@@ -9487,13 +9520,13 @@ fn patch_dol<'r>(
                     stw     r0, 0x14(r31); // currentVisor
                     stw     r0, 0x18(r31); // transitioningVisor
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             let default_visor_patch = ppcasm!(symbol_addr!("__ct__12CPlayerStateFR12CInputStream", version) + 0x70, {
                     li      r0, scan_visor;
                     stw     r0, 0x14(r30); // currentVisor
                     stw     r0, 0x18(r30); // transitioningVisor
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             // spawn after elevator
             let default_visor_patch = ppcasm!(symbol_addr!("ResetVisor__12CPlayerStateFv", version), {
                     li      r0, scan_visor;
@@ -9502,7 +9535,7 @@ fn patch_dol<'r>(
                     nop;
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
         // Otherwise, spawn mid-transition into default visor
         } else {
             // spawn on game initalization
@@ -9511,13 +9544,13 @@ fn patch_dol<'r>(
                     stw     r6, 0x14(r31); // currentVisor = combat
                     stw     r0, 0x18(r31); // transitioningVisor
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             let default_visor_patch = ppcasm!(symbol_addr!("__ct__12CPlayerStateFR12CInputStream", version) + 0x70, {
                     li      r0, visor;
                     stw     r5, 0x14(r30); // currentVisor = combat
                     stw     r0, 0x18(r30); // transitioningVisor
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             // spawn after elevator
             let default_visor_patch = ppcasm!(symbol_addr!("ResetVisor__12CPlayerStateFv", version), {
                     li      r0, 0;
@@ -9526,7 +9559,7 @@ fn patch_dol<'r>(
                     stw     r0, 0x18(r3); // transitioningVisor
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
         }
 
         let visor_item = match config.starting_visor {
@@ -9557,7 +9590,7 @@ fn patch_dol<'r>(
             let default_visor_patch = ppcasm!(symbol_addr!("__ct__7CPlayerF9TUniqueIdRC12CTransform4fRC6CAABoxUi9CVector3fffffRC13CMaterialList", version) + patch_offset, {
                     li      r0, 0; // r0 = holstered
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
 
             // stop gun from being drawn after unmorphing
             let (patch_offset, patch_offset2) = if version == Version::Pal || version == Version::NtscJ {
@@ -9568,11 +9601,11 @@ fn patch_dol<'r>(
             let default_visor_patch = ppcasm!(symbol_addr!("TransitionFromMorphBallState__7CPlayerFR13CStateManager", version) + patch_offset, {
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             let default_visor_patch = ppcasm!(symbol_addr!("TransitionFromMorphBallState__7CPlayerFR13CStateManager", version) + patch_offset2, {
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
 
             // stop gun from being drawn after unmorphing
             let (patch_offset, patch_offset2) = if version == Version::Pal || version == Version::NtscJ {
@@ -9583,11 +9616,11 @@ fn patch_dol<'r>(
             let default_visor_patch = ppcasm!(symbol_addr!("LeaveMorphBallState__7CPlayerFR13CStateManager", version) + patch_offset, {
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             let default_visor_patch = ppcasm!(symbol_addr!("LeaveMorphBallState__7CPlayerFR13CStateManager", version) + patch_offset2, {
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
 
             // do not change visors after unmorphing
             let patch_offset = if version == Version::Pal || version == Version::NtscJ {
@@ -9600,7 +9633,7 @@ fn patch_dol<'r>(
                     nop;
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
         } else {
             let (patch_offset, patch_offset2) = if version == Version::Pal || version == Version::NtscJ {
                 (0xdc, 0xf0)
@@ -9612,11 +9645,11 @@ fn patch_dol<'r>(
             let default_visor_patch = ppcasm!(symbol_addr!("UpdateVisorState__7CPlayerFRC11CFinalInputfR13CStateManager", version) + patch_offset, {
                     li r4, visor_item;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
             let default_visor_patch = ppcasm!(symbol_addr!("UpdateVisorState__7CPlayerFRC11CFinalInputfR13CStateManager", version) + patch_offset2, {
                     li r4, visor;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
 
             let patch_offset = if version == Version::Pal || version == Version::NtscJ {
                 0xb0
@@ -9629,7 +9662,7 @@ fn patch_dol<'r>(
                     nop;
                     nop;
             });
-            dol_patcher.ppcasm_patch(&default_visor_patch)?;
+            dol_patcher.labeled_ppcasm_patch("starting visor", &default_visor_patch)?;
         }
     }
 
@@ -9638,13 +9671,13 @@ fn patch_dol<'r>(
             li      r0, beam;
             stw     r0, 0x8(r31); // currentBeam
     });
-    dol_patcher.ppcasm_patch(&default_beam_patch)?;
+    dol_patcher.labeled_ppcasm_patch("starting beam", &default_beam_patch)?;
 
     if skip_splash_screens {
         let splash_scren_patch = ppcasm!(symbol_addr!("__ct__13CSplashScreenFQ213CSplashScreen13ESplashScreen", version) + 0x70, {
                 nop;
         });
-        dol_patcher.ppcasm_patch(&splash_scren_patch)?;
+        dol_patcher.labeled_ppcasm_patch("skip splash screens", &splash_scren_patch)?;
     }
 
     // Don't holster weapon when grappling
@@ -9659,7 +9692,7 @@ fn patch_dol<'r>(
         let patch = ppcasm!(symbol_addr!("UpdateGrappleState__7CPlayerFRC11CFinalInputR13CStateManager", version) + shoot_in_grapple_offset, {
             nop;
         });
-        dol_patcher.ppcasm_patch(&patch)?;
+        dol_patcher.labeled_ppcasm_patch("shoot in grapple", &patch)?;
     }
 
     /*
@@ -9770,22 +9803,22 @@ fn patch_dol<'r>(
         let better_teleport_patch = ppcasm!(symbol_addr!("Teleport__7CPlayerFRC12CTransform4fR13CStateManagerb", version) + 0x31C, {
                 nop;
         });
-        dol_patcher.ppcasm_patch(&better_teleport_patch)?;
+        dol_patcher.labeled_ppcasm_patch("smoother teleports", &better_teleport_patch)?;
         // NTSC-U 0-00 (0x80017690 - 0x8001766c)
         let better_teleport_patch = ppcasm!(symbol_addr!("SetSpawnedMorphBallState__7CPlayerFQ27CPlayer21EPlayerMorphBallStateR13CStateManager", version) + 0x24, {
                 nop; // SetCameraState
         });
-        dol_patcher.ppcasm_patch(&better_teleport_patch)?;
+        dol_patcher.labeled_ppcasm_patch("smoother teleports", &better_teleport_patch)?;
         // NTSC-U 0-00 (0x80017770 - 0x8001766c)
         let better_teleport_patch = ppcasm!(symbol_addr!("SetSpawnedMorphBallState__7CPlayerFQ27CPlayer21EPlayerMorphBallStateR13CStateManager", version) + 0x104, {
                 nop; // ForceGunOrientation
         });
-        dol_patcher.ppcasm_patch(&better_teleport_patch)?;
+        dol_patcher.labeled_ppcasm_patch("smoother teleports", &better_teleport_patch)?;
         // NTSC-U 0-00 (0x80017764 - 0x8001766c)
         let better_teleport_patch = ppcasm!(symbol_addr!("SetSpawnedMorphBallState__7CPlayerFQ27CPlayer21EPlayerMorphBallStateR13CStateManager", version) + 0xf8, {
                 nop; // DrawGun
         });
-        dol_patcher.ppcasm_patch(&better_teleport_patch)?;
+        dol_patcher.labeled_ppcasm_patch("smoother teleports", &better_teleport_patch)?;
         // let better_teleport_patch = ppcasm!(symbol_addr!("LeaveMorphBallState__7CPlayerFR13CStateManager", version) + (0x80282ec0 - 0x80282d1c), {
         //         nop; // ForceGunOrientation
         // });
@@ -9805,14 +9838,14 @@ fn patch_dol<'r>(
         let automatic_crash_patch = ppcasm!(symbol_addr!("CrashScreenControllerPollBranch", version) + patch_offset, {
                 nop;
         });
-        dol_patcher.ppcasm_patch(&automatic_crash_patch)?;
+        dol_patcher.labeled_ppcasm_patch("automatic crash screen", &automatic_crash_patch)?;
     }
 
     let cinematic_skip_patch = ppcasm!(symbol_addr!("ShouldSkipCinematic__22CScriptSpecialFunctionFR13CStateManager", version), {
             li      r3, 0x1;
             blr;
     });
-    dol_patcher.ppcasm_patch(&cinematic_skip_patch)?;
+    dol_patcher.labeled_ppcasm_patch("skippable cinematics", &cinematic_skip_patch)?;
 
     // stop doors from communicating with their partner
     // let open_door_patch = ppcasm!(symbol_addr!("OpenDoor__11CScriptDoorF9TUniqueIdR13CStateManager", version) + (0x8007ec70 - 0x8007ea64), {
@@ -9828,7 +9861,7 @@ fn patch_dol<'r>(
             lis     r6, 0xF7FF;
             stw     r6, 0x84(r31);
         });
-        dol_patcher.ppcasm_patch(&unlockables_default_ctor_patch)?;
+        dol_patcher.labeled_ppcasm_patch("unlockables", &unlockables_default_ctor_patch)?;
     } else if version == Version::NtscJ {
         let unlockables_default_ctor_patch = ppcasm!(symbol_addr!("__ct__14CSystemOptionsFv", version) + 0x1bc, {
             li      r6, 100;
@@ -9836,7 +9869,7 @@ fn patch_dol<'r>(
             lis     r6, 0xF7FF;
             stw     r6, 0x668(r31);
         });
-        dol_patcher.ppcasm_patch(&unlockables_default_ctor_patch)?;
+        dol_patcher.labeled_ppcasm_patch("unlockables", &unlockables_default_ctor_patch)?;
     } else {
         let unlockables_default_ctor_patch = ppcasm!(symbol_addr!("__ct__14CSystemOptionsFv", version) + 0x194, {
             li      r6, 100;
@@ -9844,7 +9877,7 @@ fn patch_dol<'r>(
             lis     r6, 0xF7FF;
             stw     r6, 0xd0(r3);
         });
-        dol_patcher.ppcasm_patch(&unlockables_default_ctor_patch)?;
+        dol_patcher.labeled_ppcasm_patch("unlockables", &unlockables_default_ctor_patch)?;
     };
 
     if version == Version::Pal {
@@ -9856,7 +9889,7 @@ fn patch_dol<'r>(
             mr      r3, r29;
             li      r4, 2;
         });
-        dol_patcher.ppcasm_patch(&unlockables_read_ctor_patch)?;
+        dol_patcher.labeled_ppcasm_patch("unlockables", &unlockables_read_ctor_patch)?;
     } else if version == Version::NtscJ {
         let unlockables_read_ctor_patch = ppcasm!(symbol_addr!("__ct__14CSystemOptionsFRC12CInputStream", version) + 0x310, {
             li      r6, 100;
@@ -9866,7 +9899,7 @@ fn patch_dol<'r>(
             mr      r3, r30;
             li      r4, 2;
         });
-        dol_patcher.ppcasm_patch(&unlockables_read_ctor_patch)?;
+        dol_patcher.labeled_ppcasm_patch("unlockables", &unlockables_read_ctor_patch)?;
     } else {
         let unlockables_read_ctor_patch = ppcasm!(symbol_addr!("__ct__14CSystemOptionsFRC12CInputStream", version) + 0x308, {
             li      r6, 100;
@@ -9876,7 +9909,7 @@ fn patch_dol<'r>(
             mr      r3, r29;
             li      r4, 2;
         });
-        dol_patcher.ppcasm_patch(&unlockables_read_ctor_patch)?;
+        dol_patcher.labeled_ppcasm_patch("unlockables", &unlockables_read_ctor_patch)?;
     };
 
     if config.qol_cosmetic {
@@ -9906,7 +9939,7 @@ fn patch_dol<'r>(
                     addi       r3, r1, 20;// arg_14;
                     addi       r4, r1, 12;// arg_C
             });
-            dol_patcher.ppcasm_patch(&missile_hud_formating_patch)?;
+            dol_patcher.labeled_ppcasm_patch("hud formatting", &missile_hud_formating_patch)?;
         }
 
         let powerbomb_hud_formating_patch = ppcasm!(symbol_addr!("SetBombParams__17CHudBallInterfaceFiiibbb", version) + 0x2c, {
@@ -9924,35 +9957,35 @@ fn patch_dol<'r>(
                 bl         { symbol_addr!("sprintf", version) };
 
         });
-        dol_patcher.ppcasm_patch(&powerbomb_hud_formating_patch)?;
+        dol_patcher.labeled_ppcasm_patch("hud formatting", &powerbomb_hud_formating_patch)?;
     }
 
     if version == Version::Pal || version == Version::NtscJ {
         let level_select_mlvl_upper_patch = ppcasm!(symbol_addr!("__sinit_CFrontEndUI_cpp", version) + 0x0c, {
                 lis         r3, {spawn_room.mlvl}@h;
         });
-        dol_patcher.ppcasm_patch(&level_select_mlvl_upper_patch)?;
+        dol_patcher.labeled_ppcasm_patch("level select", &level_select_mlvl_upper_patch)?;
 
         let level_select_mlvl_lower_patch = ppcasm!(symbol_addr!("__sinit_CFrontEndUI_cpp", version) + 0x18, {
                 addi        r0, r3, {spawn_room.mlvl}@l;
         });
-        dol_patcher.ppcasm_patch(&level_select_mlvl_lower_patch)?;
+        dol_patcher.labeled_ppcasm_patch("level select", &level_select_mlvl_lower_patch)?;
     } else {
         let level_select_mlvl_upper_patch = ppcasm!(symbol_addr!("__sinit_CFrontEndUI_cpp", version) + 0x04, {
                 lis         r4, {spawn_room.mlvl}@h;
         });
-        dol_patcher.ppcasm_patch(&level_select_mlvl_upper_patch)?;
+        dol_patcher.labeled_ppcasm_patch("level select", &level_select_mlvl_upper_patch)?;
 
         let level_select_mlvl_lower_patch = ppcasm!(symbol_addr!("__sinit_CFrontEndUI_cpp", version) + 0x10, {
                 addi        r0, r4, {spawn_room.mlvl}@l;
         });
-        dol_patcher.ppcasm_patch(&level_select_mlvl_lower_patch)?;
+        dol_patcher.labeled_ppcasm_patch("level select", &level_select_mlvl_lower_patch)?;
     }
 
     let level_select_mrea_idx_patch = ppcasm!(symbol_addr!("__ct__11CWorldStateFUi", version) + 0x10, {
             li          r0, { spawn_room.mrea_idx };
     });
-    dol_patcher.ppcasm_patch(&level_select_mrea_idx_patch)?;
+    dol_patcher.labeled_ppcasm_patch("level select", &level_select_mrea_idx_patch)?;
 
     if config.nonvaria_heat_damage {
         let heat_damage_patch = ppcasm!(symbol_addr!("ThinkAreaDamage__22CScriptSpecialFunctionFfR13CStateManager", version) + 0x4c, {
@@ -9962,7 +9995,7 @@ fn patch_dol<'r>(
                 cntlzw  r0, r0;
                 nop;
         });
-        dol_patcher.ppcasm_patch(&heat_damage_patch)?;
+        dol_patcher.labeled_ppcasm_patch("non-varia heat damage", &heat_damage_patch)?;
     }
 
     if config.staggered_suit_damage {
@@ -9991,14 +10024,14 @@ fn patch_dol<'r>(
                 .float 0.2;
                 .float 0.5;
         });
-        dol_patcher.ppcasm_patch(&staggered_suit_damage_patch)?;
+        dol_patcher.labeled_ppcasm_patch("staggered suit damage", &staggered_suit_damage_patch)?;
     }
 
     for (pickup_type, value) in &config.item_max_capacity {
         let capacity_patch = ppcasm!(symbol_addr!("CPlayerState_PowerUpMaxValues", version) + pickup_type.kind() * 4, {
             .long *value;
         });
-        dol_patcher.ppcasm_patch(&capacity_patch)?;
+        dol_patcher.labeled_ppcasm_patch("item capacity", &capacity_patch)?;
     }
 
     // set etank capacity and base health
@@ -10008,13 +10041,13 @@ fn patch_dol<'r>(
         .float etank_capacity;
         .float base_health;
     });
-    dol_patcher.ppcasm_patch(&etank_capacity_base_health_patch)?;
+    dol_patcher.labeled_ppcasm_patch("energy tank capacity", &etank_capacity_base_health_patch)?;
 
     if version == Version::NtscU0_02 || version == Version::Pal || version == Version::NtscJ {
         let players_choice_scan_dash_patch = ppcasm!(symbol_addr!("SidewaysDashAllowed__7CPlayerCFffRC11CFinalInputR13CStateManager", version) + 0x3c, {
                 b       { symbol_addr!("SidewaysDashAllowed__7CPlayerCFffRC11CFinalInputR13CStateManager", version) + 0x54 };
         });
-        dol_patcher.ppcasm_patch(&players_choice_scan_dash_patch)?;
+        dol_patcher.labeled_ppcasm_patch("scan dash", &players_choice_scan_dash_patch)?;
     }
 
     // Deprecated
@@ -10100,7 +10133,7 @@ fn patch_dol<'r>(
             nop;
             nop;
         });
-        dol_patcher.ppcasm_patch(&default_game_options_patch)?;
+        dol_patcher.labeled_ppcasm_patch("default game options", &default_game_options_patch)?;
     }
 
     // Multiworld focused patches
@@ -10109,7 +10142,7 @@ fn patch_dol<'r>(
         let incr_pickup_switch_patch = ppcasm!(symbol_addr!("IncrPickUpSwitchCaseData", version) + 21 * 4, {
             .long symbol_addr!("IncrPickUp__12CPlayerStateFQ212CPlayerState9EItemTypei", version) + 25 * 4;
         });
-        dol_patcher.ppcasm_patch(&incr_pickup_switch_patch)?;
+        dol_patcher.labeled_ppcasm_patch("multiworld", &incr_pickup_switch_patch)?;
 
         // Remove DecrPickUp checks for the correct item types
         let decr_pickup_patch = ppcasm!(symbol_addr!("DecrPickUp__12CPlayerStateFQ212CPlayerState9EItemTypei", version) + 5 * 4, {
@@ -10121,11 +10154,11 @@ fn patch_dol<'r>(
             nop;
             nop;
         });
-        dol_patcher.ppcasm_patch(&decr_pickup_patch)?;
+        dol_patcher.labeled_ppcasm_patch("multiworld", &decr_pickup_patch)?;
     }
 
     if let Some(update_hint_state_replacement) = &config.update_hint_state_replacement {
-        dol_patcher.patch(symbol_addr!("UpdateHintState__13CStateManagerFf", version), Cow::from(update_hint_state_replacement.clone()))?;
+        dol_patcher.labeled_patch("hint state replacement", symbol_addr!("UpdateHintState__13CStateManagerFf", version), Cow::from(update_hint_state_replacement.clone()))?;
     }
 
     // Default value is 0.2 on US version and 0.65 on PAL version
@@ -10135,13 +10168,13 @@ fn patch_dol<'r>(
     let max_phazon_damage_lag_before_damaging_patch = ppcasm!(symbol_addr!("g_maxPhazonLagBeforeDamaging", version), {
         .float 0.2;
     });
-    dol_patcher.ppcasm_patch(&max_phazon_damage_lag_before_damaging_patch)?;
+    dol_patcher.labeled_ppcasm_patch("phazon damage", &max_phazon_damage_lag_before_damaging_patch)?;
 
     if config.phazon_damage_modifier != PhazonDamageModifier::Default {
         let phazon_damage_per_sec_patch = ppcasm!(symbol_addr!("g_maxPhazonLagBeforeDamaging", version) + 4, {
             .float config.phazon_damage_per_sec;
         });
-        dol_patcher.ppcasm_patch(&phazon_damage_per_sec_patch)?;
+        dol_patcher.labeled_ppcasm_patch("phazon damage", &phazon_damage_per_sec_patch)?;
 
         let linear_phazon_damage_offset = if version == Version::Pal && version == Version::NtscJ {
             0x558
@@ -10151,7 +10184,7 @@ fn patch_dol<'r>(
         let linear_phazon_damage_patch = ppcasm!(symbol_addr!("UpdatePhazonDamage__7CPlayerFfR13CStateManager", version) + linear_phazon_damage_offset, {
             fmr f2, f0;
         });
-        dol_patcher.ppcasm_patch(&linear_phazon_damage_patch)?;
+        dol_patcher.labeled_ppcasm_patch("phazon damage", &linear_phazon_damage_patch)?;
 
         if config.phazon_damage_modifier == PhazonDamageModifier::Linear {
             let remove_phazon_damage_delay_offset = if version == Version::Pal && version == Version::NtscJ {
@@ -10163,64 +10196,67 @@ fn patch_dol<'r>(
                 nop;
                 nop;
             });
-            dol_patcher.ppcasm_patch(&remove_phazon_damage_delay_patch)?;
+            dol_patcher.labeled_ppcasm_patch("phazon damage", &remove_phazon_damage_delay_patch)?;
         }
     }
 
     // Add rel loader to the binary
-    let (rel_loader_bytes, rel_loader_map_str) = match version {
+    // There's no REL loader build for Trilogy, so patches.rel isn't loaded there
+    let rel_loader = match version {
         Version::NtscU0_00 => {
             let loader_bytes = rel_files::REL_LOADER_100;
             let map_str = rel_files::REL_LOADER_100_MAP;
-            (loader_bytes, map_str)
+            Some((loader_bytes, map_str))
         },
         Version::NtscU0_01 => {
             let loader_bytes = rel_files::REL_LOADER_101;
             let map_str = rel_files::REL_LOADER_101_MAP;
-            (loader_bytes, map_str)
+            Some((loader_bytes, map_str))
         },
         Version::NtscU0_02 => {
             let loader_bytes = rel_files::REL_LOADER_102;
             let map_str = rel_files::REL_LOADER_102_MAP;
-            (loader_bytes, map_str)
+            Some((loader_bytes, map_str))
         },
         Version::NtscK => {
             let loader_bytes = rel_files::REL_LOADER_KOR;
             let map_str = rel_files::REL_LOADER_KOR_MAP;
-            (loader_bytes, map_str)
+            Some((loader_bytes, map_str))
         },
         Version::NtscJ => {
             let loader_bytes = rel_files::REL_LOADER_JPN;
             let map_str = rel_files::REL_LOADER_JPN_MAP;
-            (loader_bytes, map_str)
+            Some((loader_bytes, map_str))
         },
         Version::Pal => {
             let loader_bytes = rel_files::REL_LOADER_PAL;
             let map_str = rel_files::REL_LOADER_PAL_MAP;
-            (loader_bytes, map_str)
+            Some((loader_bytes, map_str))
         },
-        Version::NtscUTrilogy => unreachable!(),
-        Version::NtscJTrilogy => unreachable!(),
-        Version::PalTrilogy => unreachable!(),
+        Version::NtscUTrilogy => None,
+        Version::NtscJTrilogy => None,
+        Version::PalTrilogy => None,
     };
 
-    let mut rel_loader = rel_loader_bytes.to_vec();
-    let rel_loader_padding_size = ((rel_loader.len() + 3) & !3) - rel_loader.len();
-    rel_loader.extend([0; 4][..rel_loader_padding_size].iter().copied());
+    if let Some((rel_loader_bytes, rel_loader_map_str)) = rel_loader {
+        let mut rel_loader = rel_loader_bytes.to_vec();
+        let rel_loader_padding_size = ((rel_loader.len() + 3) & !3) - rel_loader.len();
+        rel_loader.extend([0; 4][..rel_loader_padding_size].iter().copied());
 
-    let rel_loader_map = dol_linker::parse_symbol_table(
-        "extra_assets/rel_loader_1.0?.bin.map".as_ref(),
-        rel_loader_map_str.lines().map(|l| Ok(l.to_owned())),
-    ).map_err(|e| e.to_string())?;
+        let rel_loader_map = dol_linker::parse_symbol_table(
+            "extra_assets/rel_loader_1.0?.bin.map".as_ref(),
+            rel_loader_map_str.lines().map(|l| Ok(l.to_owned())),
+        ).map_err(|e| e.to_string())?;
 
-    let rel_loader_size = rel_loader.len() as u32;
-    new_text_section.extend(rel_loader);
+        let rel_loader_size = rel_loader.len() as u32;
+        new_text_section.extend(rel_loader);
 
-    dol_patcher.ppcasm_patch(&ppcasm!(symbol_addr!("PPCSetFpIEEEMode", version), {
-        b      { rel_loader_map["rel_loader_hook"] };
-    }))?;
+        dol_patcher.labeled_ppcasm_patch("rel loader", &ppcasm!(symbol_addr!("PPCSetFpIEEEMode", version), {
+            b      { rel_loader_map["rel_loader_hook"] };
+        }))?;
 
-    new_text_section_end = new_text_section_end + rel_loader_size;
+        new_text_section_end = new_text_section_end + rel_loader_size;
+    }
 
     // bool __thiscall CGameState::IsMemoryRelayActive(uint object_id, uint mlvl_id)
     let is_memory_relay_active_func = new_text_section_end;
@@ -10282,7 +10318,7 @@ fn patch_dol<'r>(
     let patch_pickup_icon_case = ppcasm!(symbol_addr!("Case1B_Switch_Draw__CMappableObject", version) + ((structs::MapaObjectType::Pickup as u32) - 0x1b) * 4, {
             .long         new_text_section_end;
     });
-    dol_patcher.ppcasm_patch(&patch_pickup_icon_case)?;
+    dol_patcher.labeled_ppcasm_patch("pickup map icons", &patch_pickup_icon_case)?;
 
     // r31 -> CMapWorldDrawParams from CMapWorld::DrawAreas()
     // lwz r4, 0x24(r31) -> IWorld
@@ -10358,7 +10394,7 @@ fn patch_dol<'r>(
         let handle_no_to_save_msg_patch = ppcasm!(symbol_addr!("ThinkSaveStation__22CScriptSpecialFunctionFfR13CStateManager", version) + 0x54, {
                 b         { new_text_section_end };
         });
-        dol_patcher.ppcasm_patch(&handle_no_to_save_msg_patch)?;
+        dol_patcher.labeled_ppcasm_patch("warp to start", &handle_no_to_save_msg_patch)?;
 
         let warp_to_start_patch = ppcasm!(new_text_section_end, {
                 lis       r14, {symbol_addr!("g_Main", version)}@h;
@@ -10390,7 +10426,7 @@ fn patch_dol<'r>(
         let call_compute_spring_ball_movement_patch = ppcasm!(symbol_addr!("ComputeBallMovement__10CMorphBallFRC11CFinalInputR13CStateManagerf", version) + 0x2c, {
                 bl         { new_text_section_end };
         });
-        dol_patcher.ppcasm_patch(&call_compute_spring_ball_movement_patch)?;
+        dol_patcher.labeled_ppcasm_patch("spring ball", &call_compute_spring_ball_movement_patch)?;
 
         // rewrote as tuple to make it cleaner
         let (velocity_offset, movement_state_offset, attached_actor_offset, energy_drain_offset, out_of_water_ticks_offset, surface_restraint_type_offset, morph_ball_offset) = if version == Version::NtscU0_00 || version == Version::NtscU0_01 || version == Version::NtscK
@@ -10543,7 +10579,7 @@ fn patch_dol<'r>(
         let call_leave_morph_ball_patch = ppcasm!(symbol_addr!("UpdateMorphBallTransition__7CPlayerFfR13CStateManager", version) + call_leave_morph_ball_offset, {
                 bl         { new_text_section_end };
         });
-        dol_patcher.ppcasm_patch(&call_leave_morph_ball_patch)?;
+        dol_patcher.labeled_ppcasm_patch("spring ball", &call_leave_morph_ball_patch)?;
 
         let spring_ball_cooldown_reset_on_unmorph_patch = ppcasm!(new_text_section_end, {
                 // stack init (at +0x000)
@@ -10583,7 +10619,7 @@ fn patch_dol<'r>(
         let call_enter_morph_ball_patch = ppcasm!(symbol_addr!("UpdateMorphBallTransition__7CPlayerFfR13CStateManager", version) + call_enter_morph_ball_offset, {
                 bl         { new_text_section_end };
         });
-        dol_patcher.ppcasm_patch(&call_enter_morph_ball_patch)?;
+        dol_patcher.labeled_ppcasm_patch("spring ball", &call_enter_morph_ball_patch)?;
 
         let spring_ball_cooldown_reset_on_morph_patch = ppcasm!(new_text_section_end, {
                 // stack init (at +0x000)
@@ -10625,7 +10661,7 @@ fn patch_dol<'r>(
         let switch_case_ice_trap_patch = ppcasm!(symbol_addr!("Case0_Switch_AcceptScriptMsg_CScriptSpecialFunction", version) + 33 * 4, {
                 .long     new_text_section_end;
         });
-        dol_patcher.ppcasm_patch(&switch_case_ice_trap_patch)?;
+        dol_patcher.labeled_ppcasm_patch("ice traps", &switch_case_ice_trap_patch)?;
 
         if version == Version::NtscJ || version == Version::Pal {
             let ice_trap_special_func_patch = ppcasm!(new_text_section_end, {
@@ -10743,13 +10779,13 @@ fn patch_dol<'r>(
             nop;
         });
 
-        dol_patcher.ppcasm_patch(&remove_check_1_patch)?;
+        dol_patcher.labeled_ppcasm_patch("ridley chest vulnerability", &remove_check_1_patch)?;
 
         let remove_check_2_patch = ppcasm!(cridley_acceptscriptmsg_addr + 0x840, {
             nop;
         });
 
-        dol_patcher.ppcasm_patch(&remove_check_2_patch)?;
+        dol_patcher.labeled_ppcasm_patch("ridley chest vulnerability", &remove_check_2_patch)?;
 
         let restore_original_check_patch = ppcasm!(cridley_acceptscriptmsg_addr + 0x884, {
             beq {cridley_acceptscriptmsg_addr + 0x88C};
@@ -10759,7 +10795,7 @@ fn patch_dol<'r>(
             nop;
         });
 
-        dol_patcher.ppcasm_patch(&restore_original_check_patch)?;
+        dol_patcher.labeled_ppcasm_patch("ridley chest vulnerability", &restore_original_check_patch)?;
 
         let restore_original_check_code_cave_patch = ppcasm!(new_text_section_end, {
             lbz r0, 0x0140(r3);
@@ -10782,14 +10818,22 @@ fn patch_dol<'r>(
     dol_patcher.add_text_segment(new_text_section_start, Cow::Owned(new_text_section))?;
 
     // move the ram after the newly added sections (if there are any)
-    dol_patcher.ppcasm_patch(&ppcasm!(symbol_addr!("OSInit", version) + 0xe0, {
+    dol_patcher.labeled_ppcasm_patch("arena start", &ppcasm!(symbol_addr!("OSInit", version) + 0xe0, {
             lis        r3, { new_text_section_end + 0x10000 }@h;
         }))?;
 
-    dol_patcher.ppcasm_patch(&ppcasm!(symbol_addr!("OSInit", version) + 0x118, {
+    dol_patcher.labeled_ppcasm_patch("arena start", &ppcasm!(symbol_addr!("OSInit", version) + 0x118, {
             lis        r3, { new_text_section_end + 0x10000 }@h;
         }))?;
 
+    if let Some(listing_filename) = config.dol_patch_listing.as_ref() {
+        let version_name = version.to_string();
+        let listing = dol_patcher.patch_report() + "\n"
+            + &dol_patcher.patch_listing(&|addr| dol_symbol_table::symbol_name(&version_name, addr));
+        fs::write(listing_filename, listing)
+            .map_err(|e| format!("Failed to write DOL patch listing: {}", e))?;
+    }
+
     *file = structs::FstEntryFile::ExternalFile(Box::new(dol_patcher));
     Ok(())
 }
@@ -14377,7 +14421,7 @@ fn patch_arboretum_sandstone<'a>(patcher: &mut PrimePatcher<'_, 'a>)
     });
 }
 
-pub fn patch_iso<T>(config: PatchConfig, mut pn: T) -> Result<(), String>
+pub fn patch_iso<T>(config: PatchConfig<'_>, mut pn: T) -> Result<(), String>
     where T: structs::ProgressNotifier
 {
     let mut audio_override_patches: Vec<AudioOverridePatch> = Vec::new();
@@ -14427,7 +14471,8 @@ pub fn patch_iso<T>(config: PatchConfig, mut pn: T) -> Result<(), String>
 
     let mut ct = Vec::new();
     let mut reader = Reader::new(&config.input_iso[..]);
-    let mut gc_disc: structs::GcDisc = reader.read(());
+    let mut gc_disc: structs::GcDisc = reader.try_read(())
+        .map_err(|e| format!("Failed to parse the input ISO: {}", e))?;
 
     if gc_disc.find_file("randomprime.txt").is_some() {
         Err(concat!("The input ISO has already been randomized once before. ",
@@ -14441,10 +14486,24 @@ pub fn patch_iso<T>(config: PatchConfig, mut pn: T) -> Result<(), String>
     } else if config.run_mode == RunMode::ExportAssets {
         export_assets(&mut gc_disc, &config)?;
         return Ok(());
+    } else if config.run_mode == RunMode::ExportStrg {
+        export_strgs(&gc_disc, config.strg_filename.as_deref().unwrap_or("strg.json"))?;
+        return Ok(());
+    } else if config.run_mode == RunMode::ExportScly {
+        export_scly(&gc_disc, config.scly_filename.as_deref().unwrap_or("scly.json"), &config.scly_rooms)?;
+        return Ok(());
     }
 
     build_and_run_patches(&mut gc_disc, &config, audio_override_patches)?;
 
+    if let Some(save_file) = config.save_file.as_ref() {
+        write_save_file(&gc_disc, save_file, &config)?;
+    }
+
+    if let Some(memory_map_filename) = config.memory_map_filename.as_ref() {
+        export_memory_map(&config, memory_map_filename)?;
+    }
+
     {
         let json_string = serde_json::to_string(&config)
             .map_err(|e| format!("Failed to serialize patch config: {}", e))?;
@@ -14473,10 +14532,18 @@ pub fn patch_iso<T>(config: PatchConfig, mut pn: T) -> Result<(), String>
     match config.iso_format {
         IsoFormat::Iso => {
             let mut file = config.output_iso;
-            file.set_len(structs::GC_DISC_LENGTH as u64)
-                .map_err(|e| format!("Failed to resize output file: {}", e))?;
             gc_disc.write(&mut file, &mut pn)
                 .map_err(|e| format!("Error writing output file: {}", e))?;
+            // Pad the image out to a full disc
+            let disc_len = structs::GC_DISC_LENGTH as u64;
+            let res: io::Result<()> = (|| {
+                if file.seek(SeekFrom::End(0))? < disc_len {
+                    file.seek(SeekFrom::Start(disc_len - 1))?;
+                    file.write_all(&[0])?;
+                }
+                Ok(())
+            })();
+            res.map_err(|e| format!("Failed to resize output file: {}", e))?;
             pn.notify_flushing_to_disk();
         },
         IsoFormat::Gcz => {
@@ -14524,7 +14591,8 @@ fn export_logbook(gc_disc: &mut structs::GcDisc, config: &PatchConfig)
         let file_entry = gc_disc.find_file(f).unwrap();
         let pak = match *file_entry.file().unwrap() {
             structs::FstEntryFile::Pak(ref pak) => pak.clone(),
-            structs::FstEntryFile::Unknown(ref reader) => reader.clone().read(()),
+            structs::FstEntryFile::Unknown(ref reader) => reader.clone().try_read(())
+                .map_err(|e| format!("Failed to parse {}: {}", f, e))?,
             _ => panic!(),
         };
 
@@ -14536,6 +14604,8 @@ fn export_logbook(gc_disc: &mut structs::GcDisc, config: &PatchConfig)
             };
 
             let mut res = res.into_owned();
+            res.kind.try_guess_kind()
+                .map_err(|e| format!("Failed to parse STRG 0x{:08X} in {}: {}", res.file_id, f, e))?;
             let strg = res.kind.as_strg_mut().unwrap();
             let string_table = strg.string_tables.as_mut_vec()[0].strings.as_mut_vec();
             if string_table.len() != 3 {
@@ -14779,17 +14849,21 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
 
     let show_starting_memo = config.starting_memo.is_some();
 
-    let starting_memo = {
-        if config.starting_memo.is_some() {
-            Some(config.starting_memo.as_ref().unwrap().as_str())
-        } else {
-            None
-        }
+    let starting_memo = config.starting_memo.as_ref();
+
+    let mut enemy_randomizer = match config.enemy_randomizer.as_ref() {
+        Some(enemy_randomizer_config) => Some(plan_enemy_randomizer(gc_disc, enemy_randomizer_config, config.seed)?),
+        None => None,
     };
 
-    let (game_resources, pickup_hudmemos, pickup_scans, extra_scans, savw_scans_to_add, local_savw_scans_to_add, savw_scan_logbook_category, extern_models, _) =
+    let (mut game_resources, pickup_hudmemos, pickup_scans, extra_scans, savw_scans_to_add, local_savw_scans_to_add, savw_scan_logbook_category, extern_models, _) =
         collect_game_resources(gc_disc, starting_memo, &config)?;
 
+    if let Some(enemy_randomizer) = enemy_randomizer.as_mut() {
+        game_resources.extend(enemy_randomizer.take_resources());
+    }
+    let enemy_randomizer = &enemy_randomizer;
+
     let extern_models = &extern_models;
     let game_resources = &game_resources;
     let pickup_hudmemos = &pickup_hudmemos;
@@ -14910,8 +14984,15 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
     let n = format!("Video/04_fileselect_playgame_{}.thp", select_game_fmv_suffix);
     let file_select_play_game_fmv = gc_disc.find_file(&n).unwrap().file().unwrap().clone();
 
+    let vanilla_strgs = vanilla_strg_languages(gc_disc, &config.strg_translation)?;
+    let vanilla_strgs = &vanilla_strgs;
+
     let mut patcher = PrimePatcher::new();
 
+    if let Some(dir) = config.scly_graph_dir.as_deref() {
+        add_scly_graph_patches(&mut patcher, dir, config.scly_graph_format, &config.scly_rooms, "before");
+    }
+
     // Add the freeze effect assets required by CPlayer::Freeze()
     if config.enable_ice_traps {
         patcher.add_file_patch(
@@ -14928,6 +15009,7 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
 
     patcher.add_file_patch(b"opening.bnr", |file| patch_bnr(file, &config.game_banner));
 
+    #[cfg(feature = "trilogy-music")]
     if let Some(flaahgra_music_files) = &config.flaahgra_music_files {
         const MUSIC_FILE_NAME: &[&[u8]] = &[
             b"Audio/rui_flaaghraR.dsp",
@@ -15436,6 +15518,19 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
                             }
                         }
 
+                        if let Some(add_objects) = room.add_objects.as_ref() {
+                            for config in add_objects {
+                                patcher.add_scly_patch(
+                                    (pak_name.as_bytes(), room_info.room_id.to_u32()),
+                                    move |ps, area| patch_add_object(
+                                        ps,
+                                        area,
+                                        config.clone(),
+                                    ),
+                                );
+                            }
+                        }
+
                         if room.streamed_audios.is_some() {
                             for config in room.streamed_audios.as_ref().unwrap() {
                                 patcher.add_scly_patch(
@@ -16341,7 +16436,7 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
         );
     }
 
-    let rel_config = create_rel_config_file(starting_room, config.quickplay);
+    let rel_config = create_rel_config_file(starting_room, config)?;
 
     if skip_frigate {
         // remove frigate data to save time/space
@@ -16424,7 +16519,7 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
     if config.results_string.is_some() {
         patcher.add_resource_patch(
             resource_info!("STRG_CompletionScreen.STRG").into(),
-            |res| patch_completion_screen(res, config.results_string.clone().unwrap(), config.version)
+            |res| patch_completion_screen(res, config.results_string.as_ref().unwrap(), config.version)
         );
     }
 
@@ -17162,6 +17257,15 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
         }
     }
 
+    for table in config.strg_translation.iter() {
+        for pak in paks.iter() {
+            patcher.add_resource_patch(
+                (&[pak.as_bytes()], table.id, FourCC::from_bytes(b"STRG")),
+                move |res| patch_translate_strg(res, &table.languages, &vanilla_strgs[&table.id])
+            );
+        }
+    }
+
     // Change the missile refill text if it also refills ammo
     if config.missile_station_pb_refill {
         let id: u32 = 2871382149;
@@ -17238,6 +17342,28 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
         }
     }
 
+    if let Some(enemy_randomizer) = enemy_randomizer.as_ref() {
+        for (pak_name, mrea_id) in enemy_randomizer.rooms() {
+            let pak_name = *pak_name;
+            patcher.add_scly_patch(
+                (pak_name.as_bytes(), *mrea_id),
+                move |ps, area| patch_randomize_enemies(ps, area, game_resources, enemy_randomizer, pak_name)
+            );
+        }
+    }
+
+    if !config.enemy_stats.is_empty() {
+        for (pak_name, rooms) in pickup_meta::ROOM_INFO.iter() {
+            let world = World::from_pak(pak_name).unwrap().to_json_key();
+            for room_info in rooms.iter() {
+                patcher.add_scly_patch(
+                    (pak_name.as_bytes(), room_info.room_id.to_u32()),
+                    move |ps, area| patch_enemy_stats(ps, area, &config.enemy_stats, world)
+                );
+            }
+        }
+    }
+
     if config.disable_item_loss && !skip_frigate {
         patcher.add_scly_patch(
             resource_info!("02_intro_elevator.MREA").into(),
@@ -17420,8 +17546,15 @@ fn build_and_run_patches<'r>(gc_disc: &mut structs::GcDisc<'r>, config: &PatchCo
         );
     }
 
+    if let Some(dir) = config.scly_graph_dir.as_deref() {
+        add_scly_graph_patches(&mut patcher, dir, config.scly_graph_format, &config.scly_rooms, "after");
+    }
+
+    // There's no clock on wasm32-unknown-unknown, Instant::now() panics there
+    #[cfg(not(target_arch = "wasm32"))]
     let time = Instant::now();
     patcher.run(gc_disc)?;
+    #[cfg(not(target_arch = "wasm32"))]
     println!("Created patches in {:?}", time.elapsed());
 
     Ok(())
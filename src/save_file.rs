@@ -0,0 +1,628 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use reader_writer::{Reader, Writable};
+use resource_info_table::resource_info;
+use structs::{Gci, GciHeader, TxtrFormat, TxtrPaletteFormat, GCI_BLOCK_SIZE};
+
+use crate::{
+    elevators::{SpawnRoomData, World},
+    patch_config::{PatchConfig, SaveFileConfig, Version},
+    pickup_meta::PickupType,
+    starting_items::StartingItems,
+    GcDiscLookupExtensions,
+    ResourceData,
+};
+
+/*
+    Builds a memory card save for the game on the disc. The layout mirrors what the game's own
+    memory card code reads and writes:
+
+    gci header (card directory entry)
+    card file:
+        u32       CRC32 of the rest of the file
+        [u8; 64]  comment
+        banner/icon images
+        save buffer (3004 bytes):
+            u32        version
+            [bool; 3]  which slots are present
+            [u8; 174]  system (persistent) options
+            [u8; 940]  bit-packed game state, for each present slot
+
+    The game state can only be decoded against the MLVL/SAVW of every world, so the save must
+    be built from the same (patched) disc it will be loaded with.
+*/
+
+const SAVE_FILENAME: &[u8] = b"MetroidPrime A";
+const COMMENT_SIZE: usize = 64;
+const SAVE_BUFFER_SIZE: usize = 3004;
+const SYSTEM_DATA_SIZE: usize = 174;
+const GAME_STATE_SIZE: usize = 940;
+const SECONDS_2000_01_01: u64 = 946684800;
+
+// Indexed by CPlayerState::EItemType (i.e. `PickupType::kind`)
+const POWERUP_MAX_VALUES: [u32; 41] = [
+    1, 1, 1, 1, 250, 1, 1, 8, 255, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 14, 1, 0, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1,
+];
+const POWER_SUIT_INDEX: usize = 20;
+
+// Everything the game derives from a world's MLVL and SAVW when (de)serializing its state
+struct SaveWorld
+{
+    world: World,
+    area_count: u32,
+    area_layers: Vec<(u32, u64)>, // (layer count, default active layer flags)
+    memory_relays: Vec<u32>,
+    doors: Vec<u32>,
+    scans: Vec<(u32, u32)>, // (scan id, logbook category)
+}
+
+// MSB-first bit packer matching the game's CBitStreamWriter
+struct BitStreamWriter
+{
+    bytes: Vec<u8>,
+    bit_len: usize,
+}
+
+impl BitStreamWriter
+{
+    fn new() -> Self
+    {
+        BitStreamWriter {
+            bytes: Vec::new(),
+            bit_len: 0,
+        }
+    }
+
+    fn write_bits(&mut self, value: u32, bit_count: u32)
+    {
+        for i in (0..bit_count).rev() {
+            if self.bit_len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.bit_len % 8);
+            }
+            self.bit_len += 1;
+        }
+    }
+
+    fn write_bool(&mut self, value: bool)
+    {
+        self.write_bits(value as u32, 1);
+    }
+}
+
+// Number of bits needed to store values up to `max_value`
+fn bit_count(max_value: u32) -> u32
+{
+    32 - max_value.leading_zeros()
+}
+
+// Same parameters as the game's CCRC32 (reflected polynomial, no final xor)
+fn crc32(data: &[u8]) -> u32
+{
+    let mut crc = 0xFFFFFFFFu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+fn read_save_world(gc_disc: &structs::GcDisc, world: World) -> Result<SaveWorld, String>
+{
+    let pak_name = world.to_pak_str();
+
+    let mlvl_res = gc_disc.find_resource(pak_name, |res| res.file_id == world.mlvl() && res.fourcc() == b"MLVL".into())
+        .ok_or_else(|| format!("Failed to find MLVL 0x{:08X} in {}", world.mlvl(), pak_name))?;
+    let mlvl = mlvl_res.kind.as_mlvl().unwrap();
+    let savw_id = mlvl.world_savw.to_u32();
+    let area_layers = mlvl.area_layer_flags.iter()
+        .map(|flags| (flags.layer_count, flags.flags))
+        .collect();
+
+    let savw_res = gc_disc.find_resource(pak_name, |res| res.file_id == savw_id && res.fourcc() == b"SAVW".into())
+        .ok_or_else(|| format!("Failed to find SAVW 0x{:08X} in {}", savw_id, pak_name))?;
+    let savw = savw_res.kind.as_savw().unwrap();
+
+    Ok(SaveWorld {
+        world,
+        area_count: savw.area_count,
+        area_layers,
+        memory_relays: savw.memory_relay_array.iter().collect(),
+        doors: savw.door_array.iter().collect(),
+        scans: savw.scan_array.iter().map(|scan| (scan.scan.to_u32(), scan.logbook_category)).collect(),
+    })
+}
+
+fn read_hint_count(gc_disc: &structs::GcDisc) -> Result<usize, String>
+{
+    let hint_res = gc_disc.find_resource("NoARAM.pak", |res| res.fourcc() == b"HINT".into())
+        .ok_or_else(|| "Failed to find HINT in NoARAM.pak".to_string())?;
+    let hint = hint_res.kind.as_hint().unwrap();
+    Ok(hint.hints.len())
+}
+
+// The top mip level and, for C8 textures, the RGB5A3 palette
+type CardTexture = (Vec<u8>, Option<Vec<u8>>);
+
+fn read_card_texture(gc_disc: &structs::GcDisc, res_id: u32) -> Result<CardTexture, String>
+{
+    let res = gc_disc.find_resource("NoARAM.pak", |res| res.file_id == res_id && res.fourcc() == b"TXTR".into())
+        .ok_or_else(|| format!("Failed to find TXTR 0x{:08X} in NoARAM.pak", res_id))?;
+    let res_data = ResourceData::new(&res);
+    let data = res_data.decompress().into_owned();
+    let txtr: structs::Txtr = Reader::new(&data[..]).read(());
+
+    let pixels: Vec<u8> = txtr.pixel_data.iter().next()
+        .ok_or_else(|| format!("TXTR 0x{:08X} has no image data", res_id))?
+        .iter().map(|byte| *byte).collect();
+    match txtr.format {
+        TxtrFormat::C8(TxtrPaletteFormat::Rgb5A3, palette) => Ok((pixels, Some(palette[..].to_vec()))),
+        TxtrFormat::Rgb5A3 => Ok((pixels, None)),
+        format => Err(format!("TXTR 0x{:08X} has format {:?}, which can't be used on a memory card", res_id, format)),
+    }
+}
+
+fn powerup_amounts(items: &StartingItems, artifacts: &[String]) -> Result<[u32; 41], String>
+{
+    let mut amounts = [0u32; 41];
+    let mut set = |pickup_type: PickupType, amount: u32| amounts[pickup_type.kind() as usize] = amount;
+
+    set(PickupType::PowerBeam, items.power_beam as u32);
+    set(PickupType::IceBeam, items.ice as u32);
+    set(PickupType::WaveBeam, items.wave as u32);
+    set(PickupType::PlasmaBeam, items.plasma as u32);
+    set(PickupType::Missile, items.missiles.max(0) as u32);
+    set(PickupType::ScanVisor, items.scan_visor as u32);
+    set(PickupType::MorphBallBomb, items.bombs as u32);
+    set(PickupType::PowerBomb, items.power_bombs.max(0) as u32);
+    set(PickupType::Flamethrower, items.flamethrower as u32);
+    set(PickupType::ThermalVisor, items.thermal_visor as u32);
+    set(PickupType::ChargeBeam, items.charge as u32);
+    set(PickupType::SuperMissile, items.super_missile as u32);
+    set(PickupType::GrappleBeam, items.grapple as u32);
+    set(PickupType::XRayVisor, items.xray as u32);
+    set(PickupType::IceSpreader, items.ice_spreader as u32);
+    set(PickupType::SpaceJumpBoots, items.space_jump as u32);
+    set(PickupType::MorphBall, items.morph_ball as u32);
+    set(PickupType::CombatVisor, items.combat_visor as u32);
+    set(PickupType::BoostBall, items.boost_ball as u32);
+    set(PickupType::SpiderBall, items.spider_ball as u32);
+    set(PickupType::GravitySuit, items.gravity_suit as u32);
+    set(PickupType::VariaSuit, items.varia_suit as u32);
+    set(PickupType::PhazonSuit, items.phazon_suit as u32);
+    set(PickupType::EnergyTank, items.energy_tanks.max(0) as u32);
+    set(PickupType::Wavebuster, items.wavebuster as u32);
+    amounts[POWER_SUIT_INDEX] = 1;
+
+    for artifact in artifacts {
+        let kind = PickupType::from_str(artifact).kind() as usize;
+        if !(29..=40).contains(&kind) {
+            Err(format!("'{}' is not an artifact", artifact))?
+        }
+        amounts[kind] = 1;
+    }
+
+    for (i, (amount, max)) in amounts.iter().zip(POWERUP_MAX_VALUES.iter()).enumerate() {
+        if amount > max {
+            Err(format!("Save file item #{} has amount {}, but at most {} can be saved", i, amount, max))?
+        }
+    }
+
+    Ok(amounts)
+}
+
+fn build_game_state(
+    save_file: &SaveFileConfig,
+    items: &StartingItems,
+    worlds: &[SaveWorld],
+    hint_count: usize,
+    timestamp: u32,
+)
+    -> Result<Vec<u8>, String>
+{
+    let spawn = SpawnRoomData::from_str(&save_file.room);
+    let map_all = save_file.map_all.unwrap_or(false);
+
+    let mut w = BitStreamWriter::new();
+
+    /* CGameState */
+    for _ in 0..128 {
+        w.write_bits(0, 8); // unused by the game on load
+    }
+    w.write_bits(timestamp, 32);
+    w.write_bool(save_file.hard_mode.unwrap_or(false));
+    w.write_bool(false); // don't re-initialize the inventory at the first spawn point
+    w.write_bits(spawn.mlvl, 32);
+    let play_time = save_file.play_time.unwrap_or(0.0).to_bits();
+    w.write_bits((play_time >> 32) as u32, 32); // most significant half first
+    w.write_bits(play_time as u32, 32);
+
+    /* CPlayerState */
+    let amounts = powerup_amounts(items, save_file.artifacts.as_deref().unwrap_or(&[]))?;
+    let health = 99.0f32 + 100.0 * amounts[PickupType::EnergyTank.kind() as usize] as f32;
+    let suit = if items.phazon_suit {
+        3
+    } else if items.gravity_suit {
+        1
+    } else if items.varia_suit {
+        2
+    } else {
+        0
+    };
+    w.write_bits(0, 32); // enabled items
+    w.write_bits(health.to_bits(), 32);
+    w.write_bits(0, bit_count(5)); // power beam selected
+    w.write_bits(suit, bit_count(4));
+    for (amount, max) in amounts.iter().zip(POWERUP_MAX_VALUES.iter()) {
+        w.write_bits(*amount, bit_count(*max)); // amount
+        w.write_bits(*amount, bit_count(*max)); // capacity
+    }
+
+    // The game tracks every scan from every SAVW, sorted by asset id
+    let mut scan_list: Vec<(u32, u32)> = worlds.iter().flat_map(|world| world.scans.iter().cloned()).collect();
+    scan_list.sort_by_key(|(id, _)| *id);
+    let all_scans = save_file.all_scans.unwrap_or(false);
+    let scans: HashSet<u32> = save_file.scans.iter().flatten().cloned().collect();
+    for id in scans.iter() {
+        if !scan_list.iter().any(|(scan_id, _)| scan_id == id) {
+            Err(format!("Scan 0x{:08X} is not listed in any world's SAVW", id))?
+        }
+    }
+    let mut logbook_scanned = 0;
+    let mut logbook_total = 0;
+    for (id, category) in scan_list.iter() {
+        let scanned = all_scans || scans.contains(id);
+        w.write_bool(scanned);
+        if *category != 0 {
+            logbook_total += 1;
+            logbook_scanned += scanned as u32;
+        }
+    }
+    w.write_bits(logbook_scanned, bit_count(0x100));
+    w.write_bits(logbook_total, bit_count(0x100));
+
+    /* CGameOptions (defaults) */
+    for _ in 0..64 {
+        w.write_bool(false);
+    }
+    w.write_bits(1, 2); // stereo
+    w.write_bits(4, 4); // screen brightness
+    w.write_bits(0, 6); // screen x offset
+    w.write_bits(0, 6); // screen y offset
+    w.write_bits(0, 5); // screen stretch
+    w.write_bits(0x7F, 7); // sfx volume
+    w.write_bits(0x7F, 7); // music volume
+    w.write_bits(0xFF, 8); // hud alpha
+    w.write_bits(0xFF, 8); // helmet alpha
+    w.write_bool(true); // hud lag
+    w.write_bool(false); // invert y
+    w.write_bool(true); // rumble
+    w.write_bool(false); // swap beam controls
+    w.write_bool(true); // hint system
+
+    /* CHintOptions */
+    for _ in 0..hint_count {
+        w.write_bits(0, 2); // not yet displayed
+        w.write_bits(0.0f32.to_bits(), 32);
+    }
+
+    /* CWorldState, for every world */
+    let mut layer_overrides: HashMap<(u32, u32), HashMap<u32, bool>> = HashMap::new();
+    for (room_name, layers) in save_file.layers.iter().flatten() {
+        let room = SpawnRoomData::from_str(room_name);
+        layer_overrides.insert((room.mlvl, room.mrea_idx), layers.clone());
+    }
+
+    let mut active_relays: HashMap<u32, HashSet<u32>> = HashMap::new();
+    for (world_name, relays) in save_file.memory_relays.iter().flatten() {
+        let world = World::from_json_key(world_name);
+        let save_world = worlds.iter().find(|save_world| save_world.world == world).unwrap();
+        for relay in relays {
+            if !save_world.memory_relays.contains(relay) {
+                Err(format!("Memory relay 0x{:X} is not listed in the SAVW for {}", relay, world_name))?
+            }
+        }
+        active_relays.insert(world.mlvl(), relays.iter().cloned().collect());
+    }
+
+    for save_world in worlds {
+        let mlvl = save_world.world.mlvl();
+
+        if mlvl == spawn.mlvl {
+            w.write_bits(spawn.mrea_idx, 32);
+            w.write_bits(spawn.mrea, 32);
+        } else {
+            w.write_bits(0, 32);
+            w.write_bits(0xFFFFFFFF, 32);
+        }
+
+        // CRelayTracker
+        let relays = active_relays.get(&mlvl);
+        for relay in save_world.memory_relays.iter() {
+            w.write_bool(relays.map(|relays| relays.contains(relay)).unwrap_or(false));
+        }
+
+        // CMapWorldInfo
+        for _ in 0..save_world.area_count {
+            w.write_bool(map_all); // visited
+        }
+        for _ in 0..save_world.area_count {
+            w.write_bool(map_all); // mapped
+        }
+        for _ in save_world.doors.iter() {
+            w.write_bool(map_all);
+        }
+
+        // CWorldLayerState, layer 0 is always active and isn't saved
+        let total_layers: u32 = save_world.area_layers.iter().map(|(count, _)| count.saturating_sub(1)).sum();
+        w.write_bits(total_layers, 10);
+        for (area_idx, (count, flags)) in save_world.area_layers.iter().enumerate() {
+            let overrides = layer_overrides.remove(&(mlvl, area_idx as u32));
+            if let Some(overrides) = overrides.as_ref() {
+                for layer in overrides.keys() {
+                    if *layer == 0 || layer >= count {
+                        Err(format!("Layer {} can't be set in area #{} of {}, which has {} layers",
+                            layer, area_idx, save_world.world.to_json_key(), count))?
+                    }
+                }
+            }
+            for layer in 1..*count {
+                let active = overrides.as_ref()
+                    .and_then(|overrides| overrides.get(&layer).cloned())
+                    .unwrap_or(flags & (1 << layer) != 0);
+                w.write_bool(active);
+            }
+        }
+    }
+
+    for _ in worlds {
+        w.write_bool(map_all); // map station used
+    }
+
+    if w.bytes.len() > GAME_STATE_SIZE {
+        Err(format!("Game state is {} bytes, but only {} fit in a save slot", w.bytes.len(), GAME_STATE_SIZE))?
+    }
+    let mut bytes = w.bytes;
+    bytes.resize(GAME_STATE_SIZE, 0);
+    Ok(bytes)
+}
+
+// Lays out the card file behind its directory entry. The CRC32 at the start of the file covers
+// everything after it, including the padding up to the end of the last block.
+fn build_gci(
+    game_id: &[u8; 6],
+    timestamp: u32,
+    banner: &CardTexture,
+    icons: &[CardTexture],
+    game_state: &[u8],
+    slot: usize,
+)
+    -> (GciHeader, Vec<u8>)
+{
+    let mut data = vec![0u8; 4]; // CRC32, filled in last
+
+    let mut comment = [0u8; COMMENT_SIZE];
+    let title = b"Metroid Prime";
+    let subtitle = b"randomprime";
+    comment[..title.len()].copy_from_slice(title);
+    comment[32..32 + subtitle.len()].copy_from_slice(subtitle);
+    data.extend_from_slice(&comment);
+
+    let image_offset = data.len() as u32;
+    let (banner, banner_palette) = banner;
+    let banner_format = if banner_palette.is_some() {
+        GciHeader::BANNER_FORMAT_C8
+    } else {
+        GciHeader::BANNER_FORMAT_RGB5A3
+    };
+    data.extend_from_slice(banner);
+    data.extend_from_slice(banner_palette.as_deref().unwrap_or(&[]));
+
+    let mut icon_format = 0u16;
+    let mut animation_speed = 0u16;
+    let mut icon_palette = None;
+    for (i, (icon, palette)) in icons.iter().enumerate() {
+        let format = if palette.is_some() {
+            GciHeader::ICON_FORMAT_C8_SHARED
+        } else {
+            GciHeader::ICON_FORMAT_RGB5A3
+        };
+        icon_format |= format << (i * 2);
+        animation_speed |= GciHeader::ANIMATION_SPEED_MIDDLE << (i * 2);
+        data.extend_from_slice(icon);
+        if palette.is_some() {
+            icon_palette = palette.as_ref();
+        }
+    }
+    data.extend_from_slice(icon_palette.map(|palette| &palette[..]).unwrap_or(&[]));
+
+    let save_buffer_start = data.len();
+    data.extend_from_slice(&0u32.to_be_bytes()); // version
+    for i in 0..3 {
+        data.push((i == slot) as u8);
+    }
+    data.extend_from_slice(&[0u8; SYSTEM_DATA_SIZE]);
+    data.extend_from_slice(game_state);
+    data.resize(save_buffer_start + SAVE_BUFFER_SIZE, 0);
+
+    let block_count = data.len().div_ceil(GCI_BLOCK_SIZE);
+    data.resize(block_count * GCI_BLOCK_SIZE, 0);
+    let crc = crc32(&data[4..]);
+    data[..4].copy_from_slice(&crc.to_be_bytes());
+
+    let mut filename = [0u8; 32];
+    filename[..SAVE_FILENAME.len()].copy_from_slice(SAVE_FILENAME);
+    let header = GciHeader {
+        game_code: game_id[..4].iter().cloned().collect(),
+        maker_code: game_id[4..].iter().cloned().collect(),
+        banner_format,
+        filename: filename.iter().cloned().collect(),
+        modified_time: timestamp,
+        image_offset,
+        icon_format,
+        animation_speed,
+        permissions: GciHeader::PERMISSION_PUBLIC,
+        copy_counter: 0,
+        first_block: 0,
+        block_count: block_count as u16,
+        comment_offset: 4,
+    };
+    (header, data)
+}
+
+pub fn write_save_file(gc_disc: &structs::GcDisc, save_file: &SaveFileConfig, config: &PatchConfig)
+    -> Result<(), String>
+{
+    if config.version == Version::NtscUTrilogy || config.version == Version::NtscJTrilogy || config.version == Version::PalTrilogy {
+        Err("Save files can only be created for GameCube versions of the game")?
+    }
+
+    let slot = save_file.slot.unwrap_or(0) as usize;
+    if slot > 2 {
+        Err(format!("Save slot must be 0, 1 or 2, not {}", slot))?
+    }
+
+    // Worlds are stored in the order of their MLVL asset ids
+    let mut worlds = World::iter()
+        .map(|world| read_save_world(gc_disc, world))
+        .collect::<Result<Vec<_>, _>>()?;
+    worlds.sort_by_key(|save_world| save_world.world.mlvl());
+
+    let hint_count = read_hint_count(gc_disc)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|t| t.as_secs().saturating_sub(SECONDS_2000_01_01) as u32)
+        .unwrap_or(0);
+    // SystemTime::now() panics on wasm32-unknown-unknown
+    #[cfg(target_arch = "wasm32")]
+    let timestamp = 0;
+    let items = save_file.items.as_ref().unwrap_or(&config.starting_items);
+    let game_state = build_game_state(save_file, items, &worlds, hint_count, timestamp)?;
+
+    let banner = read_card_texture(gc_disc, resource_info!("TXTR_SaveBanner.TXTR").res_id)?;
+    let icons = [
+        read_card_texture(gc_disc, resource_info!("TXTR_SaveIcon0.TXTR").res_id)?,
+        read_card_texture(gc_disc, resource_info!("TXTR_SaveIcon1.TXTR").res_id)?,
+    ];
+    let (header, data) = build_gci(
+        &gc_disc.header.game_identifier(), timestamp, &banner, &icons, &game_state, slot,
+    );
+    let gci = Gci {
+        header,
+        data: Reader::new(&data[..]).read((data.len(), ())),
+    };
+
+    let mut file = File::create(&save_file.output_gci)
+        .map_err(|e| format!("Failed to create save file '{}': {}", save_file.output_gci, e))?;
+    gci.write_to(&mut file)
+        .map_err(|e| format!("Failed to write save file '{}': {}", save_file.output_gci, e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use reader_writer::Readable;
+
+    fn read_bits(bytes: &[u8], start: usize, count: usize) -> u32
+    {
+        (start..start + count).fold(0, |value, bit| {
+            (value << 1) | ((bytes[bit / 8] >> (7 - bit % 8)) & 1) as u32
+        })
+    }
+
+    #[test]
+    fn test_bit_stream_writer()
+    {
+        let mut w = BitStreamWriter::new();
+        w.write_bits(0b101, 3);
+        w.write_bool(true);
+        w.write_bits(0xABC, 12);
+        w.write_bool(true);
+        assert_eq!(w.bytes, [0xBA, 0xBC, 0x80]);
+        assert_eq!(w.bit_len, 17);
+        assert_eq!(bit_count(1), 1);
+        assert_eq!(bit_count(250), 8);
+        assert_eq!(bit_count(0x100), 9);
+    }
+
+    #[test]
+    fn test_crc32()
+    {
+        // The standard check value, without the final xor
+        assert_eq!(crc32(b"123456789"), !0xCBF43926);
+        assert_eq!(crc32(b""), 0xFFFFFFFF);
+    }
+
+    #[test]
+    fn test_game_state_layout()
+    {
+        let save_file: SaveFileConfig = serde_json::from_str(
+            r#"{ "outputGci": "test.gci", "room": "frigate", "hardMode": true, "playTime": 3723.5 }"#
+        ).unwrap();
+        let state = build_game_state(&save_file, &StartingItems::default(), &[], 0, 0x12345678)
+            .unwrap();
+        assert_eq!(state.len(), GAME_STATE_SIZE);
+
+        // 128 unused bytes, then the timestamp, hard mode, the inventory flag and the world
+        assert!(state[..128].iter().all(|b| *b == 0));
+        assert_eq!(read_bits(&state, 1024, 32), 0x12345678);
+        assert_eq!(read_bits(&state, 1056, 2), 0b10);
+        assert_eq!(read_bits(&state, 1058, 32), SpawnRoomData::from_str("frigate").mlvl);
+
+        // The play time is a big endian f64
+        let play_time = 3723.5f64.to_bits();
+        assert_eq!(read_bits(&state, 1090, 32), (play_time >> 32) as u32);
+        assert_eq!(read_bits(&state, 1122, 32), play_time as u32);
+    }
+
+    #[test]
+    fn test_gci_layout()
+    {
+        let banner = (vec![0x11; 96 * 32], Some(vec![0x22; 512]));
+        let icon = (vec![0x33; 32 * 32], Some(vec![0x44; 512]));
+        let game_state = vec![0x55; GAME_STATE_SIZE];
+        let (header, data) = build_gci(
+            b"GM8E01", 0x12345678, &banner, &[icon.clone(), icon], &game_state, 1,
+        );
+
+        // comment + images (6212 bytes) and the save buffer (3004 bytes) take up 2 blocks
+        assert_eq!(data.len(), 2 * GCI_BLOCK_SIZE);
+        assert_eq!(header.block_count, 2);
+        assert_eq!(header.comment_offset, 4);
+        assert_eq!(header.image_offset, 4 + COMMENT_SIZE as u32);
+        assert_eq!(header.banner_format, GciHeader::BANNER_FORMAT_C8);
+        assert_eq!(header.icon_format, 0b0101);
+        assert_eq!(u32::from_be_bytes(data[..4].try_into().unwrap()), crc32(&data[4..]));
+        assert_eq!(&data[6212 + 4..6212 + 7], &[0, 1, 0]);
+        assert_eq!(data[6212 + 7 + SYSTEM_DATA_SIZE], 0x55);
+
+        let gci = Gci {
+            header,
+            data: Reader::new(&data[..]).read((data.len(), ())),
+        };
+        let mut bytes = vec![];
+        gci.write_to(&mut bytes).unwrap();
+        assert_eq!(GciHeader::fixed_size(), Some(64));
+        assert_eq!(bytes.len(), 64 + 2 * GCI_BLOCK_SIZE);
+
+        let gci: Gci = Reader::new(&bytes[..]).try_read(()).unwrap();
+        assert_eq!(gci.header.filename_str(), "MetroidPrime A");
+        assert_eq!(&gci.header.game_code[..], b"GM8E");
+        assert_eq!(gci.header.modified_time, 0x12345678);
+    }
+}
@@ -0,0 +1,167 @@
+use serde::Serialize;
+use std::{collections::BTreeMap, fs::File, io::Write};
+
+use structs::{ConnectionMsg, ConnectionState, SclyProperty};
+
+use crate::{
+    elevators::World,
+    pickup_meta,
+    GcDiscLookupExtensions,
+};
+
+/* Structs for modeling JSON format */
+
+// Instance ids, layer indices and connections are written the same way `editObjs`,
+// `addConnections`, `deleteIds` and `layers` expect them, so the dump can be used to look up
+// values for a room's config.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SclyDumpRoom<'r>
+{
+    mrea: u32,
+    layers: Vec<SclyDumpLayer<'r>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SclyDumpLayer<'r>
+{
+    index: usize,
+    name: String,
+    active: bool,
+    objects: Vec<SclyDumpObject<'r>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SclyDumpObject<'r>
+{
+    #[serde(rename = "type")]
+    type_name: &'static str,
+    object_type: u8,
+    id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<[f32; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<[f32; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scale: Option<[f32; 3]>,
+    connections: Vec<SclyDumpConnection>,
+    properties: SclyProperty<'r>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SclyDumpConnection
+{
+    sender_id: u32,
+    target_id: u32,
+    state: ConnectionState,
+    message: ConnectionMsg,
+}
+
+// `rooms` entries are either "<world>" or "<world>:<room>". An empty list selects every room.
+pub fn room_selected(rooms: &[String], world: &str, room_name: &str) -> bool
+{
+    if rooms.is_empty() {
+        return true;
+    }
+
+    rooms.iter().any(|x| {
+        match x.split_once(':') {
+            Some((w, r)) => w.trim().eq_ignore_ascii_case(world) && r.trim().eq_ignore_ascii_case(room_name),
+            None => x.trim().eq_ignore_ascii_case(world),
+        }
+    })
+}
+
+pub fn export_scly(gc_disc: &structs::GcDisc, filename: &str, rooms: &[String])
+    -> Result<(), String>
+{
+    // "<world>": { "<room>": <room> }
+    let mut dump = BTreeMap::<&str, BTreeMap<String, SclyDumpRoom>>::new();
+
+    for (pak_name, room_infos) in pickup_meta::ROOM_INFO.iter() {
+        let world = World::from_pak(pak_name).unwrap();
+        let world_name = world.to_json_key();
+
+        let mlvl_res = gc_disc.find_resource(pak_name, |res| res.file_id == world.mlvl() && res.fourcc() == b"MLVL".into())
+            .ok_or_else(|| format!("Failed to find MLVL 0x{:08X} in {}", world.mlvl(), pak_name))?;
+        let mlvl = mlvl_res.kind.as_mlvl().unwrap();
+
+        for room_info in room_infos.iter() {
+            let room_name = room_info.name().trim();
+            if !room_selected(rooms, world_name, room_name) {
+                continue;
+            }
+
+            let mrea_id = room_info.room_id.to_u32();
+            let area_idx = mlvl.areas.iter()
+                .position(|area| area.mrea.to_u32() == mrea_id)
+                .ok_or_else(|| format!("MREA 0x{:08X} is not part of {}", mrea_id, pak_name))?;
+            let layer_flags = mlvl.area_layer_flags.iter().nth(area_idx).unwrap();
+            let layer_names = mlvl.area_layer_names.names_for_area(area_idx).unwrap();
+
+            let mut mrea_res = gc_disc.find_resource(pak_name, |res| res.file_id == mrea_id && res.fourcc() == b"MREA".into())
+                .ok_or_else(|| format!("Failed to find MREA 0x{:08X} in {}", mrea_id, pak_name))?
+                .into_owned();
+            let scly = mrea_res.kind.as_mrea_mut().unwrap().scly_section_mut();
+
+            let mut layers = Vec::new();
+            for (index, layer) in scly.layers.as_mut_vec().iter_mut().enumerate() {
+                let mut objects = Vec::new();
+                for obj in layer.objects.as_mut_vec().iter_mut() {
+                    let property_data = &mut obj.property_data;
+                    let position = property_data.supports_position().then(|| property_data.get_position());
+                    let rotation = property_data.supports_rotation().then(|| property_data.get_rotation());
+                    let scale = property_data.supports_scale().then(|| property_data.get_scale());
+                    property_data.guess_kind();
+
+                    objects.push(SclyDumpObject {
+                        type_name: property_data.object_type_name(),
+                        object_type: property_data.object_type(),
+                        id: obj.instance_id,
+                        position,
+                        rotation,
+                        scale,
+                        connections: obj.connections.iter()
+                            .map(|conn| SclyDumpConnection {
+                                sender_id: obj.instance_id,
+                                target_id: conn.target_object_id,
+                                state: conn.state,
+                                message: conn.message,
+                            })
+                            .collect(),
+                        properties: property_data.clone(),
+                    });
+                }
+
+                layers.push(SclyDumpLayer {
+                    index,
+                    name: layer_names.get(index)
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default(),
+                    active: layer_flags.flags & (1 << index) != 0,
+                    objects,
+                });
+            }
+
+            dump.entry(world_name)
+                .or_default()
+                .insert(room_name.to_string(), SclyDumpRoom { mrea: mrea_id, layers });
+        }
+    }
+
+    if dump.is_empty() {
+        return Err(format!("No rooms matched {:?}", rooms));
+    }
+
+    let json = serde_json::to_string_pretty(&dump)
+        .map_err(|e| format!("Failed to serialize SCLY dump: {}", e))?;
+    let mut file = File::create(filename)
+        .map_err(|e| format!("Failed to create SCLY dump file: {}", e))?;
+    file.write_all(json.as_bytes())
+        .map_err(|e| format!("Failed to write SCLY dump file: {}", e))?;
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use std::{fmt::Write as _, fs, path::Path};
+
+use structs::ConnectionMsg;
+
+use crate::{
+    elevators::World,
+    mlvl_wrapper::MlvlArea,
+    patch_config::SclyGraphFormat,
+    patcher::PrimePatcher,
+    pickup_meta,
+    scly_dump::room_selected,
+};
+
+struct GraphNode
+{
+    id: u32,
+    label: String,
+}
+
+struct GraphLayer
+{
+    label: String,
+    nodes: Vec<GraphNode>,
+}
+
+struct GraphEdge
+{
+    sender_id: u32,
+    target_id: u32,
+    label: String,
+    memory_relay: bool,
+}
+
+fn conn_field_name(name: Option<&'static str>, value: u32) -> String
+{
+    match name {
+        Some(name) => name.to_string(),
+        None => format!("0x{:X}", value),
+    }
+}
+
+// Collects the room's objects (grouped by layer), their connections and any world-level memory
+// relay connection which is sent from or to this room
+fn collect_graph(area: &mut MlvlArea) -> (Vec<GraphLayer>, Vec<GraphEdge>)
+{
+    let mrea_index = area.mrea_index as u32;
+    let layer_flags = area.layer_flags.flags;
+    let layer_names = area.layer_names.clone();
+
+    let mut layers = Vec::new();
+    let mut edges = Vec::new();
+
+    let scly = area.mrea().scly_section_mut();
+    for (index, layer) in scly.layers.as_mut_vec().iter_mut().enumerate() {
+        let name = layer_names.get(index)
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let active = if layer_flags & (1 << index) != 0 { "" } else { " (inactive)" };
+
+        let mut nodes = Vec::new();
+        for obj in layer.objects.as_mut_vec().iter_mut() {
+            nodes.push(GraphNode {
+                id: obj.instance_id,
+                label: format!("{} 0x{:08X}", obj.property_data.object_type_name(), obj.instance_id),
+            });
+
+            for conn in obj.connections.iter() {
+                edges.push(GraphEdge {
+                    sender_id: obj.instance_id,
+                    target_id: conn.target_object_id,
+                    label: format!(
+                        "{} -> {}",
+                        conn_field_name(conn.state.name(), conn.state.0),
+                        conn_field_name(conn.message.name(), conn.message.0),
+                    ),
+                    memory_relay: false,
+                });
+            }
+        }
+
+        layers.push(GraphLayer {
+            label: format!("{}: {}{}", index, name, active),
+            nodes,
+        });
+    }
+
+    let in_area = |id: u32| (id >> 16) & 0x3FF == mrea_index;
+    for conn in area.memory_relay_conns.iter() {
+        if !in_area(conn.sender_id) && !in_area(conn.target_id) {
+            continue;
+        }
+
+        let message = ConnectionMsg(conn.message as u32);
+        edges.push(GraphEdge {
+            sender_id: conn.sender_id,
+            target_id: conn.target_id,
+            label: format!(
+                "MEMORY RELAY -> {}{}",
+                conn_field_name(message.name(), message.0),
+                if conn.active != 0 { "" } else { " (inactive)" },
+            ),
+            memory_relay: true,
+        });
+    }
+
+    (layers, edges)
+}
+
+fn render_dot(title: &str, layers: &[GraphLayer], edges: &[GraphEdge]) -> String
+{
+    let mut out = String::new();
+    writeln!(out, "digraph \"{}\" {{", title.replace('"', "\\\"")).unwrap();
+    writeln!(out, "    rankdir=LR;").unwrap();
+    writeln!(out, "    node [shape=box];").unwrap();
+
+    for (index, layer) in layers.iter().enumerate() {
+        writeln!(out).unwrap();
+        writeln!(out, "    subgraph cluster_layer{} {{", index).unwrap();
+        writeln!(out, "        label=\"{}\";", layer.label.replace('"', "\\\"")).unwrap();
+        for node in layer.nodes.iter() {
+            writeln!(out, "        n{:08X} [label=\"{}\"];", node.id, node.label).unwrap();
+        }
+        writeln!(out, "    }}").unwrap();
+    }
+
+    writeln!(out).unwrap();
+    for edge in edges.iter() {
+        let style = if edge.memory_relay { ", style=dashed" } else { "" };
+        writeln!(out, "    n{:08X} -> n{:08X} [label=\"{}\"{}];", edge.sender_id, edge.target_id, edge.label, style).unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn render_mermaid(title: &str, layers: &[GraphLayer], edges: &[GraphEdge]) -> String
+{
+    let mut out = String::new();
+    writeln!(out, "---").unwrap();
+    writeln!(out, "title: \"{}\"", title.replace('"', "#quot;")).unwrap();
+    writeln!(out, "---").unwrap();
+    writeln!(out, "flowchart LR").unwrap();
+
+    for (index, layer) in layers.iter().enumerate() {
+        writeln!(out, "    subgraph layer{} [\"{}\"]", index, layer.label.replace('"', "#quot;")).unwrap();
+        for node in layer.nodes.iter() {
+            writeln!(out, "        n{:08X}[\"{}\"]", node.id, node.label).unwrap();
+        }
+        writeln!(out, "    end").unwrap();
+    }
+
+    for edge in edges.iter() {
+        let arrow = if edge.memory_relay { "-.->" } else { "-->" };
+        writeln!(out, "    n{:08X} {}|\"{}\"| n{:08X}", edge.sender_id, arrow, edge.label, edge.target_id).unwrap();
+    }
+
+    out
+}
+
+// Writes "<dir>/<world> - <room> (<suffix>).<dot|mmd>"
+pub fn patch_write_scly_graph(
+    area: &mut MlvlArea,
+    dir: &str,
+    format: SclyGraphFormat,
+    world_name: &str,
+    room_name: &str,
+    suffix: &str,
+)
+    -> Result<(), String>
+{
+    let title = format!("{} - {}", world_name, room_name);
+    let (layers, edges) = collect_graph(area);
+
+    let (contents, extension) = match format {
+        SclyGraphFormat::Dot => (render_dot(&title, &layers, &edges), "dot"),
+        SclyGraphFormat::Mermaid => (render_mermaid(&title, &layers, &edges), "mmd"),
+    };
+
+    fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create SCLY graph dir '{}': {}", dir, e))?;
+    let filename = format!("{} ({}).{}", title.replace(['/', '\\', ':'], "_"), suffix, extension);
+    let path = Path::new(dir).join(filename);
+    fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write SCLY graph '{}': {}", path.display(), e))?;
+
+    Ok(())
+}
+
+
+// Registers a graph patch for every room in `rooms`. Scly patches run in the order they were
+// added, so calling this first and last brackets the rest of the room's patches.
+pub fn add_scly_graph_patches<'r, 's>(
+    patcher: &mut PrimePatcher<'r, 's>,
+    dir: &'s str,
+    format: SclyGraphFormat,
+    rooms: &'s [String],
+    suffix: &'s str,
+)
+{
+    for (pak_name, room_infos) in pickup_meta::ROOM_INFO.iter() {
+        let world_name = World::from_pak(pak_name).unwrap().to_json_key();
+        for room_info in room_infos.iter() {
+            let room_name = room_info.name().trim();
+            if !room_selected(rooms, world_name, room_name) {
+                continue;
+            }
+
+            patcher.add_scly_patch(
+                (pak_name.as_bytes(), room_info.room_id.to_u32()),
+                move |_ps, area| patch_write_scly_graph(area, dir, format, world_name, room_name, suffix),
+            );
+        }
+    }
+}
@@ -0,0 +1,199 @@
+// Exports every STRG on the disc to JSON and applies edited copies of that JSON back as fan
+// translations.
+//
+// Translations are applied string by string, by index, rather than through
+// `Strg::edit_strings`. `edit_strings` replaces a substring in every string of a table, so a
+// short vanilla string (e.g. "Missile") would also rewrite every longer string containing it.
+// Matching each index against the vanilla text also leaves alone any string the randomizer has
+// already rewritten.
+
+use serde::{Serialize, Deserialize};
+use std::{collections::{BTreeMap, HashMap}, fs::{self, File}, io::Write};
+
+use reader_writer::FourCC;
+use resource_info_table::lookup_resource_info;
+use structs::{Languages, SUPPORTED_LANGUAGES};
+
+use json_strip::strip_jsonc_comments;
+
+/* Structs for modeling JSON format */
+
+// One STRG resource as found on the disc. The same format is used for both exporting and
+// importing, so that a translation can be produced by editing an exported file in place.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StrgTable
+{
+    pub id: u32,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub paks: Vec<String>,
+    pub languages: BTreeMap<String, Vec<String>>, // "<language code>": <non-null terminated table of strings>
+}
+
+fn strip_terminator(string: String) -> String
+{
+    string.trim_end_matches('\0').to_string()
+}
+
+// Every PAK in the root of the disc, along with its name
+fn disc_paks<'r>(gc_disc: &structs::GcDisc<'r>) -> Result<Vec<(String, structs::Pak<'r>)>, String>
+{
+    let entries = gc_disc.file_system_root.dir_entries()
+        .ok_or_else(|| "The disc root is not a directory".to_string())?;
+    let mut paks = vec![];
+    for entry in entries {
+        let pak_name = entry.name().to_str().unwrap().to_string();
+        if !pak_name.to_lowercase().ends_with(".pak") {
+            continue;
+        }
+
+        let pak = match *entry.file().unwrap() {
+            structs::FstEntryFile::Pak(ref pak) => pak.clone(),
+            structs::FstEntryFile::Unknown(ref reader) => reader.clone().try_read(())
+                .map_err(|e| format!("Failed to parse {}: {}", pak_name, e))?,
+            _ => return Err(format!("{} is not a pak", pak_name)),
+        };
+        paks.push((pak_name, pak));
+    }
+    Ok(paks)
+}
+
+// The strings of each supported language in a STRG resource, without their null terminators
+fn strg_languages(res: &structs::Resource) -> BTreeMap<String, Vec<String>>
+{
+    let mut res = res.clone();
+    let strg = res.kind.as_strg_mut().unwrap();
+
+    let mut languages = BTreeMap::new();
+    for table in strg.string_tables.iter() {
+        if !SUPPORTED_LANGUAGES.contains(&table.lang.as_bytes()) {
+            continue;
+        }
+        let strings = table.strings.iter()
+            .map(|string| strip_terminator(string.into_owned().into_string()))
+            .collect();
+        languages.insert(table.lang.to_string(), strings);
+    }
+    languages
+}
+
+/* Export */
+
+pub fn export_strgs(gc_disc: &structs::GcDisc, filename: &str)
+    -> Result<(), String>
+{
+    let mut tables = BTreeMap::<u32, StrgTable>::new();
+
+    for (pak_name, pak) in disc_paks(gc_disc)? {
+        for res in pak.resources.iter() {
+            if res.fourcc() != b"STRG".into() {
+                continue;
+            }
+
+            if let Some(table) = tables.get_mut(&res.file_id) {
+                table.paks.push(pak_name.clone());
+                continue;
+            }
+
+            tables.insert(res.file_id, StrgTable {
+                id: res.file_id,
+                name: lookup_resource_info(res.file_id, FourCC::from_bytes(b"STRG"))
+                    .map(|info| info.long_name.to_string()),
+                paks: vec![pak_name.clone()],
+                languages: strg_languages(&res),
+            });
+        }
+    }
+
+    let tables: Vec<StrgTable> = tables.into_values().collect();
+    let json = serde_json::to_string_pretty(&tables)
+        .map_err(|e| format!("Failed to serialize STRG tables: {}", e))?;
+    let mut file = File::create(filename)
+        .map_err(|e| format!("Failed to create STRG export file: {}", e))?;
+    file.write_all(json.as_bytes())
+        .map_err(|e| format!("Failed to write STRG export file: {}", e))?;
+
+    Ok(())
+}
+
+/* Import */
+
+pub fn parse_strg_translation(filename: &str) -> Result<Vec<StrgTable>, String>
+{
+    let json = fs::read_to_string(filename)
+        .map_err(|e| format!("Could not read STRG translation file '{}': {}", filename, e))?;
+    let tables: Vec<StrgTable> = serde_json::from_str(strip_jsonc_comments(&json, true).as_str())
+        .map_err(|e| format!("STRG translation '{}' parse failed: {}", filename, e))?;
+
+    for table in tables.iter() {
+        for lang in table.languages.keys() {
+            if Languages::from_code(lang.as_bytes()).is_none() {
+                Err(format!("Unsupported language '{}' in STRG translation for {}", lang, table.id))?
+            }
+        }
+    }
+
+    Ok(tables)
+}
+
+/// The vanilla text of each translated STRG, read before any patches have run. Translations are
+/// matched against it instead of against the STRG being patched, which may already hold the
+/// randomizer's own edits.
+pub fn vanilla_strg_languages(gc_disc: &structs::GcDisc, translations: &[StrgTable])
+    -> Result<HashMap<u32, BTreeMap<String, Vec<String>>>, String>
+{
+    let mut vanilla = HashMap::new();
+    if translations.is_empty() {
+        return Ok(vanilla);
+    }
+    for (_, pak) in disc_paks(gc_disc)? {
+        for res in pak.resources.iter() {
+            if res.fourcc() != b"STRG".into() || vanilla.contains_key(&res.file_id) {
+                continue;
+            }
+            if translations.iter().any(|table| table.id == res.file_id) {
+                vanilla.insert(res.file_id, strg_languages(&res));
+            }
+        }
+    }
+
+    for table in translations {
+        if !vanilla.contains_key(&table.id) {
+            Err(format!("STRG translation for {} doesn't match any STRG on the disc", table.id))?
+        }
+    }
+
+    Ok(vanilla)
+}
+
+// String i is only replaced while it still holds vanilla string i. Strings the randomizer has
+// already rewritten keep its edits, and strings left untouched by the translator are skipped.
+pub fn patch_translate_strg(
+    res: &mut structs::Resource,
+    languages: &BTreeMap<String, Vec<String>>,
+    vanilla_languages: &BTreeMap<String, Vec<String>>,
+) -> Result<(), String>
+{
+    let strg = res.kind.as_strg_mut().unwrap();
+
+    for table in strg.string_tables.as_mut_vec().iter_mut() {
+        let lang = table.lang.to_string();
+        let (translated_strings, vanilla_strings) = match (languages.get(&lang), vanilla_languages.get(&lang)) {
+            (Some(translated), Some(vanilla)) => (translated, vanilla),
+            _ => continue,
+        };
+
+        let strings = translated_strings.iter().zip(vanilla_strings.iter());
+        for (string, (to, from)) in table.strings.as_mut_vec().iter_mut().zip(strings) {
+            let to = to.trim_end_matches('\0');
+            if to == from || strip_terminator(string.clone().into_string()) != *from {
+                continue;
+            }
+            *string.as_mut_string() = format!("{}\0", to);
+        }
+    }
+
+    Ok(())
+}
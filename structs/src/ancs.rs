@@ -3,8 +3,8 @@ use crate::ResId;
 use crate::res_id:: *;
 
 use reader_writer::{
-    CStr, FourCC, LazyArray, IteratorArray, Readable, Reader, RoArray, Uncached, RoArrayIter,
-    Writable,
+    CStr, FourCC, LazyArray, IteratorArray, Readable, ReadError, ReadErrorKind, Reader, RoArray,
+    Uncached, RoArrayIter, Writable,
 };
 use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
@@ -314,16 +314,29 @@ impl<'r> Readable<'r> for MetaAnimation<'r>
     type Args = ();
     fn read_from(reader: &mut Reader<'r>, (): ()) -> Self
     {
-        let kind: u32 = reader.read(());
+        match Self::try_read_from(reader, ()) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        let kind_reader = reader.clone();
+        let kind: u32 = reader.try_read(())?;
         let res = match kind {
-            0 => MetaAnimation::Play(reader.read(())),
-            1 => MetaAnimation::Blend(reader.read(())),
-            2 => MetaAnimation::PhaseBlend(reader.read(())),
-            3 => MetaAnimation::Random(reader.read(())),
-            4 => MetaAnimation::Sequence(reader.read(())),
-            n => panic!("Unexpected MetaAnimation tag: {}", n),
+            0 => MetaAnimation::Play(reader.try_read(())?),
+            1 => MetaAnimation::Blend(reader.try_read(())?),
+            2 => MetaAnimation::PhaseBlend(reader.try_read(())?),
+            3 => MetaAnimation::Random(reader.try_read(())?),
+            4 => MetaAnimation::Sequence(reader.try_read(())?),
+            n => {
+                let msg = format!("Unexpected MetaAnimation tag: {}", n);
+                return Err(kind_reader.error(ReadErrorKind::Invalid(msg))
+                    .in_field("MetaAnimation", "kind"));
+            },
         };
-        res
+        Ok(res)
     }
 
     fn size(&self) -> usize
@@ -424,15 +437,28 @@ impl<'r> Readable<'r> for MetaTransition<'r>
     type Args = ();
     fn read_from(reader: &mut Reader<'r>, (): ()) -> Self
     {
-        let kind: u32 = reader.read(());
+        match Self::try_read_from(reader, ()) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        let kind_reader = reader.clone();
+        let kind: u32 = reader.try_read(())?;
         let res = match kind {
-            0 => MetaTransition::Animation(reader.read(())),
-            1 => MetaTransition::Transition(reader.read(())),
-            2 => MetaTransition::PhaseTransition(reader.read(())),
+            0 => MetaTransition::Animation(reader.try_read(())?),
+            1 => MetaTransition::Transition(reader.try_read(())?),
+            2 => MetaTransition::PhaseTransition(reader.try_read(())?),
             3 => MetaTransition::NoTransition,
-            _ => panic!("TODO"),
+            n => {
+                let msg = format!("Unexpected MetaTransition tag: {}", n);
+                return Err(kind_reader.error(ReadErrorKind::Invalid(msg))
+                    .in_field("MetaTransition", "kind"));
+            },
         };
-        res
+        Ok(res)
     }
 
     fn size(&self) -> usize
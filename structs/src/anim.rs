@@ -1,6 +1,6 @@
 use auto_struct_macros::auto_struct;
 
-use reader_writer::{Readable, Reader, RoArray};
+use reader_writer::{Readable, ReadError, ReadErrorKind, Reader, RoArray};
 use reader_writer::generic_array::{GenericArray, typenum:: *};
 
 use crate::ResId;
@@ -19,13 +19,25 @@ impl<'r> Readable<'r> for Anim<'r>
     type Args = ();
     fn read_from(reader: &mut Reader<'r>, (): ()) -> Self
     {
-        let kind: u32 = reader.read(());
+        match Self::try_read_from(reader, ()) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        let kind_reader = reader.clone();
+        let kind: u32 = reader.try_read(())?;
         let res = match kind {
-            0 => Anim::Uncompressed(reader.read(())),
-            2 => Anim::Compressed(reader.read(())),
-            i => panic!("Invalid ANIM kind {}", i),
+            0 => Anim::Uncompressed(reader.try_read(())?),
+            2 => Anim::Compressed(reader.try_read(())?),
+            i => {
+                let msg = format!("Invalid ANIM kind {}", i);
+                return Err(kind_reader.error(ReadErrorKind::Invalid(msg)).in_field("Anim", "kind"));
+            },
         };
-        res
+        Ok(res)
     }
 
     fn size(&self) -> usize
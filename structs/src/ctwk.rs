@@ -1,6 +1,7 @@
 use auto_struct_macros::auto_struct;
 use reader_writer::{
-    Reader, Readable, Writable, CStr, generic_array::GenericArray, typenum::*,
+    Reader, Readable, ReadError, ReadErrorKind, Writable, CStr, generic_array::GenericArray,
+    typenum::*,
 };
 use std::io;
 
@@ -32,18 +33,29 @@ impl<'r> Readable<'r> for Ctwk<'r>
 {
     type Args = ();
     fn read_from(reader: &mut Reader<'r>, (): ()) -> Self
+    {
+        match Self::try_read_from(reader, ()) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
     {
         // TODO: This will not work for every CTWK, need a way to differentiate:
         //  - PlayerControls from PlayerControls2 (size == 288)
         //  - Ball from GunRes (size == 480)
-        match reader.len() {
-             96 => Ctwk::CtwkGame(reader.read(())),
-            800 => Ctwk::CtwkPlayer(reader.read(())),
-            512 => Ctwk::CtwkPlayerGun(reader.read(())),
-            480 => Ctwk::CtwkBall(reader.read(())),
-            2368 => Ctwk::CtwkGuiColors(reader.read(())),
-            _ => panic!("Unhandled CTWK size - {}", reader.len()),
-        }
+        Ok(match reader.len() {
+             96 => Ctwk::CtwkGame(reader.try_read(())?),
+            800 => Ctwk::CtwkPlayer(reader.try_read(())?),
+            512 => Ctwk::CtwkPlayerGun(reader.try_read(())?),
+            480 => Ctwk::CtwkBall(reader.try_read(())?),
+            2368 => Ctwk::CtwkGuiColors(reader.try_read(())?),
+            n => {
+                let msg = format!("Unhandled CTWK size - {}", n);
+                return Err(reader.error(ReadErrorKind::Invalid(msg)));
+            },
+        })
     }
 
     fn size(&self) -> usize
@@ -1,7 +1,7 @@
 use auto_struct_macros::auto_struct;
 
 use reader_writer::{
-    CStr, FourCC, LazyArray, Readable, Reader, RoArray, Writable,
+    CStr, FourCC, LazyArray, Readable, ReadError, ReadErrorKind, Reader, RoArray, Writable,
 };
 use reader_writer::generic_array::GenericArray;
 use reader_writer::generic_array::typenum:: *;
@@ -112,37 +112,47 @@ impl<'r> FrmeWidgetKind<'r>
 impl<'r> Readable<'r> for FrmeWidgetKind<'r>
 {
     type Args = (FourCC, u32);
-    fn read_from(reader: &mut Reader<'r>, (fourcc, version): Self::Args) -> Self
+    fn read_from(reader: &mut Reader<'r>, args: Self::Args) -> Self
     {
-        if fourcc == b"HWIG".into() {
+        match Self::try_read_from(reader, args) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (fourcc, version): Self::Args)
+        -> Result<Self, ReadError>
+    {
+        Ok(if fourcc == b"HWIG".into() {
             FrmeWidgetKind::Head
         } else if fourcc == b"BWIG".into() {
             FrmeWidgetKind::Base
         } else if fourcc == b"CAMR".into() {
-            FrmeWidgetKind::Camera(reader.read(()))
+            FrmeWidgetKind::Camera(reader.try_read(())?)
         } else if fourcc == b"LITE".into() {
-            FrmeWidgetKind::Light(reader.read(()))
+            FrmeWidgetKind::Light(reader.try_read(())?)
         } else if fourcc == b"MODL".into() {
-            FrmeWidgetKind::Model(reader.read(()))
+            FrmeWidgetKind::Model(reader.try_read(())?)
         } else if fourcc == b"TXPN".into() {
-            FrmeWidgetKind::TextPane(reader.read(version))
+            FrmeWidgetKind::TextPane(reader.try_read(version)?)
         } else if fourcc == b"METR".into() {
-            FrmeWidgetKind::Meter(reader.read(()))
+            FrmeWidgetKind::Meter(reader.try_read(())?)
         } else if fourcc == b"ENRG".into() {
-            FrmeWidgetKind::Energy(reader.read(()))
+            FrmeWidgetKind::Energy(reader.try_read(())?)
         } else if fourcc == b"GRUP".into() {
-            FrmeWidgetKind::Group(reader.read(()))
+            FrmeWidgetKind::Group(reader.try_read(())?)
         } else if fourcc == b"TBGP".into() {
-            FrmeWidgetKind::TableGroup(reader.read(()))
+            FrmeWidgetKind::TableGroup(reader.try_read(())?)
         } else if fourcc == b"PANE".into() {
-            FrmeWidgetKind::Pane(reader.read(()))
+            FrmeWidgetKind::Pane(reader.try_read(())?)
         } else if fourcc == b"SLGP".into() {
-            FrmeWidgetKind::Slider(reader.read(()))
+            FrmeWidgetKind::Slider(reader.try_read(())?)
         } else if fourcc == b"IMGP".into() {
-            FrmeWidgetKind::Image(reader.read(()))
+            FrmeWidgetKind::Image(reader.try_read(())?)
         } else {
-            panic!("Invalid Frme widget fourcc {:?}", fourcc)
-        }
+            let msg = format!("Invalid Frme widget fourcc {:?}", fourcc);
+            return Err(reader.error(ReadErrorKind::Invalid(msg)));
+        })
     }
 
     fn size(&self) -> usize
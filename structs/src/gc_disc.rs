@@ -1,6 +1,6 @@
 use auto_struct_macros::auto_struct;
 
-use reader_writer::{CStr, Reader, Readable, RoArray, WithRead, Writable};
+use reader_writer::{CStr, Reader, Readable, ReadError, ReadErrorKind, RoArray, WithRead, Writable};
 use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
 
@@ -29,19 +29,29 @@ impl<'r> Readable<'r> for GcDisc<'r>
 {
     type Args = ();
     fn read_from(reader: &mut Reader<'r>, (): ()) -> GcDisc<'r>
+    {
+        match Self::try_read_from(reader, ()) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<GcDisc<'r>, ReadError>
     {
         let start = reader.clone();
-        let header: GcDiscHeader = reader.read(());
-        let header_info = reader.read(());
-        let apploader = reader.read(());
+        let header: GcDiscHeader = reader.try_read(())?;
+        let header_info = reader.try_read(())?;
+        let apploader = reader.try_read(())?;
 
-        let fst_start = start.offset(header.fst_offset as usize);
-        let root_fst_entry: RawFstEntry = fst_start.clone().read(());
+        let fst_start = start.try_offset(header.fst_offset as usize)
+            .map_err(|e| e.in_field("GcDiscHeader", "fst_offset"))?;
+        let root_fst_entry: RawFstEntry = fst_start.clone().try_read(())?;
 
         let fst_len = root_fst_entry.length as usize;
-        let string_table_start = fst_start.offset(fst_len * RawFstEntry::fixed_size().unwrap());
+        let string_table_start = fst_start.try_offset(fst_len * RawFstEntry::fixed_size().unwrap())
+            .map_err(|e| e.in_field("RawFstEntry", "length"))?;
 
-        let fst = { fst_start }.read((0, start, string_table_start));
+        let fst = { fst_start }.try_read((0, start, string_table_start))?;
 
         let gc_disc = GcDisc {
             header: header,
@@ -49,7 +59,7 @@ impl<'r> Readable<'r> for GcDisc<'r>
             apploader: apploader,
             file_system_root: fst,
         };
-        gc_disc
+        Ok(gc_disc)
     }
 
     fn fixed_size() -> Option<usize>
@@ -206,28 +216,48 @@ pub enum FstEntry<'r>
 impl<'r> Readable<'r> for FstEntry<'r>
 {
     type Args = (u32, Reader<'r>, Reader<'r>);
-    fn read_from(reader: &mut Reader<'r>, (self_offset, disc_start, string_table): Self::Args)
-        -> Self
+    fn read_from(reader: &mut Reader<'r>, args: Self::Args) -> Self
+    {
+        match Self::try_read_from(reader, args) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (self_offset, disc_start, string_table): Self::Args)
+        -> Result<Self, ReadError>
     {
         let reader_start = reader.clone();
-        let raw: RawFstEntry = reader.read(());
-        let name = string_table.offset(raw.name_offset as usize).read::<CStr<'r>>(());
+        let raw: RawFstEntry = reader.try_read(())?;
+        let name = string_table.try_offset(raw.name_offset as usize)
+            .and_then(|mut names| names.try_read::<CStr<'r>>(()))
+            .map_err(|e| e.in_field("RawFstEntry", "name_offset"))?;
         if raw.flags == 1 {
+            let end = match raw.length.checked_sub(self_offset) {
+                Some(end) => end,
+                None => {
+                    let msg = format!("Directory ends at entry {}, before it starts", raw.length);
+                    return Err(reader_start.error(ReadErrorKind::Invalid(msg))
+                        .in_field("RawFstEntry", "length"));
+                },
+            };
             let mut entries = vec![];
             loop {
                 let bytes_read = reader_start.len() - reader.len();
                 let index = (bytes_read / RawFstEntry::fixed_size().unwrap()) as u32;
-                if index >= (raw.length - self_offset) {
+                if index >= end {
                     break
                 }
-                entries.push(reader.read((index, disc_start.clone(), string_table.clone())));
+                entries.push(reader.try_read((index, disc_start.clone(), string_table.clone()))?);
             }
-            FstEntry::Dir(name, entries)
+            Ok(FstEntry::Dir(name, entries))
         } else {
             let file = FstEntryFile::Unknown(
-                disc_start.offset(raw.offset as usize).truncated(raw.length as usize)
+                disc_start.try_offset(raw.offset as usize)
+                    .and_then(|file| file.try_truncated(raw.length as usize))
+                    .map_err(|e| e.in_field("RawFstEntry", "offset"))?
             );
-            FstEntry::File(name, file, Some(raw.offset))
+            Ok(FstEntry::File(name, file, Some(raw.offset)))
         }
     }
 
@@ -451,23 +481,35 @@ impl<'r> FstEntry<'r>
     }
 
     pub fn guess_kind(&mut self)
+    {
+        if let Err(e) = self.try_guess_kind() {
+            panic!("{}", e)
+        }
+    }
+
+    /// Like `guess_kind`, but a file that can't be parsed as its extension's kind is reported
+    /// as an error
+    pub fn try_guess_kind(&mut self) -> Result<(), ReadError>
     {
         let (name, file) = match self {
             FstEntry::File(name, file, _) => (name, file),
-            _ => return,
+            _ => return Ok(()),
         };
         let name = name.to_bytes();
         let len = name.len();
 
         // For simplicity's sake, assume all extentions are len 3
+        if len < 3 {
+            return Ok(());
+        }
         let mut ext = [name[len - 3], name[len - 2], name[len - 1]];
         ext.make_ascii_lowercase();
 
         if ext == *b"pak" {
             *file = match file {
                 FstEntryFile::Unknown(ref reader)
-                    => FstEntryFile::Pak(reader.clone().read(())),
-                FstEntryFile::Pak(_) => return,
+                    => FstEntryFile::Pak(reader.clone().try_read(())?),
+                FstEntryFile::Pak(_) => return Ok(()),
                 _ => panic!("Unexpected fst file type while trying to guess pak."),
             }
         }
@@ -475,8 +517,8 @@ impl<'r> FstEntry<'r>
         if ext == *b"thp" {
             *file = match file {
                 FstEntryFile::Unknown(ref reader)
-                    => FstEntryFile::Thp(reader.clone().read(())),
-                FstEntryFile::Thp(_) => return,
+                    => FstEntryFile::Thp(reader.clone().try_read(())?),
+                FstEntryFile::Thp(_) => return Ok(()),
                 _ => panic!("Unexpected fst file type while trying to guess thp."),
             }
         }
@@ -484,11 +526,12 @@ impl<'r> FstEntry<'r>
         if ext == *b"bnr" {
             *file = match file {
                 FstEntryFile::Unknown(ref reader)
-                    => FstEntryFile::Bnr(reader.clone().read(())),
-                FstEntryFile::Bnr(_) => return,
+                    => FstEntryFile::Bnr(reader.clone().try_read(())?),
+                FstEntryFile::Bnr(_) => return Ok(()),
                 _ => panic!("Unexpected fst file type while trying to guess bnr."),
             }
         }
+        Ok(())
     }
 
     pub fn dir_files_iter_mut<'a>(&'a mut self) -> DirFilesIterMut<'a, 'r>
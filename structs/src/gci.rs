@@ -0,0 +1,60 @@
+use auto_struct_macros::auto_struct;
+use reader_writer::{FixedArray, RoArray};
+use reader_writer::typenum::{U2, U4, U32};
+
+pub const GCI_BLOCK_SIZE: usize = 0x2000;
+
+// A single file exported from a GameCube memory card, as imported/exported by Dolphin and
+// Nintendont. The header is the card's directory entry verbatim and is followed by the raw
+// contents of the file's blocks.
+#[auto_struct(Readable, Writable)]
+#[derive(Debug, Clone)]
+pub struct Gci<'r>
+{
+    pub header: GciHeader,
+    #[auto_struct(init = (header.block_count as usize * GCI_BLOCK_SIZE, ()))]
+    pub data: RoArray<'r, u8>,
+}
+
+#[auto_struct(Readable, Writable, FixedSize)]
+#[derive(Debug, Clone)]
+pub struct GciHeader
+{
+    pub game_code: FixedArray<u8, U4>,
+    pub maker_code: FixedArray<u8, U2>,
+    #[auto_struct(expect = 0xFF)]
+    _unused0: u8,
+    pub banner_format: u8,
+    pub filename: FixedArray<u8, U32>,
+    pub modified_time: u32, // seconds since 2000-01-01
+    pub image_offset: u32,
+    pub icon_format: u16,
+    pub animation_speed: u16,
+    pub permissions: u8,
+    pub copy_counter: u8,
+    pub first_block: u16,
+    pub block_count: u16,
+    #[auto_struct(expect = 0xFFFF)]
+    _unused1: u16,
+    pub comment_offset: u32,
+}
+
+impl GciHeader
+{
+    pub const BANNER_FORMAT_C8: u8 = 1;
+    pub const BANNER_FORMAT_RGB5A3: u8 = 2;
+
+    // Icon formats are packed 2 bits per animation frame
+    pub const ICON_FORMAT_C8_SHARED: u16 = 1;
+    pub const ICON_FORMAT_RGB5A3: u16 = 2;
+
+    pub const ANIMATION_SPEED_MIDDLE: u16 = 2;
+
+    pub const PERMISSION_PUBLIC: u8 = 0x04;
+
+    pub fn filename_str(&self) -> String
+    {
+        let len = self.filename.iter().position(|b| *b == 0).unwrap_or(self.filename.len());
+        String::from_utf8_lossy(&self.filename[..len]).into_owned()
+    }
+}
@@ -10,6 +10,7 @@ mod evnt;
 mod font;
 mod frme;
 mod gc_disc;
+mod gci;
 mod hint;
 mod mapa;
 mod mapw;
@@ -329,6 +330,7 @@ pub use evnt::*;
 pub use font::*;
 pub use frme::*;
 pub use gc_disc::*;
+pub use gci::*;
 pub use hint::*;
 pub use mapa::*;
 pub use mapw::*;
@@ -1,6 +1,6 @@
 use auto_struct_macros::auto_struct;
-use reader_writer::{CStr, FourCC, IteratorArray, LazyArray, Readable, Reader, RoArray,
-                    RoArrayIter, Writable};
+use reader_writer::{CStr, FourCC, IteratorArray, LazyArray, Readable, ReadError, ReadErrorKind,
+                    Reader, RoArray, RoArrayIter, Writable};
 use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
 
@@ -132,11 +132,32 @@ impl<'r> Readable<'r> for AreaDependencies<'r>
     type Args = ();
     fn read_from(reader: &mut Reader<'r>, (): ()) -> Self
     {
-        let inner: AreaDependenciesInner = reader.read(());
+        match Self::try_read_from(reader, ()) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        let start = reader.clone();
+        let inner: AreaDependenciesInner = reader.try_read(())?;
+
+        // LayerDepCountIter subtracts adjacent offsets, so they have to be in order
+        let deps_len = inner.dependencies.len() as u32;
+        let mut last_offset = 0;
+        for offset in inner.dependency_offsets.iter().chain(std::iter::once(deps_len)) {
+            if offset < last_offset || offset > deps_len {
+                let msg = format!("Dependency offset {} is out of order or out of range", offset);
+                return Err(start.error(ReadErrorKind::Invalid(msg))
+                    .in_field("AreaDependencies", "dependency_offsets"));
+            }
+            last_offset = offset;
+        }
 
         let mut data_start = inner.dependencies.data_start();
         let iter = LayerDepCountIter::new(inner);
-        AreaDependencies { deps: data_start.read(iter), }
+        Ok(AreaDependencies { deps: data_start.try_read(iter)?, })
     }
 
     fn size(&self) -> usize
@@ -312,14 +333,50 @@ impl<'r> AreaLayerNames<'r>
     }
 }
 
+impl<'r> From<Vec<Vec<CStr<'r>>>> for AreaLayerNames<'r>
+{
+    fn from(names: Vec<Vec<CStr<'r>>>) -> AreaLayerNames<'r>
+    {
+        AreaLayerNames(names)
+    }
+}
+
 impl<'r> Readable<'r> for AreaLayerNames<'r>
 {
     type Args = u32;
     fn read_from(reader: &mut Reader<'r>, count: u32) -> Self
     {
-        let args: AreaLayerNamesArgs = reader.read(());
-        assert_eq!(args.layer_names_offsets.len(), count as usize);
-        AreaLayerNames::new(args.layer_names_offsets, args.layer_names)
+        match Self::try_read_from(reader, count) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, count: u32) -> Result<Self, ReadError>
+    {
+        let start = reader.clone();
+        let args: AreaLayerNamesArgs = reader.try_read(())?;
+        let offsets = &args.layer_names_offsets;
+        let names_len = args.layer_names.len() as u32;
+
+        let error = |msg: String| Err(start.error(ReadErrorKind::Invalid(msg))
+            .in_field("AreaLayerNames", "layer_names_offsets"));
+        if offsets.len() != count as usize {
+            return error(format!("Expected {} layer name offsets, found {}", count, offsets.len()));
+        }
+        if offsets.len() == 0 {
+            return error("An MLVL needs at least one area".to_string());
+        }
+        // AreaLayerNames::new expects the offsets to start at 0 and be in order
+        let mut last_offset = 0;
+        for (i, offset) in offsets.iter().chain(std::iter::once(names_len)).enumerate() {
+            if (i == 0 && offset != 0) || offset < last_offset || offset > names_len {
+                return error(format!("Layer name offset {} is out of order or out of range", offset));
+            }
+            last_offset = offset;
+        }
+
+        Ok(AreaLayerNames::new(args.layer_names_offsets, args.layer_names))
     }
 
     fn size(&self) -> usize
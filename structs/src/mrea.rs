@@ -1,6 +1,8 @@
 
 use auto_struct_macros::auto_struct;
-use reader_writer::{LCow, IteratorArray, Readable, Reader, RoArray, RoArrayIter, Writable, LazyArray};
+use reader_writer::{
+    LCow, IteratorArray, Readable, ReadError, Reader, RoArray, RoArrayIter, Writable, LazyArray,
+};
 
 use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
@@ -53,15 +55,23 @@ pub struct Mrea<'r>
 impl<'r> Mrea<'r>
 {
     pub fn scly_section<'s>(&'s self) -> LCow<'s, Scly<'r>>
+    {
+        match self.try_scly_section() {
+            Ok(scly) => scly,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_scly_section<'s>(&'s self) -> Result<LCow<'s, Scly<'r>>, ReadError>
     {
         let section = self.sections.iter().nth(self.scly_section_idx as usize).unwrap();
-        match section {
-            LCow::Owned(MreaSection::Unknown(ref reader)) => LCow::Owned(reader.clone().read(())),
-            LCow::Borrowed(MreaSection::Unknown(ref reader)) => LCow::Owned(reader.clone().read(())),
+        Ok(match section {
+            LCow::Owned(MreaSection::Unknown(ref reader)) => LCow::Owned(reader.clone().try_read(())?),
+            LCow::Borrowed(MreaSection::Unknown(ref reader)) => LCow::Owned(reader.clone().try_read(())?),
             LCow::Owned(MreaSection::Scly(scly)) => LCow::Owned(scly),
             LCow::Borrowed(MreaSection::Scly(scly)) => LCow::Borrowed(scly),
             _ => unreachable!(),
-        }
+        })
     }
 
     pub fn scly_section_mut(&mut self) -> &mut Scly<'r>
@@ -69,6 +79,11 @@ impl<'r> Mrea<'r>
         self.sections.as_mut_vec()[self.scly_section_idx as usize].convert_to_scly()
     }
 
+    pub fn try_scly_section_mut(&mut self) -> Result<&mut Scly<'r>, ReadError>
+    {
+        self.sections.as_mut_vec()[self.scly_section_idx as usize].try_convert_to_scly()
+    }
+
     pub fn lights_section<'s>(&'s self) -> LCow<'s, Lights<'r>>
     {
         let section = self.sections.iter().nth(self.lights_section_idx as usize).unwrap();
@@ -98,14 +113,22 @@ pub enum MreaSection<'r>
 impl<'r> MreaSection<'r>
 {
     pub fn convert_to_scly(&mut self) -> &mut Scly<'r>
+    {
+        match self.try_convert_to_scly() {
+            Ok(scly) => scly,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_convert_to_scly(&mut self) -> Result<&mut Scly<'r>, ReadError>
     {
         *self = match *self {
-            MreaSection::Unknown(ref reader) => MreaSection::Scly(reader.clone().read(())),
-            MreaSection::Scly(ref mut scly) => return scly,
+            MreaSection::Unknown(ref reader) => MreaSection::Scly(reader.clone().try_read(())?),
+            MreaSection::Scly(ref mut scly) => return Ok(scly),
             _ => panic!(),
         };
         match *self {
-            MreaSection::Scly(ref mut scly) => scly,
+            MreaSection::Scly(ref mut scly) => Ok(scly),
             _ => panic!(),
         }
     }
@@ -134,6 +157,12 @@ impl<'r> Readable<'r> for MreaSection<'r>
         res
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, size: u32) -> Result<Self, ReadError>
+    {
+        reader.check_len(size as usize)?;
+        Ok(Self::read_from(reader, size))
+    }
+
     fn size(&self) -> usize
     {
         match *self {
@@ -1,6 +1,7 @@
 use auto_struct_macros::auto_struct;
 use reader_writer::{
-    FourCC, LCow, Readable, Reader, RoArray, Writable, align_byte_count, pad_bytes,
+    FourCC, LCow, Readable, ReadError, ReadErrorKind, Reader, RoArray, Writable, align_byte_count,
+    pad_bytes,
 };
 
 use std::borrow::Cow;
@@ -167,14 +168,37 @@ impl<'r> ResourceList<'r>
 impl<'r> Readable<'r> for ResourceList<'r>
 {
     type Args = (Reader<'r>, RoArray<'r, ResourceInfo>);
-    fn read_from(reader: &mut Reader<'r>, (pak_start, info_array): Self::Args) -> Self
+    fn read_from(reader: &mut Reader<'r>, args: Self::Args) -> Self
     {
+        match Self::try_read_from(reader, args) {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn try_read_from(reader: &mut Reader<'r>, (pak_start, info_array): Self::Args)
+        -> Result<Self, ReadError>
+    {
+        // Resources are only read when they're iterated over, so make sure up front that each
+        // one is actually where its info says it is
+        for info in info_array.iter() {
+            pak_start.try_offset(info.offset as usize)?
+                .try_read::<Resource>(info)
+                .map_err(|e| e.in_field("Pak", "resources"))?;
+        }
+
+        // The iterators and cursors assume every Array elem holds at least one resource
+        let list = if info_array.len() == 0 {
+            vec![]
+        } else {
+            vec![ResourceListElem::Array(info_array)]
+        };
         let res = ResourceList {
             pak_start: Some(pak_start),
-            list: vec![ResourceListElem::Array(info_array)],
+            list,
         };
-        reader.advance(res.size());
-        res
+        reader.try_advance(res.size())?;
+        Ok(res)
     }
 
     fn size(&self) -> usize
@@ -536,6 +560,16 @@ impl<'r> Readable<'r> for Resource<'r>
         res
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, info: Self::Args) -> Result<Self, ReadError>
+    {
+        if info.compressed > 1 {
+            let msg = format!("Bad info.compressed: {}", info.compressed);
+            return Err(reader.error(ReadErrorKind::Invalid(msg)).in_field("Resource", "compressed"));
+        }
+        reader.check_len(info.size as usize)?;
+        Ok(Self::read_from(reader, info))
+    }
+
     fn size(&self) -> usize
     {
         align_byte_count(32, self.kind.size())
@@ -553,7 +587,7 @@ impl<'r> Writable for Resource<'r>
 }
 
 macro_rules! build_resource_data {
-    ($($name:ident, $fourcc:expr, $accessor:ident, $accessor_mut:ident,)*) => {
+    ($($name:ident, $fourcc:expr, $accessor:ident, $try_accessor:ident, $accessor_mut:ident,)*) => {
 
         #[derive(Clone, Debug)]
         pub enum ResourceKind<'r>
@@ -575,32 +609,48 @@ macro_rules! build_resource_data {
             }
 
             pub fn guess_kind(&mut self)
+            {
+                if let Err(e) = self.try_guess_kind() {
+                    panic!("{}", e)
+                }
+            }
+
+            pub fn try_guess_kind(&mut self) -> Result<(), ReadError>
             {
                 let (mut reader, fourcc) = match self {
                     ResourceKind::Unknown(reader, fourcc) => (reader.clone(), *fourcc),
-                    _ => return,
+                    _ => return Ok(()),
                 };
 
                 if false { }
                 $(else if fourcc == $fourcc.into() {
-                    *self = ResourceKind::$name(reader.read(()));
+                    *self = ResourceKind::$name(reader.try_read(())?);
                 })*
+                Ok(())
             }
 
             $(
                 pub fn $accessor(&self) -> Option<Cow<$name<'r>>>
                 {
-                    match self {
+                    match self.$try_accessor() {
+                        Ok(res) => res,
+                        Err(e) => panic!("{}", e),
+                    }
+                }
+
+                pub fn $try_accessor(&self) -> Result<Option<Cow<'_, $name<'r>>>, ReadError>
+                {
+                    Ok(match self {
                         ResourceKind::$name(inst) => Some(Cow::Borrowed(inst)),
                         ResourceKind::Unknown(reader, fourcc) => {
                             if *fourcc == $fourcc.into() {
-                                Some(Cow::Owned(reader.clone().read(())))
+                                Some(Cow::Owned(reader.clone().try_read(())?))
                             } else {
                                 None
                             }
                         },
                         _ => None,
-                    }
+                    })
                 }
 
                 pub fn $accessor_mut(&mut self) -> Option<&mut $name<'r>>
@@ -641,16 +691,16 @@ macro_rules! build_resource_data {
 }
 
 build_resource_data!(
-    Dumb, b"DUMB", as_dumb, as_dumb_mut,
-    Evnt, b"EVNT", as_evnt, as_evnt_mut,
-    Frme, b"FRME", as_frme, as_frme_mut,
-    Hint, b"HINT", as_hint, as_hint_mut,
-    Mapa, b"MAPA", as_mapa, as_mapa_mut,
-    Mapw, b"MAPW", as_mapw, as_mapw_mut,
-    Mlvl, b"MLVL", as_mlvl, as_mlvl_mut,
-    Mrea, b"MREA", as_mrea, as_mrea_mut,
-    Savw, b"SAVW", as_savw, as_savw_mut,
-    Scan, b"SCAN", as_scan, as_scan_mut,
-    Strg, b"STRG", as_strg, as_strg_mut,
-    Ctwk, b"CTWK", as_ctwk, as_ctwk_mut,
+    Dumb, b"DUMB", as_dumb, try_as_dumb, as_dumb_mut,
+    Evnt, b"EVNT", as_evnt, try_as_evnt, as_evnt_mut,
+    Frme, b"FRME", as_frme, try_as_frme, as_frme_mut,
+    Hint, b"HINT", as_hint, try_as_hint, as_hint_mut,
+    Mapa, b"MAPA", as_mapa, try_as_mapa, as_mapa_mut,
+    Mapw, b"MAPW", as_mapw, try_as_mapw, as_mapw_mut,
+    Mlvl, b"MLVL", as_mlvl, try_as_mlvl, as_mlvl_mut,
+    Mrea, b"MREA", as_mrea, try_as_mrea, as_mrea_mut,
+    Savw, b"SAVW", as_savw, try_as_savw, as_savw_mut,
+    Scan, b"SCAN", as_scan, try_as_scan, as_scan_mut,
+    Strg, b"STRG", as_strg, try_as_strg, as_strg_mut,
+    Ctwk, b"CTWK", as_ctwk, try_as_ctwk, as_ctwk_mut,
 );
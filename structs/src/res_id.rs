@@ -1,4 +1,5 @@
-use reader_writer::{FourCC, Readable, Reader, Writable};
+use reader_writer::{FourCC, Readable, ReadError, Reader, Writable};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use std::convert::TryFrom;
 use std::fmt;
@@ -121,12 +122,33 @@ impl<'r, K> Readable<'r> for ResId<K>
         ResId(reader.read(()), PhantomData)
     }
 
+    fn try_read_from(reader: &mut Reader<'r>, (): ()) -> Result<Self, ReadError>
+    {
+        Ok(ResId(reader.try_read(())?, PhantomData))
+    }
+
     fn fixed_size() -> Option<usize>
     {
         <u32 as Readable<'r>>::fixed_size()
     }
 }
 
+impl<K> Serialize for ResId<K>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+impl<'de, K> Deserialize<'de> for ResId<K>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>
+    {
+        u32::deserialize(deserializer).map(ResId::new)
+    }
+}
+
 impl<K> Writable for ResId<K>
 {
     fn write_to<W: io::Write>(&self, w: &mut W) -> io::Result<u64>
@@ -1,12 +1,17 @@
 use auto_struct_macros::auto_struct;
 
-use reader_writer::{FourCC, LCow, RoArray, LazyArray, Readable, Reader, Writable, generic_array::GenericArray};
+use reader_writer::{
+    FourCC, LCow, RoArray, LazyArray, Readable, ReadError, Reader, Writable,
+    generic_array::GenericArray,
+};
 use reader_writer::typenum::*;
 
 use std::io;
 use std::borrow::Cow;
 use std::fmt;
 
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::scly_props;
 use crate::scly_structs::{PatternedInfo, DamageInfo, DamageVulnerability, HealthInfo};
 
@@ -212,6 +217,18 @@ macro_rules! build_scly_property {
             $($name(Box<scly_props::$name<'r >> ),)*
         }
 
+        // Object types `structs` doesn't know the layout of are written as `null`
+        impl<'r> Serialize for SclyProperty<'r>
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            {
+                match self {
+                    SclyProperty::Unknown { .. } => serializer.serialize_none(),
+                    $(SclyProperty::$name(x) => x.serialize(serializer),)*
+                }
+            }
+        }
+
         impl<'r> SclyProperty<'r>
         {
             pub fn object_type(&self) -> u8
@@ -223,6 +240,25 @@ macro_rules! build_scly_property {
                 }
             }
 
+            // Reads the properties of the object type named `type_name` (as in `object_type_name`)
+            pub fn deserialize_named<'de, D: Deserializer<'de>>(type_name: &str, deserializer: D)
+                -> Result<Self, D::Error>
+            {
+                match type_name {
+                    $(stringify!($name) => Ok(SclyProperty::$name(Box::new(scly_props::$name::deserialize(deserializer)?))),)*
+                    _ => Err(D::Error::custom(format!("Unknown script object type \"{}\"", type_name))),
+                }
+            }
+
+            pub fn object_type_name(&self) -> &'static str
+            {
+                #[allow(unreachable_patterns)] // ridley throws a warning because we have both PAL and NTSC ridley definitions
+                match self.object_type() {
+                    $(<scly_props::$name as SclyPropertyData>::OBJECT_TYPE => stringify!($name),)*
+                    _ => "Unknown",
+                }
+            }
+
             /* Position */
 
             pub fn supports_position(&self) -> bool {
@@ -580,6 +616,12 @@ macro_rules! build_scly_property {
                 prop
             }
 
+            fn try_read_from(reader: &mut Reader<'r>, args: Self::Args) -> Result<Self, ReadError>
+            {
+                reader.check_len(args.1)?;
+                Ok(Self::read_from(reader, args))
+            }
+
             fn size(&self) -> usize
             {
                 match *self {
@@ -798,7 +840,7 @@ pub trait SclyPropertyData
 }
 
 #[auto_struct(Readable, FixedSize, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Connection
 {
     pub state: ConnectionState,
@@ -811,6 +853,14 @@ macro_rules! build_scly_conn_field {
         impl $struct_name
         {
             $(pub const $field: $struct_name = $struct_name($value);)+
+
+            pub fn name(&self) -> Option<&'static str>
+            {
+                match self.0 {
+                    $($value => Some(stringify!($field)),)+
+                    _ => None,
+                }
+            }
         }
 
         impl fmt::Debug for $struct_name
@@ -836,6 +886,11 @@ macro_rules! build_scly_conn_field {
                 $struct_name(i)
             }
 
+            fn try_read_from(reader: &mut Reader<'r>, (): Self::Args) -> Result<Self, ReadError>
+            {
+                Ok($struct_name(reader.try_read(())?))
+            }
+
             fn fixed_size() -> Option<usize>
             {
                 u32::fixed_size()
@@ -849,6 +904,18 @@ macro_rules! build_scly_conn_field {
                 self.0.write_to(writer)
             }
         }
+
+        // Known values are written by name, the same way the patcher's config spells them
+        impl Serialize for $struct_name
+        {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            {
+                match self.name() {
+                    Some(name) => serializer.serialize_str(name),
+                    None => serializer.serialize_u32(self.0),
+                }
+            }
+        }
     };
 }
   
@@ -8,12 +8,13 @@ use crate::scly_props::structs::{ActorParameters, AncsProp, DamageVulnerability,
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Actor<'r>
 {
     #[auto_struct(expect = 24)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -46,6 +47,7 @@ pub struct Actor<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Actor<'r>
 {
     const OBJECT_TYPE: u8 = 0x0;
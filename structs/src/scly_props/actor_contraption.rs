@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_props::structs::{DamageVulnerability, AnimationParameters,ActorParameters, HealthInfo, DamageInfo};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActorContraption<'r>
 {
     #[auto_struct(expect = 15)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -30,6 +31,7 @@ pub struct ActorContraption<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for ActorContraption<'r>
 {
     const OBJECT_TYPE: u8 = 0x6E;
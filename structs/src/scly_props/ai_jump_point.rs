@@ -4,14 +4,16 @@ use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AIJumpPoint<'r>
 {
     #[auto_struct(expect = 5)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -5,14 +5,16 @@ use reader_writer::generic_array::GenericArray;
 use crate::scly_props::structs::*;
 use crate::SclyPropertyData;
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AmbientAI<'r>
 {
     #[auto_struct(expect = 16)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
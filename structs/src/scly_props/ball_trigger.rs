@@ -8,12 +8,13 @@ use reader_writer::{
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BallTrigger<'r>
 {
     #[auto_struct(expect = 9)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
     pub position: GenericArray<f32, U3>,
     pub scale: GenericArray<f32, U3>,
@@ -26,6 +27,7 @@ pub struct BallTrigger<'r>
 }
 
 use crate::{impl_position, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for BallTrigger<'r>
 {
     const OBJECT_TYPE: u8 = 0x48;
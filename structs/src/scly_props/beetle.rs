@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_structs::*;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Beetle<'r>
 {
     #[auto_struct(expect = 16)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub flavor: f32,
@@ -35,6 +36,7 @@ pub struct Beetle<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Beetle<'r>
 {
     const OBJECT_TYPE: u8 = 0x16;
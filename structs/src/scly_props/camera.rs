@@ -6,12 +6,13 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Camera<'r>
 {
     #[auto_struct(expect = 15)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -37,6 +38,7 @@ pub struct Camera<'r>
 }
 
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Camera<'r>
 {
     const OBJECT_TYPE: u8 = 0x0C;
@@ -6,9 +6,10 @@ use reader_writer::{
     typenum::U3,
 };
 use crate::SclyPropertyData; 
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraHintParameters
 {
     #[auto_struct(expect = 15)]
@@ -38,7 +39,7 @@ pub struct CameraHintParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoolFloat
 {
     pub active: u8,
@@ -46,7 +47,7 @@ pub struct BoolFloat
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoolVec3
 {
     pub active: u8,
@@ -54,12 +55,13 @@ pub struct BoolVec3
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraHint<'r>
 {
     #[auto_struct(expect = 9)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
     pub position: GenericArray<f32, U3>,
     pub rotation: GenericArray<f32, U3>,
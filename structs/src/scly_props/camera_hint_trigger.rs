@@ -8,12 +8,13 @@ use reader_writer::{
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraHintTrigger<'r>
 {
     #[auto_struct(expect = 7)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
     pub position: GenericArray<f32, U3>,
     pub rotation: GenericArray<f32, U3>,
@@ -24,6 +25,7 @@ pub struct CameraHintTrigger<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for CameraHintTrigger<'r>
 {
     const OBJECT_TYPE: u8 = 0x73;
@@ -5,14 +5,16 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 use crate::scly_props::structs::*;
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChozoGhost<'r>
 {
     #[auto_struct(expect = 31)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -32,12 +34,13 @@ pub struct ChozoGhost<'r>
     pub wpsc2: u32,
     pub damage_info2: DamageInfo,
 
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub behave_chances: GenericArray<BehaveChance, U3>,
     pub dont_cares: GenericArray<u32, U14>,
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BehaveChance
 {
     #[auto_struct(expect = 7)]
@@ -2,14 +2,16 @@ use auto_struct_macros::auto_struct;
 
 use reader_writer::CStr;
 use crate::SclyPropertyData;
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Counter<'r>
 {
     #[auto_struct(expect = 5)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub start_value: u32,
@@ -9,12 +9,13 @@ use crate::scly_props::structs::{DamageVulnerability, HealthInfo, VisorParameter
 
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DamageableTrigger<'r>
 {
     #[auto_struct(expect = 12)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -35,6 +36,7 @@ pub struct DamageableTrigger<'r>
 }
 
 use crate::{impl_position, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for DamageableTrigger<'r>
 {
     const OBJECT_TYPE: u8 = 0x1A;
@@ -5,14 +5,16 @@ use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
 
 use crate::SclyPropertyData;
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DistanceFog<'r>
 {
     #[auto_struct(expect = 8)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub mode: u32,
@@ -6,12 +6,13 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Dock<'r>
 {
     #[auto_struct(expect = 7)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub active: u8,
@@ -23,6 +24,7 @@ pub struct Dock<'r>
 }
 
 use crate::{impl_position, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Dock<'r>
 {
     const OBJECT_TYPE: u8 = 0x0B;
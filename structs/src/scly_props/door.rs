@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_props::structs::{ActorParameters, AncsProp};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Door<'r>
 {
     #[auto_struct(expect = 14)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -34,6 +35,7 @@ pub struct Door<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Door<'r>
 {
     const OBJECT_TYPE: u8 = 0x03;
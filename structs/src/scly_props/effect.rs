@@ -9,12 +9,13 @@ use crate::scly_props::structs::LightParameters;
 use crate::res_id::*;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Effect<'r>
 {
     #[auto_struct(expect = 24)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -46,6 +47,7 @@ pub struct Effect<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Effect<'r>
 {
     const OBJECT_TYPE: u8 = 0x7;
@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_structs::*;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ElitePirate<'r>
 {
     #[auto_struct(expect = 42)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -58,6 +59,7 @@ pub struct ElitePirate<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for ElitePirate<'r>
 {
     const OBJECT_TYPE: u8 = 0x26;
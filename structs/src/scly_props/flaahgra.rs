@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_props::structs::{ActorParameters, AnimationParameters, DamageVulnerability, DamageInfo, PatternedInfo, HealthInfo};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Flaahgra<'r>
 {
     #[auto_struct(expect = 23)]
     pub prop_count: u32,
  
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -38,6 +39,7 @@ pub struct Flaahgra<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Flaahgra<'r>
 {
     const OBJECT_TYPE: u8 = 0x4D;
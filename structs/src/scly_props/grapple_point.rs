@@ -7,7 +7,7 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GrappleParams
 {
     #[auto_struct(expect = 12)]
@@ -29,12 +29,13 @@ pub struct GrappleParams
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GrapplePoint<'r>
 {
     #[auto_struct(expect = 5)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -46,6 +47,7 @@ pub struct GrapplePoint<'r>
 }
 
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for GrapplePoint<'r>
 {
     const OBJECT_TYPE: u8 = 0x30;
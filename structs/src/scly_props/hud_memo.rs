@@ -3,14 +3,16 @@ use auto_struct_macros::auto_struct;
 use reader_writer::CStr;
 use crate::{ResId, SclyPropertyData};
 use crate::res_id::*;
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HudMemo<'r>
 {
     #[auto_struct(expect = 6)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub first_message_timer: f32,
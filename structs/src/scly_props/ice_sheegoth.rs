@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_props::structs::{ActorParameters, DamageVulnerability, DamageInfo, PatternedInfo, HealthInfo};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IceSheegoth<'r>
 {
     #[auto_struct(expect = 37)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -22,6 +23,7 @@ pub struct IceSheegoth<'r>
     pub patterned_info: PatternedInfo,
     pub actor_params: ActorParameters,
     pub dont_care0: GenericArray<u32, U6>,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub damage_vulnerabilities: GenericArray<DamageVulnerability, U3>,
     pub dont_care1: u32,
     pub damage_info1: DamageInfo,
@@ -35,6 +37,7 @@ pub struct IceSheegoth<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for IceSheegoth<'r>
 {
     const OBJECT_TYPE: u8 = 0x4B;
@@ -5,14 +5,16 @@ use reader_writer::generic_array::GenericArray;
 use crate::scly_props::structs::*;
 use crate::SclyPropertyData;
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JellyZap<'r>
 {
     #[auto_struct(expect = 20)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
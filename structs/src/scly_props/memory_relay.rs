@@ -2,15 +2,17 @@ use auto_struct_macros::auto_struct;
 
 use crate::SclyPropertyData;
 use reader_writer::CStr;
+use serde::{Deserialize, Serialize};
 
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MemoryRelay<'r>
 {
     #[auto_struct(expect = 3)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
     pub unknown: u8,
     pub active: u8,
@@ -5,14 +5,16 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 use crate::scly_props::structs::*;
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Metroid<'r>
 {
     #[auto_struct(expect = 20)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub unknown1: u32,
@@ -28,6 +30,7 @@ pub struct Metroid<'r>
 
     pub dont_cares: GenericArray<f32, U6>,
 
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub animation_parameters: GenericArray<AnimationParameters, U4>,
 
     pub dont_care: u8,
@@ -7,13 +7,14 @@ use reader_writer::typenum::*;
 use reader_writer::CStr;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetroidPrimeStage1<'r> {
     #[auto_struct(expect = 22)]
     prop_count: u32,
 
     #[auto_struct(expect = 3)]
     pub version: u32,
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
     pub position: GenericArray<f32, U3>,
     pub rotation: GenericArray<f32, U3>,
@@ -28,6 +29,7 @@ pub struct MetroidPrimeStage1<'r> {
     pub health_info1: HealthInfo,
     pub health_info2: HealthInfo,
     pub unknown9: u32,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub exo_structs: GenericArray<ExoStructA, U4>,
     pub unknown10: u32,
     pub unknown11: u32,
@@ -35,7 +37,7 @@ pub struct MetroidPrimeStage1<'r> {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoStructA {
     #[auto_struct(expect = 14)]
     prop_count: u32,
@@ -43,15 +45,17 @@ pub struct ExoStructA {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoStructB {
     #[auto_struct(expect = 29)]
     prop_count: u32,
     pub patterned_info: PatternedInfo,
     pub actor_params: ActorParameters,
     pub unknown2: u32,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub shake_datas: GenericArray<CameraShakeData, U3>,
     pub exo_struct_ba: ExoStructBA,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub exo_struct_bb: GenericArray<ExoStructBB, U4>,
     pub wpsc1: u32,
     pub damage_info2: DamageInfo,
@@ -63,20 +67,22 @@ pub struct ExoStructB {
     pub damage_info4: DamageInfo,
     pub camera_shake_data3: CameraShakeData,
     pub dont_care: GenericArray<u32, U4>,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub exo_struct_bc: GenericArray<ExoStructBC, U4>,
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraShakeData { // PrimeStruct2
     pub use_sfx: u8,
     pub duration: f32,
     pub sfx_dist: f32,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub components: GenericArray<CameraShakerComponent, U3>,
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraShakerComponent {
     pub use_modulation: u8,
     pub am: CameraShakePoint,
@@ -84,13 +90,13 @@ pub struct CameraShakerComponent {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraShakePoint {
     pub dont_care: GenericArray<f32, U4>,
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoStructBA {
     #[auto_struct(expect = 9)]
     prop_count: u32,
@@ -105,7 +111,7 @@ pub struct ExoStructBA {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoStructBB {
     pub beam_info: BeamInfo,
     pub wpsc: u32,
@@ -116,7 +122,7 @@ pub struct ExoStructBB {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoStructBBA {
     #[auto_struct(expect = 8)]
     prop_count: u32,
@@ -124,7 +130,7 @@ pub struct ExoStructBBA {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoStructBC {
     #[auto_struct(expect = 4)]
     prop_count: u32,
@@ -134,7 +140,7 @@ pub struct ExoStructBC {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExoProjectileInfo {
     #[auto_struct(expect = 10)]
     prop_count: u32,
@@ -145,6 +151,7 @@ pub struct ExoProjectileInfo {
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for MetroidPrimeStage1<'r> {
     const OBJECT_TYPE: u8 = 0x84;
 
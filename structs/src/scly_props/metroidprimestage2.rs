@@ -8,12 +8,13 @@ use crate::res_id:: *;
 use crate::scly_props::structs::{ActorParameters, DamageInfo, PatternedInfo, DamageVulnerability, HealthInfo};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetroidPrimeStage2<'r>
 {
     #[auto_struct(expect = 11)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -29,6 +30,7 @@ pub struct MetroidPrimeStage2<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for MetroidPrimeStage2<'r>
 {
     const OBJECT_TYPE: u8 = 0x83;
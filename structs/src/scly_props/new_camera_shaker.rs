@@ -4,14 +4,16 @@ use reader_writer::CStr;
 use reader_writer::typenum::*;
 use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewCameraShaker<'r>
 {
     #[auto_struct(expect = 8)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -23,11 +25,12 @@ pub struct NewCameraShaker<'r>
     pub duration: f32,
     pub sfx_dist: f32,
 
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub shakers: GenericArray<NewCameraShakerComponent, U3>,
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewCameraShakerComponent
 {
     pub unknown1: u32,
@@ -37,7 +40,7 @@ pub struct NewCameraShakerComponent
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewCameraShakePoint
 {
     pub unknown1: u32,
@@ -8,12 +8,13 @@ use crate::SclyPropertyData;
 use crate::scly_props::structs::{ActorParameters, DamageInfo, PatternedInfo, DamageVulnerability, HealthInfo};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewIntroBoss<'r>
 {
     #[auto_struct(expect = 13)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -26,11 +27,14 @@ pub struct NewIntroBoss<'r>
     pub weapon_desc: f32,
     pub damage_info: DamageInfo,
 
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub particles: GenericArray<ResId<PART>, U2>,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub textures: GenericArray<ResId<TXTR>, U2>,
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for NewIntroBoss<'r>
 {
     const OBJECT_TYPE: u8 = 0x0E;
@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_structs::*;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OmegaPirate<'r>
 {
     #[auto_struct(expect = 45)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -39,6 +40,7 @@ pub struct OmegaPirate<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for OmegaPirate<'r>
 {
     const OBJECT_TYPE: u8 = 0x86;
@@ -5,14 +5,16 @@ use reader_writer::generic_array::GenericArray;
 use crate::scly_props::structs::*;
 use crate::SclyPropertyData;
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info_with_auxillary};
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhazonHealingNodule<'r>
 {
     #[auto_struct(expect = 9)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -25,6 +27,7 @@ pub struct PhazonHealingNodule<'r>
     pub dont_care1: u8,
     pub dont_care2: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub target_locator: CStr<'r>,
 }
 
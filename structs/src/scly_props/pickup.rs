@@ -9,12 +9,13 @@ use crate::res_id::*;
 
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pickup<'r>
 {
     #[auto_struct(expect = 18)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -42,6 +43,7 @@ pub struct Pickup<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Pickup<'r>
 {
     const OBJECT_TYPE: u8 = 0x11;
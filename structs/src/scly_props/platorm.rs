@@ -9,12 +9,13 @@ use crate::scly_props::structs::{ActorParameters, AncsProp, DamageVulnerability,
 
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Platform<'r>
 {
     #[auto_struct(expect = 19)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -43,6 +44,7 @@ pub struct Platform<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Platform<'r>
 {
     const OBJECT_TYPE: u8 = 0x8;
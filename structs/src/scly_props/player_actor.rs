@@ -10,12 +10,13 @@ use crate::scly_props::structs::{
 };
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerActor<'r>
 {
     #[auto_struct(expect = 19)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -43,7 +44,7 @@ pub struct PlayerActor<'r>
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerActorParams
 {
     #[auto_struct(derive = 5 + unknown5.is_some() as u32)]
@@ -59,6 +60,7 @@ pub struct PlayerActorParams
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for PlayerActor<'r>
 {
     const OBJECT_TYPE: u8 = 0x4c;
@@ -6,7 +6,7 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerHintStruct
 {
     #[auto_struct(expect = 15)]
@@ -30,12 +30,13 @@ pub struct PlayerHintStruct
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerHint<'r>
 {
     #[auto_struct(expect = 6)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -47,6 +48,7 @@ pub struct PlayerHint<'r>
 }
 
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for PlayerHint<'r>
 {
     const OBJECT_TYPE: u8 = 0x3E;
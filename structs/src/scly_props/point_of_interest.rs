@@ -7,12 +7,13 @@ use crate::scly_props::structs::ScannableParameters;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PointOfInterest<'r>
 {
     #[auto_struct(expect = 6)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -23,6 +24,7 @@ pub struct PointOfInterest<'r>
 }
 
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for PointOfInterest<'r>
 {
     const OBJECT_TYPE: u8 = 0x42;
@@ -9,12 +9,13 @@ use crate::res_id:: *;
 use crate::scly_props::structs::{ActorParameters, DamageInfo, PatternedInfo, RidleyStruct1, RidleyStruct2};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RidleyV1<'r>
 {
     #[auto_struct(expect = 48)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -24,6 +25,7 @@ pub struct RidleyV1<'r>
     pub patterned_info: PatternedInfo,
     pub actor_params: ActorParameters,
 
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub models: GenericArray<ResId<CMDL>, U12>,
     pub particle: ResId<PART>,
 
@@ -60,6 +62,7 @@ pub struct RidleyV1<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for RidleyV1<'r>
 {
     const OBJECT_TYPE: u8 = 0x7B;
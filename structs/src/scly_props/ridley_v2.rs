@@ -7,11 +7,12 @@ use crate::res_id:: *;
 use crate::scly_props::structs::{ActorParameters, DamageInfo, PatternedInfo, RidleyStruct1, RidleyStruct2};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RidleyV2<'r> {
     #[auto_struct(expect = 40)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -21,6 +22,7 @@ pub struct RidleyV2<'r> {
     pub patterned_info: PatternedInfo,
     pub actor_params: ActorParameters,
 
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub models: GenericArray<ResId<CMDL>, U2>,
     pub particle: ResId<PART>,
 
@@ -59,6 +61,7 @@ pub struct RidleyV2<'r> {
 }
 
 use crate::{impl_position, impl_rotation, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for RidleyV2<'r> {
     const OBJECT_TYPE: u8 = 0x7B;
     impl_position!();
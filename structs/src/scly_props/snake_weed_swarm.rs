@@ -8,12 +8,13 @@ use crate::scly_props::structs::{ActorParameters, AncsProp, DamageInfo};
 
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnakeWeedSwarm<'r>
 {
     #[auto_struct(expect = 25)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -48,6 +49,7 @@ pub struct SnakeWeedSwarm<'r>
 }
 
 use crate::{impl_position, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for SnakeWeedSwarm<'r>
 {
     const OBJECT_TYPE: u8 = 0x6D;
@@ -6,12 +6,13 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Sound<'r>
 {
     #[auto_struct(expect = 20)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -37,6 +38,7 @@ pub struct Sound<'r>
 }
 
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Sound<'r>
 {
     const OBJECT_TYPE: u8 = 0x9;
@@ -6,12 +6,13 @@ use reader_writer::generic_array::GenericArray;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpecialFunction<'r>
 {
     #[auto_struct(expect = 15)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -19,6 +20,7 @@ pub struct SpecialFunction<'r>
 
     pub type_: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub unknown0: CStr<'r>,
     pub unknown1: f32,
     pub unknown2: f32,
@@ -38,6 +40,7 @@ pub struct SpecialFunction<'r>
 }
 
 use crate::{impl_position, impl_rotation};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for SpecialFunction<'r>
 {
     const OBJECT_TYPE: u8 = 0x3A;
@@ -2,17 +2,20 @@ use auto_struct_macros::auto_struct;
 
 use reader_writer::CStr;
 use crate::SclyPropertyData;
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamedAudio<'r>
 {
     #[auto_struct(expect = 9)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub active: u8,
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub audio_file_name: CStr<'r>,
 
     pub no_stop_on_deactivate: u8,
@@ -5,9 +5,72 @@ use reader_writer::generic_array::GenericArray;
 
 use crate::ResId;
 use crate::res_id:: *;
+use serde::{Deserialize, Serialize};
+
+// Script object names are stored nul-terminated, but are much more readable as plain strings
+pub mod cstr_serde
+{
+    use reader_writer::CStr;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::{borrow::Cow, ffi::CString};
+
+    pub fn serialize<S: Serializer>(x: &CStr, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        serializer.serialize_str(&x.to_string_lossy())
+    }
+
+    pub fn deserialize<'de, 'r, D: Deserializer<'de>>(deserializer: D) -> Result<CStr<'r>, D::Error>
+    {
+        let string = String::deserialize(deserializer)?;
+        CString::new(string).map(Cow::Owned).map_err(D::Error::custom)
+    }
+}
+
+// generic_array's own Deserialize impl requires the element type to implement Default
+pub mod generic_array_serde
+{
+    use reader_writer::generic_array::{ArrayLength, GenericArray};
+    use serde::{de::{Error, SeqAccess, Visitor}, Deserialize, Deserializer};
+    use std::{fmt, marker::PhantomData};
+
+    struct ArrayVisitor<T, N>(PhantomData<(T, N)>);
+
+    impl<'de, T, N> Visitor<'de> for ArrayVisitor<T, N>
+        where T: Deserialize<'de>,
+              N: ArrayLength<T>,
+    {
+        type Value = GenericArray<T, N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+        {
+            write!(formatter, "{} elements", N::USIZE)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        {
+            let mut elems = Vec::with_capacity(N::USIZE);
+            while elems.len() < N::USIZE {
+                match seq.next_element()? {
+                    Some(elem) => elems.push(elem),
+                    None => return Err(A::Error::invalid_length(elems.len(), &self)),
+                }
+            }
+            Ok(GenericArray::from_exact_iter(elems).unwrap())
+        }
+    }
+
+    // Asks for exactly N elements (like generic_array does), so the length is known up front
+    pub fn deserialize<'de, T, N, D>(deserializer: D) -> Result<GenericArray<T, N>, D::Error>
+        where T: Deserialize<'de>,
+              N: ArrayLength<T>,
+              D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(N::USIZE, ArrayVisitor(PhantomData))
+    }
+}
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GrappleParameters
 {
     #[auto_struct(expect = 12)]
@@ -18,7 +81,7 @@ pub struct GrappleParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActorParameters
 {
     #[auto_struct(expect = 14)]
@@ -45,7 +108,7 @@ pub struct ActorParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnimationParameters
 {
     pub animation_character_set: u32,
@@ -54,7 +117,7 @@ pub struct AnimationParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AncsProp
 {
     pub file_id: ResId<ANCS>,
@@ -63,7 +126,7 @@ pub struct AncsProp
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LightParameters
 {
     #[auto_struct(expect = 14)]
@@ -86,7 +149,7 @@ pub struct LightParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScannableParameters
 {
     #[auto_struct(expect = 1)]
@@ -95,7 +158,7 @@ pub struct ScannableParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisorParameters
 {
     #[auto_struct(expect = 3)]
@@ -106,7 +169,7 @@ pub struct VisorParameters
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub struct DamageInfo
 {
     #[auto_struct(expect = 4)]
@@ -118,7 +181,7 @@ pub struct DamageInfo
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DamageVulnerability
 {
     #[auto_struct(expect = 18)]
@@ -157,7 +220,7 @@ pub enum TypeVulnerability {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChargedBeams
 {
     #[auto_struct(expect = 5)]
@@ -172,7 +235,7 @@ pub struct ChargedBeams
 
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BeamCombos
 {
     #[auto_struct(expect = 5)]
@@ -187,7 +250,7 @@ pub struct BeamCombos
 
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthInfo
 {
     #[auto_struct(expect = 2)]
@@ -198,7 +261,7 @@ pub struct HealthInfo
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatternedInfo
 {
     #[auto_struct(expect = 38)]
@@ -245,7 +308,7 @@ pub struct PatternedInfo
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BeamInfo {
     #[auto_struct(expect = 16)]
     prop_count: u32,
@@ -269,12 +332,14 @@ pub struct BeamInfo {
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RidleyStruct1
 {
     pub unknown0: u32,
     pub unknown1: u32,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub particles: GenericArray<ResId<PART>, U2>,
+    #[serde(deserialize_with = "crate::scly_props::structs::generic_array_serde::deserialize")]
     pub textures: GenericArray<ResId<TXTR>, U2>,
     pub unknown2: f32,
     pub unknown3: f32,
@@ -290,7 +355,7 @@ pub struct RidleyStruct1
 }
 
 #[auto_struct(Readable, Writable, FixedSize)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RidleyStruct2
 {
     pub unknown0: u32,
@@ -305,7 +370,7 @@ pub struct RidleyStruct2
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraShakerComponent
 {
     pub unknown1: u32,
@@ -315,7 +380,7 @@ pub struct CameraShakerComponent
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CameraShakePoint
 {
     pub unknown1: u32,
@@ -7,12 +7,13 @@ use crate::SclyPropertyData;
 use crate::scly_props::structs::{ActorParameters, PatternedInfo, DamageInfo, DamageVulnerability, HealthInfo};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Thardus<'r>
 {
     #[auto_struct(expect = 44)]
     pub prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -28,6 +29,7 @@ pub struct Thardus<'r>
 }
 
 use crate::{impl_position, impl_rotation, impl_scale, impl_patterned_info_with_auxillary};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Thardus<'r>
 {
     const OBJECT_TYPE: u8 = 0x58;
@@ -8,12 +8,13 @@ use crate::scly_props::structs::DamageInfo;
 use crate::SclyPropertyData;
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trigger<'r>
 {
     #[auto_struct(expect = 9)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub position: GenericArray<f32, U3>,
@@ -27,6 +28,7 @@ pub struct Trigger<'r>
 }
 
 use crate::{impl_position, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Trigger<'r>
 {
     const OBJECT_TYPE: u8 = 0x04;
@@ -8,12 +8,13 @@ use crate::scly_props::structs::DamageInfo;
 
 // https://github.com/AxioDL/metaforce/blob/1655d229cfdfbd5f792a7c3e84adc862653f70a7/DataSpec/DNAMP1/ScriptObjects/Water.hpp
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Water<'r>
 {
     #[auto_struct(expect = 63)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
     pub position: GenericArray<f32, U3>,
     pub scale: GenericArray<f32, U3>,
@@ -78,7 +79,7 @@ pub struct Water<'r>
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FluidUVMotion
 {
     pub fluid_layer_motion1: FluidLayerMotion,
@@ -89,7 +90,7 @@ pub struct FluidUVMotion
 }
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FluidLayerMotion
 {
     pub fluid_uv_motion: u32,
@@ -100,6 +101,7 @@ pub struct FluidLayerMotion
 }
 
 use crate::{impl_position, impl_scale};
+use serde::{Deserialize, Serialize};
 impl<'r> SclyPropertyData for Water<'r>
 {
     const OBJECT_TYPE: u8 = 0x20;
@@ -15,14 +15,16 @@ use std::{
     borrow::Cow,
     ffi::CString
 };
+use serde::{Deserialize, Serialize};
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldTransporter<'r>
 {
     #[auto_struct(derive = 21 + 5 * pal_additions.is_some() as u32)]
     prop_count: u32,
 
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub name: CStr<'r>,
 
     pub active: u8,
@@ -52,9 +54,10 @@ pub struct WorldTransporter<'r>
 
 
 #[auto_struct(Readable, Writable)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldTransporterPalAdditions<'r>
 {
+    #[serde(with = "crate::scly_props::structs::cstr_serde")]
     pub audio_stream: CStr<'r>,
     pub unknown0: u8,
     pub unknown1: f32,
@@ -5,7 +5,7 @@ use reader_writer::{
     RoArrayIter,
 };
 
-static SUPPORTED_LANGUAGES: &'static [&[u8; 4]] = &[
+pub static SUPPORTED_LANGUAGES: &'static [&[u8; 4]] = &[
     b"ENGL",
     b"DUTC",
     b"FREN",
@@ -15,11 +15,23 @@ static SUPPORTED_LANGUAGES: &'static [&[u8; 4]] = &[
     b"SPAN",
 ];
 
+#[derive(Clone, Copy)]
 pub enum Languages {
     All,
     Some(&'static [&'static [u8; 4]])
 }
 
+impl Languages
+{
+    /// Selects a single language by its STRG code (e.g. `ENGL` or `FREN`)
+    pub fn from_code(code: &[u8]) -> Option<Languages>
+    {
+        SUPPORTED_LANGUAGES.iter()
+            .position(|lang| &lang[..] == code)
+            .map(|i| Languages::Some(&SUPPORTED_LANGUAGES[i..i + 1]))
+    }
+}
+
 #[auto_struct(Readable, Writable)]
 #[derive(Debug, Clone)]
 pub struct Strg<'r>
@@ -58,6 +70,14 @@ impl<'r> Strg<'r>
     }
 
     pub fn add_strings(self: &mut Self, strings: &[String], languages: Languages)
+    {
+        self.add_strings_per_language(languages, |_| strings.to_vec())
+    }
+
+    /// Like `add_strings`, but the strings appended to each table are chosen by its language.
+    /// Tables are padded with empty strings so that they all end up the same length.
+    pub fn add_strings_per_language<F>(&mut self, languages: Languages, mut strings: F)
+        where F: FnMut(&[u8; 4]) -> Vec<String>
     {
         let languages = match languages {
             Languages::All => SUPPORTED_LANGUAGES,
@@ -66,7 +86,7 @@ impl<'r> Strg<'r>
         let is_jpn = Self::is_jpn_version(languages.clone());
         for table in self.string_tables.as_mut_vec().iter_mut() {
             if languages.contains(&table.lang.as_bytes()) {
-                for string in strings.iter() {
+                for string in strings(table.lang.as_bytes()).iter() {
                     if is_jpn {
                         table.strings.as_mut_vec().push(format!("&line-extra-space=4;&font=C29C51F1;{}", string).into());
                     } else {
@@ -75,6 +95,21 @@ impl<'r> Strg<'r>
                 }
             }
         }
+        self.pad_string_tables();
+    }
+
+    fn pad_string_tables(&mut self)
+    {
+        let string_count = self.string_tables.iter()
+            .map(|table| table.strings.len())
+            .max()
+            .unwrap_or(0);
+        for table in self.string_tables.as_mut_vec().iter_mut() {
+            let strings = table.strings.as_mut_vec();
+            while strings.len() < string_count {
+                strings.push("\0".to_string().into());
+            }
+        }
     }
 
     pub fn edit_strings(self: &mut Self, (from, to): (String, String), languages: Languages)
@@ -104,6 +139,24 @@ impl<'r> Strg<'r>
         }
     }
 
+    /// Builds a table for each of `languages`, with the strings for each one chosen by the
+    /// caller. Tables are padded with empty strings so that they all end up the same length.
+    pub fn from_strings_per_language<F>(languages: &[&[u8; 4]], mut strings: F) -> Strg<'r>
+        where F: FnMut(&[u8; 4]) -> Vec<String>
+    {
+        let mut strg = Strg {
+            string_tables: languages.iter()
+                .map(|lang| StrgStringTable {
+                    lang: (*lang).into(),
+                    strings: strings(lang).into_iter().map(|i| i.into()).collect::<Vec<_>>().into(),
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        };
+        strg.pad_string_tables();
+        strg
+    }
+
     pub fn from_strings_jpn(strings: Vec<String>) -> Strg<'r>
     {
         let strings: LazyArray<LazyUtf16beStr> = strings.into_iter().map(|i| format!("&line-extra-space=4;&font=C29C51F1;{}", i).into()).collect::<Vec<_>>().into();
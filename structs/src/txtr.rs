@@ -1,6 +1,6 @@
 use auto_struct_macros::auto_struct;
 
-use reader_writer::generic_array::GenericArray;
+use reader_writer::generic_array::{ArrayLength, GenericArray};
 use reader_writer::generic_array::typenum::{U32, U512};
 use reader_writer::{IteratorArray, LazyArray, RoArray, Reader};
 
@@ -67,7 +67,7 @@ pub struct Txtr<'r>
     #[auto_struct(derive = format.palette())]
     palette: Option<TxtrPalette<'_>>,
 
-    #[auto_struct(literal = TxtrFormat::new(hdr_format, &palette))]
+    #[auto_struct(try_literal = TxtrFormat::new(hdr_format, &palette))]
     pub format: TxtrFormat,
 
     #[auto_struct(init = MipmapSizeIter::new(width, height, format.txtr_format(), mipmap_count))]
@@ -94,33 +94,41 @@ pub enum TxtrFormat
 
 impl TxtrFormat
 {
-    fn new(fmt: u32, palette: &Option<TxtrPalette>) -> Self
+    fn new(fmt: u32, palette: &Option<TxtrPalette>) -> Result<Self, String>
     {
-        match fmt {
+        Ok(match fmt {
             0x0 => TxtrFormat::I4,
             0x1 => TxtrFormat::I8,
             0x2 => TxtrFormat::Ia4,
             0x3 => TxtrFormat::Ia8,
             0x4 => {
-                let palette = palette.as_ref().unwrap();
-                TxtrFormat::C4(
-                    TxtrPaletteFormat::from_u32(palette.format),
-                    Box::new(palette.color_data.iter().collect()),
-                )
+                let (format, color_data) = TxtrFormat::palette_parts(palette)?;
+                TxtrFormat::C4(format, color_data)
             },
             0x5 => {
-                let palette = palette.as_ref().unwrap();
-                TxtrFormat::C8(
-                    TxtrPaletteFormat::from_u32(palette.format),
-                    Box::new(palette.color_data.iter().collect()),
-                )
+                let (format, color_data) = TxtrFormat::palette_parts(palette)?;
+                TxtrFormat::C8(format, color_data)
             },
             0x7 => TxtrFormat::Rgb565,
             0x8 => TxtrFormat::Rgb5A3,
             0x9 => TxtrFormat::Rgba8,
             0xa => TxtrFormat::Cmpr,
-            fmt => panic!("Unknown or unsupported TXTR format: {:#x}", fmt),
-        }
+            fmt => return Err(format!("Unknown or unsupported TXTR format: {:#x}", fmt)),
+        })
+    }
+
+    fn palette_parts<N>(palette: &Option<TxtrPalette>)
+        -> Result<(TxtrPaletteFormat, Box<GenericArray<u8, N>>), String>
+        where N: ArrayLength<u8>
+    {
+        // has_palette guarantees the palette was read
+        let palette = palette.as_ref().unwrap();
+        let color_data = GenericArray::from_exact_iter(palette.color_data.iter())
+            .ok_or_else(|| format!(
+                "Expected a TXTR palette of {} bytes, found {}",
+                N::USIZE, palette.color_data.len(),
+            ))?;
+        Ok((TxtrPaletteFormat::from_u32(palette.format)?, Box::new(color_data)))
     }
 
     fn txtr_format(&self) -> u32
@@ -170,13 +178,13 @@ pub enum TxtrPaletteFormat
 
 impl TxtrPaletteFormat
 {
-    fn from_u32(x: u32) -> Self
+    fn from_u32(x: u32) -> Result<Self, String>
     {
         match x {
-            0x0 => TxtrPaletteFormat::Ia8,
-            0x1 => TxtrPaletteFormat::Rgb565,
-            0x2 => TxtrPaletteFormat::Rgb5A3,
-            _ => panic!("Invalid TXTR palette format {:#x}", x),
+            0x0 => Ok(TxtrPaletteFormat::Ia8),
+            0x1 => Ok(TxtrPaletteFormat::Rgb565),
+            0x2 => Ok(TxtrPaletteFormat::Rgb5A3),
+            _ => Err(format!("Invalid TXTR palette format {:#x}", x)),
         }
     }
 }
@@ -0,0 +1,313 @@
+use std::{borrow::Cow, ffi::CString, fmt};
+
+use proptest::{
+    prelude::*,
+    test_runner::{RngAlgorithm, TestRng},
+};
+use reader_writer::{
+    generic_array::{ArrayLength, GenericArray},
+    CStr, FourCC, Readable, Reader, RoArray, Writable,
+};
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, SeqAccess, Visitor};
+use structs::ResId;
+
+/// Source of random values for the generators. Each test case gets its own seed from proptest,
+/// so a failing case can be replayed.
+pub struct Gen(TestRng);
+
+impl Gen
+{
+    pub fn new(seed: [u8; 32]) -> Gen
+    {
+        Gen(TestRng::from_seed(RngAlgorithm::ChaCha, &seed))
+    }
+
+    pub fn gen<T: Random>(&mut self) -> T
+    {
+        T::random(self)
+    }
+
+    pub fn below(&mut self, n: usize) -> usize
+    {
+        self.0.random_range(0..n)
+    }
+
+    pub fn chance(&mut self) -> bool
+    {
+        self.0.random()
+    }
+
+    pub fn vec<T>(&mut self, max_len: usize, mut f: impl FnMut(&mut Gen) -> T) -> Vec<T>
+    {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    pub fn bytes(&mut self, len: usize) -> Vec<u8>
+    {
+        (0..len).map(|_| self.0.random()).collect()
+    }
+
+    // Short and printable, so failures stay readable
+    pub fn string(&mut self) -> String
+    {
+        let len = self.below(12);
+        (0..len).map(|_| self.0.random_range(b' '..=b'~') as char).collect()
+    }
+
+    pub fn cstr(&mut self) -> CStr<'static>
+    {
+        Cow::Owned(CString::new(self.string()).unwrap())
+    }
+
+    pub fn array<T, N: ArrayLength<T>>(&mut self, mut f: impl FnMut(&mut Gen) -> T)
+        -> GenericArray<T, N>
+    {
+        GenericArray::from_exact_iter((0..N::USIZE).map(|_| f(self))).unwrap()
+    }
+
+    pub fn opt<T>(&mut self, f: impl FnOnce(&mut Gen) -> T) -> Option<T>
+    {
+        if self.chance() {
+            Some(f(self))
+        } else {
+            None
+        }
+    }
+}
+
+/// Builds a `T` from every seed proptest picks
+pub fn generated<T: fmt::Debug>(f: fn(&mut Gen) -> T) -> impl Strategy<Value = T>
+{
+    any::<[u8; 32]>().prop_map(move |seed| f(&mut Gen::new(seed)))
+}
+
+pub trait Random
+{
+    fn random(g: &mut Gen) -> Self;
+}
+
+macro_rules! impl_random {
+    ($($t:ty),*) => {
+        $(
+        impl Random for $t
+        {
+            fn random(g: &mut Gen) -> Self
+            {
+                g.0.random()
+            }
+        }
+        )*
+    };
+}
+
+impl_random!(u8, u16, u32, u64, i32);
+
+// NaN would never compare equal to itself; anything finite survives the trip bit-for-bit
+impl Random for f32
+{
+    fn random(g: &mut Gen) -> Self
+    {
+        g.0.random_range(-1e6..1e6)
+    }
+}
+
+impl Random for FourCC
+{
+    fn random(g: &mut Gen) -> Self
+    {
+        FourCC::from_bytes(&g.0.random())
+    }
+}
+
+impl<K> Random for ResId<K>
+{
+    fn random(g: &mut Gen) -> Self
+    {
+        ResId::new(g.gen())
+    }
+}
+
+impl Random for CStr<'static>
+{
+    fn random(g: &mut Gen) -> Self
+    {
+        g.cstr()
+    }
+}
+
+impl<T: Random, N: ArrayLength<T>> Random for GenericArray<T, N>
+{
+    fn random(g: &mut Gen) -> Self
+    {
+        g.array(T::random)
+    }
+}
+
+pub fn to_bytes<T: Writable>(value: &T) -> Vec<u8>
+{
+    let mut bytes = vec![];
+    value.write_to(&mut bytes).unwrap();
+    bytes
+}
+
+/// A `RoArray` can only be read, never built from values, so write the elements out first. The
+/// buffer is leaked so that the array can outlive the test case.
+pub fn ro_array<T>(elems: Vec<T>, args: T::Args) -> RoArray<'static, T>
+    where T: Readable<'static> + Writable,
+          T::Args: Clone,
+{
+    let bytes = elems.iter().flat_map(to_bytes).collect::<Vec<_>>();
+    Reader::new(Box::leak(bytes.into_boxed_slice())).read((elems.len(), args))
+}
+
+/// A deserializer that makes up every value it's asked for. Driving a type's `Deserialize` impl
+/// with it builds a random instance of any of the `scly_props` types without naming their fields.
+pub struct RandomDeserializer<'g>(pub &'g mut Gen);
+
+impl<'g> RandomDeserializer<'g>
+{
+    fn elems(&mut self, len: usize) -> RandomSeq<'_>
+    {
+        RandomSeq { gen: self.0, remaining: len }
+    }
+}
+
+struct RandomSeq<'g>
+{
+    gen: &'g mut Gen,
+    remaining: usize,
+}
+
+impl<'de, 'g> SeqAccess<'de> for RandomSeq<'g>
+{
+    type Error = de::value::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(RandomDeserializer(self.gen)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize>
+    {
+        Some(self.remaining)
+    }
+}
+
+macro_rules! random_primitives {
+    ($($method:ident => $visit:ident($t:ty),)*) => {
+        $(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error>
+        {
+            visitor.$visit(self.0.gen::<$t>())
+        }
+        )*
+    };
+}
+
+impl<'de, 'g> Deserializer<'de> for RandomDeserializer<'g>
+{
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    {
+        Err(de::Error::custom("the random deserializer needs a type hint"))
+    }
+
+    random_primitives! {
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_f32 => visit_f32(f32),
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_bool(self.0.chance())
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_string(self.0.string())
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        if self.0.chance() {
+            visitor.visit_some(self)
+        } else {
+            visitor.visit_none()
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V)
+        -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V)
+        -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(mut self, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        let len = self.0.below(4);
+        visitor.visit_seq(self.elems(len))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(mut self, len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_seq(self.elems(len))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(mut self, _name: &'static str, len: usize, visitor: V)
+        -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_seq(self.elems(len))
+    }
+
+    // Derived impls accept a struct as a sequence of its fields, in order
+    fn deserialize_struct<V: Visitor<'de>>(
+        mut self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    {
+        visitor.visit_seq(self.elems(fields.len()))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    {
+        let variant = variants[self.0.below(variants.len())];
+        visitor.visit_enum(variant.into_deserializer())
+    }
+
+    serde::forward_to_deserialize_any! {
+        i8 i16 i64 i128 u128 f64 char bytes byte_buf map identifier ignored_any
+    }
+}
@@ -0,0 +1,1151 @@
+//! Builds random instances of the formats in `structs`, writes them, reads the bytes back and
+//! checks that:
+//!  - `size()` agrees with the number of bytes written (before and after the trip),
+//!  - reading consumes exactly the bytes that were written,
+//!  - writing the copy that was read back produces the same bytes again,
+//!  - the copy that was read back holds the same values as the original.
+//!
+//! Types that derive `Deserialize` (all of `scly_props`) are generated by `RandomDeserializer`.
+//! Everything else has a hand-written generator below, which only builds instances the game
+//! could contain (e.g. fields that only exist for some versions are set to match the version).
+
+mod gen;
+mod scly;
+
+use std::fmt;
+
+use proptest::prelude::*;
+use reader_writer::{LazyArray, Readable, Reader, RoArray, Uncached, Writable};
+use structs::{
+    mlvl::Dock, AdditiveAnimation, Ancs, AnimTime, Animation, AnimationAABB,
+    AnimationIndexedAABB, AnimationName, AnimationResource, AnimationSet, Area, AreaDependencies,
+    AreaLayerFlags, AudioGroup, Bnr, BnrMetadata, CameraWidget, CharacterInfo, CharacterSet,
+    Ctwk, CtwkGame, Dependency, DockConnection, EffectComponent, EffectEvent, EnergyWidget,
+    EventBase, Evnt, Frme, FrmeWidget, FrmeWidgetKind, GroupWidget, HalfTransition, ImageWidget,
+    LayerToggle, LightLayer, LightWidget, Lights, LoopEvent, Mapa, MapaBorder, MapaObject,
+    MapaPrimitive, MapaSurface, MapaSurfaceHeader, MemoryRelayConn, MeterWidget, MetaAnimation,
+    MetaAnimationBlend, MetaAnimationPlay, MetaAnimationRandom, MetaAnimationRandomPair,
+    MetaAnimationSequence, MetaTransition, MetaTransitionAnimation, MetaTransitionTransition,
+    Mlvl, ModelWidget, Mrea, MreaSection, NamedResource, Pak, PaneWidget, ParticleResData,
+    PasAnimState, PasAnimStateAnimInfo, PasAnimStateAnimInfoInner, PasAnimStateParamInfo,
+    PasDatabase, Resource, ResourceKind, Savw, Scan, ScanImage, ScannableObject, SliderWidget,
+    SoundEvent, Strg, StrgStringTable, TableGroupWidget, TextPaneWidget, Thp, ThpAudioInfo,
+    ThpComponent, ThpComponents, ThpFrameData, ThpVideoInfo, Transition, Txtr, TxtrFormat,
+    TxtrPaletteFormat, UserEvent, SUPPORTED_LANGUAGES,
+};
+
+use gen::{generated, ro_array, to_bytes, Gen};
+
+/// Writes `value`, reads it back (with `args`) and checks the sizes and bytes. Returns the copy
+/// that was read so the caller can compare it with the original.
+fn round_trip<T>(value: &T, args: T::Args) -> Result<T, TestCaseError>
+    where T: Readable<'static> + Writable,
+{
+    let bytes = to_bytes(value);
+    prop_assert_eq!(value.size(), bytes.len(), "size() doesn't match the bytes written");
+
+    let bytes: &'static [u8] = Box::leak(bytes.into_boxed_slice());
+    let mut reader = Reader::new(bytes);
+    let read: T = reader.read(args);
+    prop_assert_eq!(reader.len(), 0, "reading left bytes behind");
+    prop_assert_eq!(read.size(), bytes.len(), "size() changed after reading");
+    prop_assert!(to_bytes(&read) == bytes, "writing the copy read back changed the bytes");
+    Ok(read)
+}
+
+// Nothing here implements PartialEq, so compare the Debug output. Readers only print their
+// address, and strings read back from a file are `Borrowed` where generated ones are `Owned`.
+fn debug_string<T: fmt::Debug>(value: &T) -> String
+{
+    let mut s = format!("{:?}", value).replace("Borrowed(", "Owned(");
+    while let Some(start) = s.find("Reader(0x") {
+        let end = start + s[start..].find(')').unwrap();
+        s.replace_range(start..=end, "Reader");
+    }
+    s
+}
+
+fn assert_same<T: fmt::Debug>(original: &T, read: &T) -> Result<(), TestCaseError>
+{
+    prop_assert_eq!(debug_string(original), debug_string(read));
+    Ok(())
+}
+
+fn reader(g: &mut Gen, len: usize) -> Reader<'static>
+{
+    Reader::new(Box::leak(g.bytes(len).into_boxed_slice()))
+}
+
+fn bytes_array(g: &mut Gen, len: usize) -> RoArray<'static, u8>
+{
+    let bytes = g.bytes(len);
+    ro_array(bytes, ())
+}
+
+#[cfg(not(debug_assertions))]
+fn resource(g: &mut Gen, kind: ResourceKind<'static>) -> Resource<'static>
+{
+    Resource {
+        compressed: false,
+        file_id: g.gen(),
+        kind,
+    }
+}
+
+#[cfg(debug_assertions)]
+fn resource(g: &mut Gen, kind: ResourceKind<'static>) -> Resource<'static>
+{
+    Resource {
+        compressed: false,
+        file_id: g.gen(),
+        kind,
+        original_offset: 0,
+    }
+}
+
+/* STRG */
+
+fn strg(g: &mut Gen) -> Strg<'static>
+{
+    let lang_count = 1 + g.below(SUPPORTED_LANGUAGES.len());
+    let string_count = g.below(5);
+    Strg {
+        string_tables: SUPPORTED_LANGUAGES[..lang_count].iter()
+            .map(|lang| StrgStringTable {
+                lang: (*lang).into(),
+                strings: (0..string_count)
+                    .map(|_| format!("{}\0", g.string()).into())
+                    .collect::<Vec<_>>()
+                    .into(),
+            })
+            .collect::<Vec<_>>()
+            .into(),
+    }
+}
+
+/* SAVW */
+
+fn savw(g: &mut Gen) -> Savw<'static>
+{
+    Savw {
+        area_count: g.gen(),
+        cinematic_skip_array: g.vec(4, |g| g.gen()).into(),
+        memory_relay_array: ro_array(g.vec(4, |g| g.gen()), ()),
+        layer_toggle_array: ro_array(g.vec(4, |g| LayerToggle {
+            area_id: g.gen(),
+            layer_index: g.gen(),
+        }), ()),
+        door_array: ro_array(g.vec(4, |g| g.gen()), ()),
+        scan_array: g.vec(4, |g| ScannableObject {
+            scan: g.gen(),
+            logbook_category: g.gen(),
+        }).into(),
+    }
+}
+
+/* SCAN */
+
+fn scan(g: &mut Gen) -> Scan<'static>
+{
+    Scan {
+        frme: g.gen(),
+        strg: g.gen(),
+        scan_speed: g.gen(),
+        category: g.gen(),
+        icon_flag: g.gen(),
+        images: g.array(|g| ScanImage {
+            txtr: g.gen(),
+            appearance_percent: g.gen(),
+            image_position: g.gen(),
+            width: g.gen(),
+            height: g.gen(),
+            interval: g.gen(),
+            fade_duration: g.gen(),
+        }),
+        padding: g.gen(),
+        _dummy: std::marker::PhantomData,
+    }
+}
+
+/* EVNT */
+
+fn event_base(g: &mut Gen) -> EventBase<'static>
+{
+    EventBase {
+        unknown0: g.gen(),
+        name: g.cstr(),
+        event_type: g.gen(),
+        timestamp: AnimTime {
+            timestamp: g.gen(),
+            differential_state: g.gen(),
+        },
+        event_index: g.gen(),
+        unknown1: g.gen(),
+        weight: g.gen(),
+        character_index: g.gen(),
+        unknown2: g.gen(),
+    }
+}
+
+fn evnt(g: &mut Gen) -> Evnt<'static>
+{
+    Evnt {
+        loop_events: ro_array(g.vec(3, |g| LoopEvent {
+            base: event_base(g),
+            unknown: g.gen(),
+        }), ()),
+        user_events: ro_array(g.vec(3, |g| UserEvent {
+            base: event_base(g),
+            event_type: g.gen(),
+            bone_name: g.cstr(),
+        }), ()),
+        effect_events: g.vec(3, |g| EffectEvent {
+            base: event_base(g),
+            frame_count: g.gen(),
+            effect_type: g.gen(),
+            effect_file_id: g.gen(),
+            bone_name: g.cstr(),
+            scale: g.gen(),
+            transform_type: g.gen(),
+        }).into(),
+        // Version 1 files stop before the sound events
+        sound_events: g.opt(|g| ro_array(g.vec(3, |g| SoundEvent {
+            base: event_base(g),
+            sound_id: g.gen(),
+            reference_amplitude: g.gen(),
+            reference_distance: g.gen(),
+        }), ())),
+    }
+}
+
+/* BNR */
+
+fn bnr_metadata(g: &mut Gen) -> BnrMetadata
+{
+    BnrMetadata {
+        game_name: g.gen(),
+        developer: g.gen(),
+        game_name_full: g.gen(),
+        developer_full: g.gen(),
+        description: g.gen(),
+    }
+}
+
+fn bnr(g: &mut Gen) -> Bnr<'static>
+{
+    Bnr {
+        padding: bytes_array(g, 0x1C),
+        pixels: g.gen(),
+        english_fields: bnr_metadata(g),
+        // BNR2 (PAL) banners have metadata for the other languages too
+        other_lang_fields: g.opt(|g| g.array(bnr_metadata)),
+    }
+}
+
+/* MAPA */
+
+fn mapa(g: &mut Gen) -> Mapa<'static>
+{
+    let surface_count = g.below(3);
+    Mapa {
+        type_: g.gen(),
+        visibility_mode: g.gen(),
+        aabb: g.gen(),
+        objects: g.vec(3, |g| MapaObject {
+            type_: g.gen(),
+            visibility_mode: g.gen(),
+            editor_id: g.gen(),
+            seed1: g.gen(),
+            transform_matrix: g.gen(),
+            seek2: g.gen(),
+        }).into(),
+        vertices: g.vec(5, |g| g.gen()).into(),
+        surface_headers: (0..surface_count).map(|_| MapaSurfaceHeader {
+            center: g.gen(),
+            center_of_mass: g.gen(),
+            primitive_table_start: g.gen(),
+            border_table_start: g.gen(),
+        }).collect::<Vec<_>>().into(),
+        surfaces: (0..surface_count).map(|_| MapaSurface {
+            primitives: ro_array(g.vec(3, |g| {
+                let len = g.below(8);
+                MapaPrimitive {
+                    type_: g.gen(),
+                    indices: bytes_array(g, len),
+                }
+            }), ()),
+            borders: ro_array(g.vec(3, |g| {
+                let len = g.below(8);
+                MapaBorder { indices: bytes_array(g, len) }
+            }), ()),
+        }).collect::<Vec<_>>().into(),
+    }
+}
+
+/* MLVL */
+
+fn area(g: &mut Gen) -> Area<'static>
+{
+    let attached_areas = g.vec(3, |g| g.gen());
+    Area {
+        area_name_strg: g.gen(),
+        area_transform: g.gen(),
+        area_bounding_box: g.gen(),
+        mrea: g.gen(),
+        internal_id: g.gen(),
+        attached_area_count: attached_areas.len() as u32,
+        attached_areas: attached_areas.into(),
+        dependencies: AreaDependencies {
+            deps: g.vec(3, |g| g.vec(3, |g| Dependency {
+                asset_id: g.gen(),
+                asset_type: g.gen(),
+            }).into()).into(),
+        },
+        docks: g.vec(2, |g| Dock {
+            connecting_docks: g.vec(2, |g| DockConnection {
+                array_index: g.gen(),
+                dock_index: g.gen(),
+            }).into(),
+            dock_coordinates: g.vec(4, |g| g.gen()).into(),
+        }).into(),
+    }
+}
+
+fn mlvl(g: &mut Gen) -> Mlvl<'static>
+{
+    // Every world has at least one area
+    let area_count = 1 + g.below(3);
+    Mlvl {
+        world_name_strg: g.gen(),
+        world_savw: g.gen(),
+        default_skybox_cmdl: g.gen(),
+        memory_relay_conns: g.vec(3, |g| MemoryRelayConn {
+            sender_id: g.gen(),
+            target_id: g.gen(),
+            message: g.gen(),
+            active: g.gen(),
+        }).into(),
+        areas: (0..area_count).map(|_| area(g)).collect::<Vec<_>>().into(),
+        world_map_mapw: g.gen(),
+        audio_groups: ro_array(g.vec(3, |g| AudioGroup {
+            group_id: g.gen(),
+            agsc: g.gen(),
+        }), ()),
+        area_layer_flags: (0..area_count).map(|_| AreaLayerFlags {
+            layer_count: g.gen(),
+            flags: g.gen(),
+        }).collect::<Vec<_>>().into(),
+        area_layer_names: (0..area_count)
+            .map(|_| g.vec(3, |g| g.cstr()))
+            .collect::<Vec<_>>()
+            .into(),
+    }
+}
+
+/* MREA */
+
+fn lights(g: &mut Gen) -> Lights<'static>
+{
+    Lights {
+        light_layers: g.vec(3, |g| LightLayer {
+            light_type: g.gen(),
+            color: g.gen(),
+            position: g.gen(),
+            direction: g.gen(),
+            brightness: g.gen(),
+            spot_cutoff: g.gen(),
+            unknown0: g.gen(),
+            unknown1: g.gen(),
+            unknown2: g.gen(),
+            falloff_type: g.gen(),
+            unknown3: g.gen(),
+        }).into(),
+    }
+}
+
+fn mrea(g: &mut Gen) -> Mrea<'static>
+{
+    // Sections that aren't parsed are kept as raw bytes
+    let mut sections = g.vec(4, |g| {
+        let len = 32 * g.below(4);
+        MreaSection::Unknown(reader(g, len))
+    });
+    let scly_section_idx = g.below(sections.len() + 1);
+    sections.insert(scly_section_idx, MreaSection::Scly(scly::scly(g)));
+    let lights_section_idx = g.below(sections.len() + 1);
+    sections.insert(lights_section_idx, MreaSection::Lights(lights(g)));
+    let scly_section_idx = scly_section_idx + (lights_section_idx <= scly_section_idx) as usize;
+
+    Mrea {
+        area_transform: g.gen(),
+        world_model_count: g.gen(),
+        world_geometry_section_idx: g.gen(),
+        scly_section_idx: scly_section_idx as u32,
+        collision_section_idx: g.gen(),
+        unknown_section_idx: g.gen(),
+        lights_section_idx: lights_section_idx as u32,
+        visibility_tree_section_idx: g.gen(),
+        path_section_idx: g.gen(),
+        area_octree_section_idx: g.gen(),
+        sections: sections.into(),
+    }
+}
+
+fn parse_mrea(mrea: &mut Mrea)
+{
+    scly::parse_scly(mrea.scly_section_mut());
+    mrea.lights_section_mut();
+}
+
+/* PAK */
+
+fn resource_kind(g: &mut Gen) -> ResourceKind<'static>
+{
+    match g.below(9) {
+        0 => ResourceKind::Strg(strg(g)),
+        1 => ResourceKind::Savw(savw(g)),
+        2 => ResourceKind::Scan(scan(g)),
+        3 => ResourceKind::Evnt(evnt(g)),
+        4 => ResourceKind::Mapa(mapa(g)),
+        5 => ResourceKind::Frme(frme(g)),
+        6 => ResourceKind::Mrea(mrea(g)),
+        7 => ResourceKind::Mlvl(mlvl(g)),
+        _ => {
+            let len = g.below(64);
+            ResourceKind::Unknown(reader(g, len), b"TXTR".into())
+        },
+    }
+}
+
+fn pak(g: &mut Gen) -> Pak<'static>
+{
+    Pak {
+        start: Reader::new(&[]),
+        unused: g.gen(),
+        named_resources: ro_array(g.vec(3, |g| {
+            let name = g.string().into_bytes();
+            NamedResource {
+                fourcc: g.gen(),
+                file_id: g.gen(),
+                name_length: name.len() as u32,
+                name: ro_array(name, ()),
+            }
+        }), ()),
+        resources: g.vec(4, |g| {
+            let kind = resource_kind(g);
+            resource(g, kind)
+        }).into_iter().collect(),
+    }
+}
+
+/// Resources are read back as `ResourceKind::Unknown`; parse them (and anything inside them
+/// that's also read lazily) so they can be compared
+fn parse_resources(pak: &Pak) -> Vec<String>
+{
+    pak.resources.iter()
+        .map(|res| {
+            let mut kind = res.kind.clone();
+            kind.guess_kind();
+            if let ResourceKind::Mrea(mrea) = &mut kind {
+                parse_mrea(mrea);
+            }
+            format!("{} {:08X} {}", res.compressed, res.file_id, debug_string(&kind))
+        })
+        .collect()
+}
+
+/* FRME */
+
+fn text_pane_widget(g: &mut Gen, version: u32) -> TextPaneWidget
+{
+    TextPaneWidget {
+        x_dim: g.gen(),
+        z_dim: g.gen(),
+        scale_center: g.gen(),
+        font: g.gen(),
+        word_wrap: g.gen(),
+        horizontal: g.gen(),
+        justification: g.gen(),
+        vertical_justification: g.gen(),
+        fill_color: g.gen(),
+        outline_color: g.gen(),
+        block_extent: g.gen(),
+        jpn_font: if version == 1 { Some(g.gen()) } else { None },
+        jpn_point_scale: if version == 1 { Some(g.gen()) } else { None },
+    }
+}
+
+fn frme_widget_kind(g: &mut Gen, version: u32) -> FrmeWidgetKind<'static>
+{
+    match g.below(13) {
+        0 => FrmeWidgetKind::Head,
+        1 => FrmeWidgetKind::Base,
+        2 => {
+            let perspective = g.chance();
+            FrmeWidgetKind::Camera(CameraWidget {
+                perspective_projection: if perspective { Some(g.gen()) } else { None },
+                orthographic_projection: if perspective { None } else { Some(g.gen()) },
+            })
+        },
+        3 => {
+            let light_type = g.below(3) as u32;
+            FrmeWidgetKind::Light(LightWidget {
+                light_type,
+                dist_c: g.gen(),
+                dist_l: g.gen(),
+                dist_q: g.gen(),
+                ang_c: g.gen(),
+                ang_l: g.gen(),
+                ang_q: g.gen(),
+                loaded_idx: g.gen(),
+                cutoff: if light_type == 0 { Some(g.gen()) } else { None },
+            })
+        },
+        4 => FrmeWidgetKind::Model(ModelWidget {
+            model: g.gen(),
+            blend_mode: g.gen(),
+            light_mask: g.gen(),
+        }),
+        5 => FrmeWidgetKind::TextPane(text_pane_widget(g, version)),
+        6 => FrmeWidgetKind::Meter(MeterWidget {
+            unknown: g.gen(),
+            no_round_up: g.gen(),
+            max_capacity: g.gen(),
+            worker_count: g.gen(),
+        }),
+        7 => FrmeWidgetKind::Energy(EnergyWidget { txtr: g.gen() }),
+        8 => FrmeWidgetKind::Group(GroupWidget {
+            default_worker: g.gen(),
+            unknown: g.gen(),
+        }),
+        9 => FrmeWidgetKind::TableGroup(TableGroupWidget {
+            element_count: g.gen(),
+            unknown0: g.gen(),
+            unknown1: g.gen(),
+            default_selection: g.gen(),
+            unknown2: g.gen(),
+            select_wraparound: g.gen(),
+            unknown3: g.gen(),
+            unknown4: g.gen(),
+            unknown5: g.gen(),
+            unknown6: g.gen(),
+            unknown7: g.gen(),
+            unknown8: g.gen(),
+            unknown9: g.gen(),
+            unknown10: g.gen(),
+            unknown11: g.gen(),
+        }),
+        10 => FrmeWidgetKind::Pane(PaneWidget {
+            x_dim: g.gen(),
+            z_dim: g.gen(),
+            scale_center: g.gen(),
+        }),
+        11 => FrmeWidgetKind::Slider(SliderWidget {
+            min: g.gen(),
+            max: g.gen(),
+            curr: g.gen(),
+            increment: g.gen(),
+        }),
+        _ => FrmeWidgetKind::Image(ImageWidget {
+            texture: g.gen(),
+            unknown0: g.gen(),
+            unknown1: g.gen(),
+            quad_coords: ro_array(g.vec(4, |g| g.gen()), ()),
+            uv_coords: ro_array(g.vec(4, |g| g.gen()), ()),
+        }),
+    }
+}
+
+fn frme(g: &mut Gen) -> Frme<'static>
+{
+    let version = g.below(2) as u32;
+    Frme {
+        version,
+        unknown0: g.gen(),
+        unknown1: g.gen(),
+        widgets: g.vec(4, |g| FrmeWidget {
+            name: g.cstr(),
+            parent: g.cstr(),
+            use_anim_controller: g.gen(),
+            default_visible: g.gen(),
+            default_active: g.gen(),
+            cull_faces: g.gen(),
+            color: g.gen(),
+            model_draw_flags: g.gen(),
+            kind: frme_widget_kind(g, version),
+            worker_id: g.opt(|g| g.gen()),
+            origin: g.gen(),
+            basis: g.gen(),
+            rotation_center: g.gen(),
+            unknown0: g.gen(),
+            unknown1: g.gen(),
+        }).into(),
+    }
+}
+
+/* ANCS */
+
+// Animations nest, so stop branching after a couple of levels
+fn meta_animation(g: &mut Gen, depth: usize) -> MetaAnimation<'static>
+{
+    let kind = if depth >= 2 { 0 } else { g.below(5) };
+    match kind {
+        0 => MetaAnimation::Play(Uncached::Owned(Box::new(MetaAnimationPlay {
+            anim: g.gen(),
+            index: g.gen(),
+            name: g.cstr(),
+            unknown0: g.gen(),
+            unknown1: g.gen(),
+        }))),
+        1 | 2 => {
+            let blend = MetaAnimationBlend {
+                anim_a: meta_animation(g, depth + 1),
+                anim_b: meta_animation(g, depth + 1),
+                unknown0: g.gen(),
+                unknown1: g.gen(),
+            };
+            if kind == 1 {
+                MetaAnimation::Blend(Uncached::Owned(Box::new(blend)))
+            } else {
+                MetaAnimation::PhaseBlend(Uncached::Owned(Box::new(blend)))
+            }
+        },
+        3 => {
+            let anims = g.vec(3, |g| MetaAnimationRandomPair {
+                meta: meta_animation(g, depth + 1),
+                probability: g.gen(),
+            });
+            MetaAnimation::Random(Uncached::Owned(Box::new(MetaAnimationRandom {
+                anim_count: anims.len() as u32,
+                anims: ro_array(anims, ()),
+            })))
+        },
+        _ => {
+            let anims = g.vec(3, |g| meta_animation(g, depth + 1));
+            MetaAnimation::Sequence(Uncached::Owned(Box::new(MetaAnimationSequence {
+                anim_count: anims.len() as u32,
+                anims: ro_array(anims, ()),
+            })))
+        },
+    }
+}
+
+fn meta_transition_transition(g: &mut Gen) -> Uncached<'static, MetaTransitionTransition>
+{
+    Uncached::Owned(Box::new(MetaTransitionTransition {
+        time: g.gen(),
+        unknown0: g.gen(),
+        unknown1: g.gen(),
+        unknown2: g.gen(),
+        unknown3: g.gen(),
+    }))
+}
+
+fn meta_transition(g: &mut Gen) -> MetaTransition<'static>
+{
+    match g.below(4) {
+        0 => MetaTransition::Animation(Uncached::Owned(Box::new(MetaTransitionAnimation {
+            meta: meta_animation(g, 0),
+        }))),
+        1 => MetaTransition::Transition(meta_transition_transition(g)),
+        2 => MetaTransition::PhaseTransition(meta_transition_transition(g)),
+        _ => MetaTransition::NoTransition,
+    }
+}
+
+// Parameters of type 3 hold a single byte, the rest hold 4
+fn pas_param_len(param_type: u32) -> usize
+{
+    if param_type == 3 { 1 } else { 4 }
+}
+
+fn pas_anim_state(g: &mut Gen) -> PasAnimState<'static>
+{
+    let param_info = ro_array(g.vec(3, |g| {
+        let param_type = g.below(5) as u32;
+        PasAnimStateParamInfo {
+            param_type,
+            unknown0: g.gen(),
+            unknown1: g.gen(),
+            data0: bytes_array(g, pas_param_len(param_type)),
+            data1: bytes_array(g, pas_param_len(param_type)),
+        }
+    }), ());
+    let anim_info = g.vec(3, |g| PasAnimStateAnimInfo {
+        unknown: g.gen(),
+        items: param_info.iter()
+            .map(|param| PasAnimStateAnimInfoInner {
+                data0: bytes_array(g, pas_param_len(param.param_type)),
+            })
+            .collect::<Vec<_>>()
+            .into(),
+    });
+    PasAnimState {
+        unknown: g.gen(),
+        param_info_count: param_info.len() as u32,
+        anim_info_count: anim_info.len() as u32,
+        anim_info: ro_array(anim_info, param_info.clone()),
+        param_info,
+    }
+}
+
+// `Effect` is also the name of a script object, so the ANCS one can't be named from here. Write
+// the effects out instead and let the field they're read into pick the type.
+fn effect_bytes(g: &mut Gen) -> (u32, Vec<u8>)
+{
+    let count = g.below(3);
+    let mut bytes = vec![];
+    for _ in 0..count {
+        let components = g.vec(2, |g| EffectComponent {
+            name: g.cstr(),
+            type_: g.gen(),
+            file_id: g.gen(),
+            bone: g.cstr(),
+            scale: g.gen(),
+            parent_mode: g.gen(),
+            flags: g.gen(),
+        });
+        bytes.extend(to_bytes(&g.cstr()));
+        bytes.extend(to_bytes(&(components.len() as u32)));
+        for component in &components {
+            bytes.extend(to_bytes(component));
+        }
+    }
+    (count as u32, bytes)
+}
+
+fn character_info(g: &mut Gen) -> CharacterInfo<'static>
+{
+    // Which of the optional fields are present depends on this
+    let info_type_count = 1 + g.below(10) as u16;
+    let min = |n: u16| if info_type_count > n { Some(()) } else { None };
+
+    let animation_names = g.vec(3, |g| AnimationName {
+        index: g.gen(),
+        unknown: if info_type_count < 10 { Some(g.cstr()) } else { None },
+        name: g.cstr(),
+    });
+    let anim_states = g.vec(2, pas_anim_state);
+    let elsc_assets = min(5).map(|()| ro_array(g.vec(3, |g| g.gen()), ()));
+    let animation_aabbs = min(1).map(|()| g.vec(2, |g| AnimationAABB {
+        name: g.cstr(),
+        aabb: g.gen(),
+    }));
+    let (effect_count, effect_bytes) = effect_bytes(g);
+    let effect_bytes: &'static [u8] = Box::leak(effect_bytes.into_boxed_slice());
+    let animation_indices = min(4).map(|()| g.vec(3, |g| g.gen()));
+    let animation_indexed_aabbs = min(9).map(|()| g.vec(2, |g| AnimationIndexedAABB {
+        index: g.gen(),
+        aabb: g.gen(),
+    }));
+
+    CharacterInfo {
+        id: g.gen(),
+        info_type_count,
+        name: g.cstr(),
+        cmdl: g.gen(),
+        cskr: g.gen(),
+        cinf: g.gen(),
+        animation_count: animation_names.len() as u32,
+        animation_names: ro_array(animation_names, info_type_count),
+        pas_database: PasDatabase {
+            anim_state_count: anim_states.len() as u32,
+            default_state: g.gen(),
+            anim_states: ro_array(anim_states, ()),
+        },
+        particles: ParticleResData {
+            part_assets: g.vec(3, |g| g.gen()).into(),
+            swhc_assets: ro_array(g.vec(3, |g| g.gen()), ()),
+            unknowns: ro_array(g.vec(3, |g| g.gen()), ()),
+            elsc_count: elsc_assets.as_ref().map(|a| a.len() as u32),
+            elsc_assets,
+        },
+        unknown0: g.gen(),
+        unknown1: min(9).map(|()| g.gen()),
+        unknown2: min(9).map(|()| g.gen()),
+        animation_aabb_count: animation_aabbs.as_ref().map(|a| a.len() as u32),
+        animation_aabbs: animation_aabbs.map(|a| ro_array(a, ())),
+        effect_count: min(1).map(|()| effect_count),
+        effects: min(1).map(|()| Reader::new(effect_bytes).read((effect_count as usize, ()))),
+        overlay_cmdl: min(3).map(|()| g.gen()),
+        overlay_cskr: min(3).map(|()| g.gen()),
+        animation_index_count: animation_indices.as_ref().map(|a| a.len() as u32),
+        animation_indices: animation_indices.map(|a| ro_array(a, ())),
+        unknown3: min(9).map(|()| g.gen()),
+        unknown4: min(9).map(|()| g.gen()),
+        animation_indexed_aabb_count: animation_indexed_aabbs.as_ref().map(|a| a.len() as u32),
+        animation_indexed_aabbs: animation_indexed_aabbs.map(|a| ro_array(a, ())),
+    }
+}
+
+fn animation_set(g: &mut Gen) -> AnimationSet<'static>
+{
+    let info_count = 1 + g.below(4) as u16;
+    let transitions = g.vec(2, |g| Transition {
+        unknown: g.gen(),
+        anim_index_a: g.gen(),
+        anim_index_b: g.gen(),
+        meta: meta_transition(g),
+    });
+    let additive_animations = g.vec(2, |g| AdditiveAnimation {
+        index: g.gen(),
+        fade_in: g.gen(),
+        fade_out: g.gen(),
+    });
+    let half_transitions = if info_count > 2 {
+        Some(g.vec(2, |g| HalfTransition {
+            index: g.gen(),
+            meta: meta_transition(g),
+        }))
+    } else {
+        None
+    };
+
+    AnimationSet {
+        info_count,
+        animations: g.vec(3, |g| Animation {
+            name: g.cstr(),
+            meta: meta_animation(g, 0),
+        }).into(),
+        transition_count: transitions.len() as u32,
+        transitions: ro_array(transitions, ()),
+        default_transition: meta_transition(g),
+        additive_animation_count: additive_animations.len() as u32,
+        additive_animations: ro_array(additive_animations, ()),
+        fade_in: g.gen(),
+        fade_out: g.gen(),
+        half_transition_count: half_transitions.as_ref().map(|h| h.len() as u32),
+        half_transitions: half_transitions.map(|h| ro_array(h, ())),
+        animation_resources: if info_count > 3 {
+            Some(g.vec(3, |g| AnimationResource {
+                anim: g.gen(),
+                evnt: g.gen(),
+            }).into())
+        } else {
+            None
+        },
+    }
+}
+
+fn ancs(g: &mut Gen) -> Ancs<'static>
+{
+    Ancs {
+        char_set: CharacterSet {
+            char_info: g.vec(2, character_info).into(),
+        },
+        anim_set: animation_set(g),
+    }
+}
+
+/* CTWK */
+
+/// The tweak structs other than `CtwkGame` are long runs of numbers, so build them by reading
+/// random bytes. `len` is the size `Ctwk` tells them apart by.
+fn read_random<T: Readable<'static, Args = ()>>(g: &mut Gen, len: usize) -> T
+{
+    Reader::new(Box::leak(g.bytes(len).into_boxed_slice())).read(())
+}
+
+fn ctwk_game(g: &mut Gen) -> CtwkGame<'static>
+{
+    // The names are short enough that the struct always pads out to 96 bytes
+    CtwkGame {
+        start: Reader::new(&[]),
+        world_prefix: g.cstr(),
+        default_room: g.cstr(),
+        fov: g.gen(),
+        unknown1: g.gen(),
+        unknown2: g.gen(),
+        unknown3: g.gen(),
+        splash_screens_disabled: g.gen(),
+        unknown5: g.gen(),
+        press_start_delay: g.gen(),
+        wavecap_intensity_normal: g.gen(),
+        wavecap_intensity_poison: g.gen(),
+        wavecap_intensity_lava: g.gen(),
+        ripple_intensity_normal: g.gen(),
+        ripple_intensity_poison: g.gen(),
+        ripple_intensity_lava: g.gen(),
+        fluid_env_bump_scale: g.gen(),
+        water_fog_distance_base: g.gen(),
+        water_fog_distance_range: g.gen(),
+        gravity_water_fog_distance_base: g.gen(),
+        gravity_water_fog_distance_range: g.gen(),
+        hardmode_damage_mult: g.gen(),
+        hardmode_weapon_mult: g.gen(),
+    }
+}
+
+fn ctwk(g: &mut Gen) -> Ctwk<'static>
+{
+    match g.below(5) {
+        0 => Ctwk::CtwkGame(ctwk_game(g)),
+        1 => Ctwk::CtwkPlayer(read_random(g, 800)),
+        2 => Ctwk::CtwkPlayerGun(read_random(g, 512)),
+        3 => Ctwk::CtwkBall(read_random(g, 480)),
+        _ => Ctwk::CtwkGuiColors(read_random(g, 2368)),
+    }
+}
+
+/* TXTR */
+
+const TXTR_FORMATS: &[u32] = &[0x0, 0x1, 0x2, 0x3, 0x4, 0x5, 0x7, 0x8, 0x9, 0xA];
+
+fn txtr_palette_format(g: &mut Gen) -> TxtrPaletteFormat
+{
+    match g.below(3) {
+        0 => TxtrPaletteFormat::Ia8,
+        1 => TxtrPaletteFormat::Rgb565,
+        _ => TxtrPaletteFormat::Rgb5A3,
+    }
+}
+
+fn txtr(g: &mut Gen) -> Txtr<'static>
+{
+    let format_id = TXTR_FORMATS[g.below(TXTR_FORMATS.len())];
+    let format = match format_id {
+        0x0 => TxtrFormat::I4,
+        0x1 => TxtrFormat::I8,
+        0x2 => TxtrFormat::Ia4,
+        0x3 => TxtrFormat::Ia8,
+        0x4 => TxtrFormat::C4(txtr_palette_format(g), Box::new(g.gen())),
+        0x5 => TxtrFormat::C8(txtr_palette_format(g), Box::new(g.gen())),
+        0x7 => TxtrFormat::Rgb565,
+        0x8 => TxtrFormat::Rgb5A3,
+        0x9 => TxtrFormat::Rgba8,
+        _ => TxtrFormat::Cmpr,
+    };
+
+    // Each mipmap is half the width and height of the one before
+    let width = 4 << g.below(4);
+    let height = 4 << g.below(4);
+    let mipmap_count = 1 + g.below(3);
+    let pixel_data = (0..mipmap_count)
+        .map(|i| {
+            let pixels = (width >> i) * (height >> i);
+            let len = match format_id {
+                0x0 | 0x4 | 0xA => pixels / 2,
+                0x1 | 0x2 | 0x5 => pixels,
+                0x9 => pixels * 4,
+                _ => pixels * 2,
+            };
+            g.bytes(len).into()
+        })
+        .collect::<Vec<LazyArray<u8>>>();
+
+    Txtr {
+        width: width as u16,
+        height: height as u16,
+        format,
+        pixel_data: pixel_data.into(),
+    }
+}
+
+/* THP */
+
+fn thp(g: &mut Gen) -> Thp<'static>
+{
+    // Component slots are video (0), audio (1) or unused (0xFF)
+    let component_types = (0..16).map(|_| [0, 1, 0xFF][g.below(3)]).collect::<Vec<u8>>();
+    let components = component_types.iter()
+        .map(|kind| ThpComponent {
+            video_info: if *kind == 0 {
+                Some(ThpVideoInfo { width: g.gen(), height: g.gen() })
+            } else {
+                None
+            },
+            audio_info: if *kind == 1 {
+                Some(ThpAudioInfo {
+                    channels_count: g.gen(),
+                    frequency: g.gen(),
+                    samples_count: g.gen(),
+                })
+            } else {
+                None
+            },
+        })
+        .collect::<Vec<_>>();
+    let component_count = 1 + g.below(2) as u32;
+    let has_audio = component_count > 1;
+
+    // Every THP has at least one frame
+    let frame_count = 1 + g.below(3);
+    let frames = (0..frame_count)
+        .map(|_| {
+            let video_len = g.below(64);
+            let audio_data = if has_audio {
+                let len = g.below(64);
+                Some(g.bytes(len))
+            } else {
+                None
+            };
+            ThpFrameData {
+                frame_size_next: g.gen(),
+                frame_size_prev: g.gen(),
+                video_size: video_len as u32,
+                audio_size: audio_data.as_ref().map(|a| a.len() as u32),
+                video_data: bytes_array(g, video_len),
+                audio_data: audio_data.map(|a| ro_array(a, ())),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Thp {
+        max_buffer_size: g.gen(),
+        max_audio_samples: g.gen(),
+        components: ThpComponents {
+            component_count,
+            component_types: ro_array(component_types, ()),
+            components: components.into(),
+        },
+        frames: frames.into(),
+    }
+}
+
+proptest! {
+    #[test]
+    fn strg_round_trips(strg in generated(strg))
+    {
+        let read = round_trip(&strg, ())?;
+        assert_same(&strg, &read)?;
+    }
+
+    #[test]
+    fn savw_round_trips(savw in generated(savw))
+    {
+        let read = round_trip(&savw, ())?;
+        assert_same(&savw, &read)?;
+    }
+
+    #[test]
+    fn scan_round_trips(scan in generated(scan))
+    {
+        let read = round_trip(&scan, ())?;
+        assert_same(&scan, &read)?;
+    }
+
+    #[test]
+    fn evnt_round_trips(evnt in generated(evnt))
+    {
+        let read = round_trip(&evnt, ())?;
+        assert_same(&evnt, &read)?;
+    }
+
+    #[test]
+    fn bnr_round_trips(bnr in generated(bnr))
+    {
+        let read = round_trip(&bnr, ())?;
+        assert_same(&bnr, &read)?;
+    }
+
+    #[test]
+    fn mapa_round_trips(mapa in generated(mapa))
+    {
+        let read = round_trip(&mapa, ())?;
+        assert_same(&mapa, &read)?;
+    }
+
+    #[test]
+    fn mlvl_round_trips(mlvl in generated(mlvl))
+    {
+        let read = round_trip(&mlvl, ())?;
+        assert_same(&mlvl, &read)?;
+    }
+
+    #[test]
+    fn mrea_round_trips(mrea in generated(mrea))
+    {
+        let mut read = round_trip(&mrea, ())?;
+        parse_mrea(&mut read);
+        assert_same(&mrea, &read)?;
+    }
+
+    #[test]
+    fn pak_round_trips(pak in generated(pak))
+    {
+        let read = round_trip(&pak, ())?;
+        prop_assert_eq!(pak.unused, read.unused);
+        assert_same(&pak.named_resources, &read.named_resources)?;
+        prop_assert_eq!(parse_resources(&pak), parse_resources(&read));
+    }
+
+    #[test]
+    fn frme_round_trips(frme in generated(frme))
+    {
+        let read = round_trip(&frme, ())?;
+        assert_same(&frme, &read)?;
+    }
+
+    #[test]
+    fn ancs_round_trips(ancs in generated(ancs))
+    {
+        let read = round_trip(&ancs, ())?;
+        assert_same(&ancs, &read)?;
+    }
+
+    #[test]
+    fn ctwk_round_trips(ctwk in generated(ctwk))
+    {
+        let read = round_trip(&ctwk, ())?;
+        assert_same(&ctwk, &read)?;
+    }
+
+    #[test]
+    fn txtr_round_trips(txtr in generated(txtr))
+    {
+        let read = round_trip(&txtr, ())?;
+        assert_same(&txtr, &read)?;
+    }
+
+    #[test]
+    fn thp_round_trips(thp in generated(thp))
+    {
+        let read = round_trip(&thp, ())?;
+        assert_same(&thp, &read)?;
+    }
+}
+
+// ResourceList used to hold an empty Array element for a PAK without resources, which its
+// iterators and cursors don't expect
+#[test]
+fn empty_pak_round_trips()
+{
+    let pak = Pak {
+        start: Reader::new(&[]),
+        unused: 0,
+        named_resources: ro_array(vec![], ()),
+        resources: vec![].into_iter().collect(),
+    };
+    let mut read = round_trip(&pak, ()).unwrap();
+    assert_eq!(read.resources.len(), 0);
+    assert_eq!(read.resources.iter().count(), 0);
+
+    let mut g = Gen::new([0; 32]);
+    let res = resource(&mut g, ResourceKind::Unknown(Reader::new(b"TXTR"), b"TXTR".into()));
+    let file_id = res.file_id;
+    read.resources.cursor().insert_before(std::iter::once(res));
+    let read = round_trip(&read, ()).unwrap();
+    let ids: Vec<_> = read.resources.iter().map(|res| res.file_id).collect();
+    assert_eq!(ids, [file_id]);
+}
+
+#[test]
+fn truncated_resources_fail_to_parse()
+{
+    let mut g = Gen::new([0; 32]);
+    let strg_bytes = to_bytes(&strg(&mut g));
+    let mut kind = ResourceKind::Unknown(
+        Reader::new(&strg_bytes[..strg_bytes.len() / 2]),
+        b"STRG".into(),
+    );
+    assert!(kind.try_as_strg().is_err());
+    assert!(kind.try_guess_kind().is_err());
+
+    let mut mrea = mrea(&mut g);
+    let scly_bytes = to_bytes(&*mrea.scly_section()).leak();
+    let scly_section_idx = mrea.scly_section_idx as usize;
+    mrea.sections.as_mut_vec()[scly_section_idx] =
+        MreaSection::Unknown(Reader::new(&scly_bytes[..scly_bytes.len() / 2]));
+    assert!(mrea.try_scly_section().is_err());
+    assert!(mrea.try_scly_section_mut().is_err());
+}
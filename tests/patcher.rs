@@ -0,0 +1,436 @@
+use disc_fixture::{
+    connection, cstr, find_mlvl, find_resource, find_scly, instance_id, layer_objects, object,
+    pak_mut, to_bytes, DiscFixture, RoomFixture, WorldFixture,
+};
+use randomprime::{
+    add_modify_obj_patches::{patch_add_relay, patch_add_timer},
+    custom_assets::build_resource_raw,
+    elevators::World,
+    enemy_randomizer::{patch_randomize_enemies, plan_enemy_randomizer_for_rooms, EnemyType},
+    patch_config::{EnemyRandomizerConfig, RelayConfig, TimerConfig},
+    patcher::PrimePatcher,
+    strg_translation::patch_translate_strg,
+};
+use reader_writer::Reader;
+use structs::{
+    scly_structs::{
+        ActorParameters, AncsProp, BeamCombos, ChargedBeams, DamageInfo, DamageVulnerability,
+        HealthInfo, LightParameters, PatternedInfo, ScannableParameters, VisorParameters,
+    },
+    ConnectionMsg, ConnectionState, FlickerBat, GcDisc, MemoryRelay, Pak, Relay, ResId,
+    ResourceKind, Strg, Timer, WarWasp,
+};
+
+use std::collections::BTreeMap;
+
+const PAK: &str = "Metroid1.pak";
+const MLVL: u32 = 0x1000_0000;
+const ROOM_A: u32 = 0x2000_0000;
+const ROOM_B: u32 = 0x2000_0002;
+
+fn relay(name: &str, active: bool) -> Relay<'static>
+{
+    Relay {
+        name: cstr(name),
+        active: active as u8,
+    }
+}
+
+fn world() -> WorldFixture
+{
+    let mut switch = object(instance_id(0, 0, 1), relay("Switch", true));
+    switch.connections.as_mut_vec().push(
+        connection(ConnectionState::ZERO, ConnectionMsg::SET_TO_ZERO, instance_id(1, 0, 2))
+    );
+    WorldFixture::new(PAK, MLVL, "Tallon Overworld")
+        .room(RoomFixture::new(ROOM_A, ROOM_A + 1, "Landing Site")
+            .object(0, switch)
+            .layer("Ship", false)
+            .object(1, object(instance_id(1, 0, 2), relay("Target", false))))
+        .room(RoomFixture::new(ROOM_B, ROOM_B + 1, "Gully"))
+}
+
+#[test]
+fn test_disc_fixture()
+{
+    let world = world();
+    let disc_bytes = DiscFixture::new().world(&world).build();
+    let mut disc: GcDisc = Reader::new(&disc_bytes).read(());
+    assert_eq!(&disc.header.game_identifier(), b"GM8E01");
+
+    let pak = pak_mut(&mut disc, PAK);
+    let mlvl = find_mlvl(pak);
+    assert_eq!(mlvl.world_savw.to_u32(), world.savw_id());
+    assert_eq!(mlvl.areas.len(), 2);
+    assert_eq!(mlvl.area_layer_names.names_for_area(0).unwrap()[1].to_str().unwrap(), "Ship");
+    assert_eq!(mlvl.area_layer_flags.get(0).unwrap().flags, 0b01);
+
+    let savw = find_resource(pak, b"SAVW", world.savw_id()).kind.as_savw().unwrap().into_owned();
+    assert_eq!(savw.area_count, 2);
+
+    let strg = find_resource(pak, b"STRG", ROOM_A + 1).kind.as_strg().unwrap().into_owned();
+    let table = strg.string_tables.iter().next().unwrap();
+    assert_eq!(table.strings.iter().next().unwrap().into_owned().into_string(), "Landing Site\0");
+
+    let scly = find_scly(pak, ROOM_A);
+    let objects = layer_objects(&scly, 0);
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].connections.get(0).unwrap().target_object_id, instance_id(1, 0, 2));
+    assert_eq!(layer_objects(&scly, 1)[0].property_data.as_relay().unwrap().name.to_str().unwrap(), "Target");
+}
+
+#[test]
+fn test_add_and_edit_objects()
+{
+    let disc_bytes = DiscFixture::new().world(&world()).build();
+    let mut disc: GcDisc = Reader::new(&disc_bytes).read(());
+
+    let timer = TimerConfig {
+        id: 0x0C00_0050,
+        layer: Some(3),
+        active: None,
+        time: 2.5,
+        max_random_add: None,
+        looping: Some(true),
+        start_immediately: None,
+    };
+    let relay = RelayConfig {
+        id: instance_id(1, 0, 2),
+        layer: None,
+        active: Some(true),
+    };
+
+    let mut patcher = PrimePatcher::new();
+    patcher.add_scly_patch(
+        (PAK.as_bytes(), ROOM_A),
+        move |ps, area| patch_add_timer(ps, area, timer.clone()),
+    );
+    patcher.add_scly_patch(
+        (PAK.as_bytes(), ROOM_A),
+        move |ps, area| patch_add_relay(ps, area, relay.clone()),
+    );
+    patcher.run(&mut disc).unwrap();
+
+    // Write the PAK out and read it back, so the patched data also has to serialize correctly
+    let pak_bytes = to_bytes(pak_mut(&mut disc, PAK));
+    let pak: Pak = Reader::new(&pak_bytes).read(());
+
+    let scly = find_scly(&pak, ROOM_A);
+    assert_eq!(scly.layers.len(), 4);
+    let timers = layer_objects(&scly, 3);
+    assert_eq!(timers.len(), 1);
+    assert_eq!(timers[0].instance_id, 0x0C00_0050);
+    let timer = timers[0].property_data.as_timer().unwrap();
+    assert_eq!(timer.start_time, 2.5);
+    assert_eq!(timer.looping, 1);
+
+    let target = &layer_objects(&scly, 1)[0];
+    assert_eq!(target.property_data.as_relay().unwrap().active, 1);
+
+    // The new layers are registered in the MLVL too
+    let mlvl = find_mlvl(&pak);
+    let layer_flags = mlvl.area_layer_flags.get(0).unwrap();
+    assert_eq!(layer_flags.layer_count, 4);
+    assert_eq!(layer_flags.flags, 0b1101);
+    let names = mlvl.area_layer_names.names_for_area(0).unwrap();
+    assert_eq!(names[3].to_str().unwrap(), "New Layer");
+    assert_eq!(mlvl.areas.iter().next().unwrap().dependencies.deps.len(), 5);
+
+    // Untouched rooms stay the same
+    assert_eq!(mlvl.area_layer_flags.get(1).unwrap().layer_count, 1);
+    assert_eq!(find_scly(&pak, ROOM_B).layers.len(), 1);
+}
+
+#[test]
+fn test_add_memory_relay()
+{
+    let disc_bytes = DiscFixture::new().world(&world()).build();
+    let mut disc: GcDisc = Reader::new(&disc_bytes).read(());
+
+    let mut patcher = PrimePatcher::new();
+    patcher.add_scly_patch((PAK.as_bytes(), ROOM_B), |_ps, area| {
+        let id = area.new_object_id_from_layer_id(0);
+        let mut mem_relay = object(id, MemoryRelay {
+            name: cstr("Memory Relay"),
+            unknown: 0,
+            active: 1,
+        });
+        mem_relay.connections.as_mut_vec().push(
+            connection(ConnectionState::ACTIVE, ConnectionMsg::ACTIVATE, instance_id(0, 0, 1))
+        );
+        area.add_memory_relay(mem_relay);
+        Ok(())
+    });
+    patcher.run(&mut disc).unwrap();
+
+    // Write the PAK out and read it back, so the patched data also has to serialize correctly
+    let pak_bytes = to_bytes(pak_mut(&mut disc, PAK));
+    let pak: Pak = Reader::new(&pak_bytes).read(());
+
+    let objects = layer_objects(&find_scly(&pak, ROOM_B), 0);
+    assert_eq!(objects.len(), 1);
+    assert!(objects[0].property_data.is_memory_relay());
+    // Ids are allocated downwards from the top of the area's range
+    assert_eq!(objects[0].instance_id, instance_id(0, 1, 0xEFFE));
+
+    let mlvl = find_mlvl(&pak);
+    let conns: Vec<_> = mlvl.memory_relay_conns.iter().map(|conn| conn.into_owned()).collect();
+    assert_eq!(conns.len(), 1);
+    assert_eq!(conns[0].sender_id, objects[0].instance_id);
+    assert_eq!(conns[0].target_id, instance_id(0, 0, 1));
+    assert_eq!(conns[0].message, ConnectionMsg::ACTIVATE.0 as u16);
+    assert_eq!(conns[0].active, 1);
+}
+
+#[test]
+fn test_translate_strg()
+{
+    let strings = |strings: &[&str]| strings.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let english = |s: &[&str]| BTreeMap::from([("ENGL".to_string(), strings(s))]);
+
+    // The randomizer has already rewritten the third string
+    let strg = Strg::from_strings(strings(&["Say No\0", "No\0", "Randomized\0"]));
+    let mut res = build_resource_raw(0x5000_0000, ResourceKind::Strg(strg));
+    let vanilla = english(&["Say No", "No", "Vanilla"]);
+    let translated = english(&["Say No", "Nein", "Vanille"]);
+    patch_translate_strg(&mut res, &translated, &vanilla).unwrap();
+
+    let strg = res.kind.as_strg().unwrap().into_owned();
+    let table = strg.string_tables.iter().next().unwrap();
+    let patched: Vec<_> = table.strings.iter().map(|s| s.into_owned().into_string()).collect();
+    assert_eq!(patched, strings(&["Say No\0", "Nein\0", "Randomized\0"]));
+}
+
+fn damage_info() -> DamageInfo
+{
+    DamageInfo {
+        weapon_type: 9,
+        damage: 5.0,
+        radius: 0.0,
+        knockback_power: 5.0,
+    }
+}
+
+fn patterned_info() -> PatternedInfo
+{
+    let beams = || ChargedBeams { power: 1, ice: 1, wave: 1, plasma: 1, phazon: 1 };
+    let combos = || BeamCombos { power: 1, ice: 1, wave: 1, plasma: 1, phazon: 1 };
+    PatternedInfo {
+        mass: 25.0,
+        speed: 1.0,
+        turn_speed: 120.0,
+        detection_range: 20.0,
+        detection_height_range: 20.0,
+        detection_angle: 180.0,
+        min_attack_range: 0.0,
+        max_attack_range: 10.0,
+        average_attack_time: 2.0,
+        attack_time_variation: 1.0,
+        leash_radius: 30.0,
+        player_leash_radius: 30.0,
+        player_leash_time: 5.0,
+        contact_damage: damage_info(),
+        damage_wait_time: 1.0,
+        health_info: HealthInfo {
+            health: 10.0,
+            knockback_resistance: 1.0,
+        },
+        damage_vulnerability: DamageVulnerability {
+            power: 1, ice: 1, wave: 1, plasma: 1, bomb: 1, power_bomb: 1, missile: 1,
+            boost_ball: 1, phazon: 1,
+            enemy_weapon0: 1, enemy_weapon1: 1, enemy_weapon2: 1, enemy_weapon3: 1,
+            unknown_weapon0: 1, unknown_weapon1: 1, unknown_weapon2: 1,
+            charged_beams: beams(),
+            beam_combos: combos(),
+        },
+        half_extent: 1.0,
+        height: 1.0,
+        body_origin: [0.0, 0.0, 0.0].into(),
+        step_up_height: 0.5,
+        x_damage: 0.0,
+        frozen_x_damage: 0.0,
+        x_damage_delay: 0.0,
+        death_sfx: u32::MAX,
+        animation_parameters: AncsProp {
+            file_id: ResId::invalid(),
+            node_index: 0,
+            default_animation: u32::MAX,
+        },
+        active: 1,
+        state_machine: ResId::invalid(),
+        into_freeze_dur: 0.5,
+        out_of_freeze_dur: 0.5,
+        unknown0: 0.5,
+        pathfinding_index: 0,
+        particle0_scale: [1.0, 1.0, 1.0].into(),
+        particle0: ResId::invalid(),
+        electric: ResId::invalid(),
+        particle1_scale: [1.0, 1.0, 1.0].into(),
+        particle1: ResId::invalid(),
+        ice_shatter_sfx: u32::MAX,
+    }
+}
+
+fn actor_params() -> ActorParameters
+{
+    ActorParameters {
+        light_params: LightParameters {
+            unknown0: 1,
+            unknown1: 1.0,
+            shadow_tessellation: 0,
+            unknown2: 1.0,
+            unknown3: 20.0,
+            color: [1.0, 1.0, 1.0, 1.0].into(),
+            unknown4: 1,
+            world_lighting: 1,
+            light_recalculation: 1,
+            unknown5: [0.0, 0.0, 0.0].into(),
+            unknown6: 4,
+            unknown7: 4,
+            unknown8: 0,
+            light_layer_id: 0,
+        },
+        scan_params: ScannableParameters {
+            scan: ResId::invalid(),
+        },
+        xray_cmdl: ResId::invalid(),
+        xray_cskr: ResId::invalid(),
+        thermal_cmdl: ResId::invalid(),
+        thermal_cskr: ResId::invalid(),
+        unknown0: 1,
+        unknown1: 1.0,
+        unknown2: 1.0,
+        visor_params: VisorParameters {
+            unknown0: 0,
+            target_passthrough: 0,
+            visor_mask: 15,
+        },
+        enable_thermal_heat: 1,
+        unknown3: 0,
+        unknown4: 0,
+        unknown5: 1.0,
+    }
+}
+
+fn flicker_bat(name: &str, position: [f32; 3], scale: f32) -> FlickerBat<'static>
+{
+    FlickerBat {
+        name: cstr(name),
+        unknown1: 0,
+        position: position.into(),
+        rotation: [0.0, 0.0, 90.0].into(),
+        scale: [scale, scale, scale].into(),
+        patterned_info: patterned_info(),
+        actor_params: actor_params(),
+        collider: 1,
+        starts_hidden: 0,
+        enable_los: 1,
+    }
+}
+
+fn war_wasp(name: &str, part: u32) -> WarWasp<'static>
+{
+    WarWasp {
+        name: cstr(name),
+        flavor: 0,
+        position: [0.0, 0.0, 0.0].into(),
+        rotation: [0.0, 0.0, 0.0].into(),
+        scale: [1.0, 1.0, 1.0].into(),
+        patterned_info: patterned_info(),
+        actor_parameters: actor_params(),
+        dont_care: 0,
+        damage_info1: damage_info(),
+        wpsc: u32::MAX,
+        damage_info2: damage_info(),
+        part,
+        sound: u32::MAX,
+    }
+}
+
+#[test]
+fn test_randomize_enemies()
+{
+    const PART: u32 = 0x3000_0000;
+    const TXTR: u32 = 0x3000_0001;
+    let world = World::TallonOverworld;
+    let pak_name = world.to_pak_str();
+
+    let bat = instance_id(0, 1, 1);
+    let mut bat_object = object(bat, flicker_bat("Bat", [1.0, 2.0, 3.0], 2.5));
+    bat_object.connections.as_mut_vec().push(
+        connection(ConnectionState::DEAD, ConnectionMsg::RESET_AND_START, instance_id(0, 1, 3))
+    );
+    // Unlocks the room once it's dead, so it has to stay a Flickerbat
+    let mut locked_bat = object(instance_id(0, 1, 2), flicker_bat("Locked Bat", [0.0; 3], 1.0));
+    locked_bat.connections.as_mut_vec().push(
+        connection(ConnectionState::DEAD, ConnectionMsg::SET_TO_ZERO, instance_id(0, 1, 4))
+    );
+    let timer = Timer {
+        name: cstr("Timer"),
+        start_time: 1.0,
+        max_random_add: 0.0,
+        looping: 0,
+        start_immediately: 0,
+        active: 1,
+    };
+
+    // The War Wasp's PART references a TXTR, which has to come along with it
+    let fixture = WorldFixture::new(pak_name, world.mlvl(), "Tallon Overworld")
+        .resource(PART, ResourceKind::Unknown(Reader::new(b"GPSM\x30\x00\x00\x01"), b"PART".into()))
+        .resource(TXTR, ResourceKind::Unknown(Reader::new(b"TXTR"), b"TXTR".into()))
+        .room(RoomFixture::new(ROOM_A, ROOM_A + 1, "Hive Cave")
+            .object(0, object(instance_id(0, 0, 1), war_wasp("Wasp", PART)))
+            .dependency(0, PART, b"PART")
+            .dependency(0, TXTR, b"TXTR"))
+        .room(RoomFixture::new(ROOM_B, ROOM_B + 1, "Bat Cave")
+            .object(0, bat_object)
+            .object(0, locked_bat)
+            .object(0, object(instance_id(0, 1, 3), timer))
+            .object(0, object(instance_id(0, 1, 4), relay("Unlock", true))));
+    let disc_bytes = DiscFixture::new().world(&fixture).build();
+    let mut disc: GcDisc = Reader::new(&disc_bytes).read(());
+
+    let worlds = [(pak_name, world, vec![(ROOM_A, "Hive Cave"), (ROOM_B, "Bat Cave")])];
+    let config = EnemyRandomizerConfig::default();
+    // Find a seed which turns the first Flickerbat into a War Wasp
+    let mut enemy_randomizer = (0..64)
+        .map(|seed| plan_enemy_randomizer_for_rooms(&disc, &config, seed, &worlds).unwrap())
+        .find(|plan| plan.enemy_type(pak_name, ROOM_B, bat) == Some(EnemyType::WarWasp))
+        .expect("No seed swaps the Flickerbat");
+    assert_eq!(enemy_randomizer.enemy_type(pak_name, ROOM_B, instance_id(0, 1, 2)), None);
+    let game_resources = enemy_randomizer.take_resources();
+
+    let mut patcher = PrimePatcher::new();
+    patcher.add_scly_patch((pak_name.as_bytes(), ROOM_B), |ps, area| {
+        patch_randomize_enemies(ps, area, &game_resources, &enemy_randomizer, pak_name)
+    });
+    patcher.run(&mut disc).unwrap();
+
+    let pak_bytes = to_bytes(pak_mut(&mut disc, pak_name));
+    let pak: Pak = Reader::new(&pak_bytes).read(());
+
+    // The replacement keeps the original's placement, size and connections
+    let objects = layer_objects(&find_scly(&pak, ROOM_B), 0);
+    assert_eq!(objects[0].instance_id, bat);
+    let wasp = objects[0].property_data.as_war_wasp().unwrap();
+    assert_eq!(wasp.part, PART);
+    assert_eq!(&wasp.position[..], &[1.0, 2.0, 3.0]);
+    assert_eq!(&wasp.rotation[..], &[0.0, 0.0, 90.0]);
+    assert_eq!(&wasp.scale[..], &[2.5, 2.5, 2.5]);
+    let conns: Vec<_> = objects[0].connections.iter().map(|conn| conn.into_owned()).collect();
+    assert_eq!(conns.len(), 1);
+    assert_eq!(conns[0].target_object_id, instance_id(0, 1, 3));
+    assert_eq!(conns[0].message, ConnectionMsg::RESET_AND_START);
+    assert!(objects[1].property_data.is_flicker_bat());
+
+    // The donor's dependencies are added to the layer and their resources to the PAK
+    let mlvl = find_mlvl(&pak);
+    let area = mlvl.areas.iter().nth(1).unwrap();
+    let deps: Vec<_> = area.dependencies.deps.iter().next().unwrap()
+        .iter()
+        .map(|dep| (dep.asset_id, dep.asset_type))
+        .collect();
+    assert_eq!(deps, vec![(PART, b"PART".into()), (TXTR, b"TXTR".into())]);
+    assert_eq!(pak.resources.iter().filter(|res| res.file_id == PART).count(), 2);
+    assert_eq!(pak.resources.iter().filter(|res| res.file_id == TXTR).count(), 2);
+}
@@ -0,0 +1,54 @@
+//! Bindings for patching a disc held in memory, so a browser page can patch a user-supplied ISO
+//! locally. See web/patcher.js.
+
+use std::{cell::RefCell, io::Cursor, panic};
+
+use randomprime::{
+    c_interface::{CbMessage, ProgressNotifier},
+    patch_config::PatchConfigBuilder,
+    patches,
+};
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Patches `input_iso` as described by `config_json`, the same JSON `randomprime_patch_iso`
+/// takes (its `outputIso` only picks the output format). `callback` receives the same
+/// `CbMessage` JSON strings, ending with either a success or an error message. Returns the
+/// patched disc, or `undefined` on errors.
+#[wasm_bindgen(js_name = patchIso)]
+pub fn patch_iso(config_json: &str, input_iso: Vec<u8>, callback: &js_sys::Function) -> Option<Vec<u8>>
+{
+    // Panics abort on wasm, keep the message around for take_panic_message
+    panic::set_hook(Box::new(|pinfo| {
+        PANIC_MESSAGE.with(|msg| *msg.borrow_mut() = Some(pinfo.to_string()));
+    }));
+
+    let send = |msg: &CbMessage| {
+        let _ = callback.call1(&JsValue::NULL, &JsValue::from_str(&msg.to_json()));
+    };
+
+    let mut output = Cursor::new(Vec::new());
+    let res = PatchConfigBuilder::from_json(config_json)
+        .and_then(|builder| builder.input_bytes(input_iso).output(&mut output).build())
+        .and_then(|config| patches::patch_iso(config, ProgressNotifier::new(send)));
+    match res {
+        Ok(()) => {
+            send(&CbMessage::Success);
+            Some(output.into_inner())
+        },
+        Err(msg) => {
+            send(&CbMessage::error(&msg));
+            None
+        },
+    }
+}
+
+/// Returns the message of the panic that aborted the last `patchIso` call, if any
+#[wasm_bindgen(js_name = takePanicMessage)]
+pub fn take_panic_message() -> Option<String>
+{
+    PANIC_MESSAGE.with(|msg| msg.borrow_mut().take())
+}
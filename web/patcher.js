@@ -0,0 +1,73 @@
+"use strict";
+
+const form = document.getElementById("patch_form");
+const button = document.getElementById("patch_button");
+const progressBar = document.getElementById("progress_bar");
+const progressMsg = document.getElementById("progress_msg");
+const downloadLink = document.getElementById("download_link");
+
+function setProgress(percent, msg, cls)
+{
+    progressBar.style.width = percent + "%";
+    progressBar.className = "progress-bar" + (cls ? " " + cls : "");
+    progressMsg.textContent = msg;
+}
+
+function outputName(configJson)
+{
+    try {
+        const outputIso = JSON.parse(configJson).outputIso || "prime_out.iso";
+        return outputIso.split(/[\\/]/).pop();
+    } catch (e) {
+        return "prime_out.iso";
+    }
+}
+
+form.addEventListener("submit", async function(event) {
+    event.preventDefault();
+    const configJson = document.getElementById("config_json").value;
+    const file = document.getElementById("input_iso").files[0];
+
+    button.disabled = true;
+    downloadLink.classList.add("hidden");
+    if (downloadLink.href) {
+        URL.revokeObjectURL(downloadLink.href);
+        downloadLink.removeAttribute("href");
+    }
+    setProgress(0, "Reading " + file.name);
+
+    const inputIso = new Uint8Array(await file.arrayBuffer());
+
+    // Patching takes a while, so it runs in a worker to keep the page (and progress bar)
+    // responsive. A fresh one is used for every disc since a panic leaves the module unusable.
+    const worker = new Worker("patcher_worker.js", { type: "module" });
+    worker.postMessage({ configJson, inputIso }, [inputIso.buffer]);
+
+    worker.onmessage = function(event) {
+        const data = event.data;
+        if (data.type === "message") {
+            // A CbMessage, the same JSON the C interface's callback receives
+            const msg = JSON.parse(data.json);
+            if (msg.type === "progress") {
+                setProgress(msg.percent, msg.msg);
+            } else if (msg.type === "success") {
+                setProgress(100, "Done", "progress-bar-success");
+            } else if (msg.type === "error") {
+                setProgress(100, "Error: " + msg.msg, "progress-bar-danger");
+            }
+        } else if (data.type === "done") {
+            worker.terminate();
+            button.disabled = false;
+            if (data.outputIso) {
+                const blob = new Blob([data.outputIso], { type: "application/octet-stream" });
+                downloadLink.href = URL.createObjectURL(blob);
+                downloadLink.download = outputName(configJson);
+                downloadLink.classList.remove("hidden");
+            }
+        } else if (data.type === "panic") {
+            worker.terminate();
+            button.disabled = false;
+            setProgress(100, "The patcher crashed: " + data.msg, "progress-bar-danger");
+        }
+    };
+});
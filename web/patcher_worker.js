@@ -0,0 +1,21 @@
+"use strict";
+
+// Built with wasm-bindgen, see the README's WebAssembly section
+import init, { patchIso, takePanicMessage } from "./pkg/randomprime_wasm.js";
+
+const ready = init();
+
+self.onmessage = async function(event) {
+    await ready;
+    const { configJson, inputIso } = event.data;
+    try {
+        const outputIso = patchIso(configJson, inputIso, function(json) {
+            self.postMessage({ type: "message", json });
+        });
+        const transfer = outputIso ? [outputIso.buffer] : [];
+        self.postMessage({ type: "done", outputIso }, transfer);
+    } catch (e) {
+        // Panics abort with a RuntimeError, the hook kept the actual message
+        self.postMessage({ type: "panic", msg: takePanicMessage() || String(e) });
+    }
+};